@@ -0,0 +1,97 @@
+//! Compares sorting by long byte-slice keys with the generated `Ord`, with a hand-written
+//! comparison of slices and with element-by-element comparison of iterators
+//!
+//! Generated comparison passes the key to `Ord` of `[u8]`, which compares with `memcmp`, so the
+//! first three variants are expected to be equally fast, and all of them faster than the
+//! iterator baseline. Run with `cargo bench --bench byte_keys`
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::Criterion;
+use ord_by_key::ord_eq_by_key_selector;
+
+/// Length of keys, long enough for the comparison to dominate the sort
+const KEY_LEN: usize = 4096;
+
+#[ord_eq_by_key_selector(|b| &b.data[..])]
+#[derive(Clone)]
+pub struct BySlice {
+    pub data: Vec<u8>,
+}
+
+#[ord_eq_by_key_selector(|b| ord_by_key::Bytes(&b.data))]
+#[derive(Clone)]
+pub struct ByBytes {
+    pub data: Vec<u8>,
+}
+
+/// Deterministic pseudo-random keys sharing long common prefixes, so comparisons have to scan
+/// most of the key before finding a difference
+fn keys(count: usize) -> Vec<Vec<u8>> {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..count)
+        .map(|_| {
+            let mut key = vec![0x5a; KEY_LEN];
+            let tail = KEY_LEN - 64;
+            for byte in &mut key[tail..] {
+                *byte = next() as u8;
+            }
+            key
+        })
+        .collect()
+}
+
+fn bench_sort(c: &mut Criterion) {
+    const COUNT: usize = 2_000;
+
+    let keys = keys(COUNT);
+    let by_slice: Vec<_> = keys.iter().map(|k| BySlice { data: k.clone() }).collect();
+    let by_bytes: Vec<_> = keys.iter().map(|k| ByBytes { data: k.clone() }).collect();
+    let mut group = c.benchmark_group("sort_byte_keys");
+
+    group.bench_function("generated_slice", |b| {
+        b.iter_batched_ref(
+            || by_slice.clone(),
+            |values| black_box(values).sort(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("generated_bytes", |b| {
+        b.iter_batched_ref(
+            || by_bytes.clone(),
+            |values| black_box(values).sort(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("manual_slice", |b| {
+        b.iter_batched_ref(
+            || by_slice.clone(),
+            |values| black_box(values).sort_by(|a, b| a.data[..].cmp(&b.data[..])),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("iterator_baseline", |b| {
+        b.iter_batched_ref(
+            || by_slice.clone(),
+            |values| black_box(values).sort_by(|a, b| a.data.iter().cmp(b.data.iter())),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sort);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Compares sorting with `Ord::cmp` and with always inlined `cmp_mono` of `gen_cmp_mono`
+//!
+//! Run with `cargo bench --bench cmp_mono`
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::Criterion;
+use ord_by_key::ord_eq_by_key_selector;
+
+/// Representative multi-key record: most comparisons are decided by the first key, ties
+/// fall through to the string and the last key
+#[ord_eq_by_key_selector(|r| r.bucket, &r.name[..], core::cmp::Reverse(r.version); gen_cmp_mono)]
+#[derive(Clone)]
+pub struct Record {
+    pub bucket: u16,
+    pub name: String,
+    pub version: u64,
+}
+
+/// Deterministic pseudo-random records
+fn records(count: usize) -> Vec<Record> {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..count)
+        .map(|_| Record {
+            bucket: (next() % 64) as u16,
+            name: format!("record-{}", next() % 256),
+            version: next(),
+        })
+        .collect()
+}
+
+fn bench_sort(c: &mut Criterion) {
+    const COUNT: usize = 10_000;
+
+    let values = records(COUNT);
+    let mut group = c.benchmark_group("sort_by");
+
+    group.bench_function("ord_cmp", |b| {
+        b.iter_batched_ref(
+            || values.clone(),
+            |values| black_box(values).sort_by(Ord::cmp),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("cmp_mono", |b| {
+        b.iter_batched_ref(
+            || values.clone(),
+            |values| black_box(values).sort_by(Record::cmp_mono),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sort);
+criterion_main!(benches);
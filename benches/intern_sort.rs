@@ -0,0 +1,78 @@
+//! Compares sorting records by a long category string with a regular sort and with
+//! `intern_sort`, for few and for many distinct categories
+//!
+//! With a handful of categories `intern_sort` compares strings only while building the intern
+//! table and is expected to be faster. With many categories lookups in the table take as many
+//! comparisons as the regular sort, so `intern_sort` is expected to be slower. Run with
+//! `cargo bench --bench intern_sort`
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+
+#[derive(Clone)]
+pub struct Record {
+    pub category: String,
+    pub id: u32,
+}
+
+/// Deterministic pseudo-random records with `categories` distinct categories sharing a long
+/// common prefix
+fn records(count: usize, categories: u64) -> Vec<Record> {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    let prefix = "warehouse/north/aisle-12/shelf-3/".repeat(4);
+
+    (0..count as u32)
+        .map(|id| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            Record {
+                category: format!("{}{}", prefix, state % categories),
+                id,
+            }
+        })
+        .collect()
+}
+
+fn bench_sort(c: &mut Criterion) {
+    const COUNT: usize = 20_000;
+
+    let mut group = c.benchmark_group("sort_by_category");
+
+    for &categories in &[4, 8, 64, 20_000] {
+        let records = records(COUNT, categories);
+
+        group.bench_with_input(
+            BenchmarkId::new("naive", categories),
+            &records,
+            |b, records| {
+                b.iter_batched_ref(
+                    || records.clone(),
+                    |values| black_box(values).sort_by(|a, b| a.category.cmp(&b.category)),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("intern_sort", categories),
+            &records,
+            |b, records| {
+                b.iter_batched_ref(
+                    || records.clone(),
+                    |values| ord_by_key::intern_sort(black_box(values), |r| &r.category),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sort);
+criterion_main!(benches);
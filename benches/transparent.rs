@@ -0,0 +1,95 @@
+//! Compares `transparent` mode with hand-written delegation and with a key selector
+//!
+//! Run with `cargo bench --bench transparent`
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use ord_by_key::ord_eq_by_key_selector;
+use std::cmp::Ordering;
+
+#[ord_eq_by_key_selector(transparent)]
+#[repr(transparent)]
+pub struct TransparentId(u64);
+
+#[ord_eq_by_key_selector(|(id)| id)]
+pub struct SelectorId(u64);
+
+#[repr(transparent)]
+pub struct ManualId(u64);
+
+impl Ord for ManualId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for ManualId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ManualId {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for ManualId {}
+
+/// Deterministic pseudo-random ids
+fn ids(count: usize) -> impl Iterator<Item = u64> {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+
+    (0..count).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+fn bench_sort(c: &mut Criterion) {
+    const COUNT: usize = 10_000;
+
+    let mut group = c.benchmark_group("sort");
+
+    group.bench_function("manual", |b| {
+        let values: Vec<_> = ids(COUNT).map(ManualId).collect();
+        b.iter_batched_ref(
+            || values.iter().map(|v| ManualId(v.0)).collect::<Vec<_>>(),
+            |values| black_box(values).sort_unstable(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("transparent", |b| {
+        let values: Vec<_> = ids(COUNT).map(TransparentId).collect();
+        b.iter_batched_ref(
+            || {
+                values
+                    .iter()
+                    .map(|v| TransparentId(v.0))
+                    .collect::<Vec<_>>()
+            },
+            |values| black_box(values).sort_unstable(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("selector", |b| {
+        let values: Vec<_> = ids(COUNT).map(SelectorId).collect();
+        b.iter_batched_ref(
+            || values.iter().map(|v| SelectorId(v.0)).collect::<Vec<_>>(),
+            |values| black_box(values).sort_unstable(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sort);
+criterion_main!(benches);
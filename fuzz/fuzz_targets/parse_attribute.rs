@@ -0,0 +1,21 @@
+//! Feeds arbitrary token streams to the attribute parser. Parser must never panic, all malformed
+//! input has to be reported as `syn::Error`
+//!
+//! Run with `cargo +nightly fuzz run parse_attribute` from the repository root
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+#[allow(dead_code)]
+#[path = "../../ord_by_key_derive/src/attr.rs"]
+mod attr;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(data) = std::str::from_utf8(data) {
+        if let Ok(tokens) = proc_macro2::TokenStream::from_str(data) {
+            let _ = syn::parse2::<attr::MacroAttribute>(tokens);
+        }
+    }
+});
@@ -0,0 +1,206 @@
+//! `ord_by_key_advanced` macro, which implements comparison and hashing traits by independent
+//! lists of keys
+
+use crate::attr::AdvancedAttribute;
+use crate::attr::KeySelector;
+use crate::attr::Keys;
+use crate::attr::ParamDefinition;
+use crate::Comparison;
+use crate::KeyBound;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Ident;
+
+pub(crate) fn expand(attr: AdvancedAttribute, input: DeriveInput) -> syn::Result<TokenStream> {
+    if let Data::Union(data) = &input.data {
+        return Err(syn::Error::new(
+            data.union_token.span,
+            "`ord_by_key_advanced` supports only structs and enums",
+        ));
+    }
+
+    let ord = match attr.ord_keys {
+        Some(keys) => Some(crate::comparison(
+            keys,
+            &input,
+            "_ord_eq_by_key_selector",
+            KeyBound::Ord,
+        )?),
+        None => None,
+    };
+
+    let eq = match attr.eq_keys {
+        Some(keys) => Some(crate::comparison(
+            keys,
+            &input,
+            "_ord_eq_by_key_selector_eq",
+            KeyBound::Eq,
+        )?),
+        None => None,
+    };
+
+    let hash = match attr.hash_keys {
+        Some(keys) => Some(hashing(keys, &input)?),
+        None => None,
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut funcs = quote! {};
+    let mut assertions = quote! {};
+    let mut trait_impls = quote! {};
+
+    if let Some(ord) = ord {
+        let Comparison {
+            funcs: ord_funcs,
+            assertions: ord_assertions,
+            cmp,
+            ..
+        } = ord;
+
+        funcs.extend(ord_funcs);
+        assertions.extend(ord_assertions);
+        trait_impls.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    #cmp
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(self.cmp(other))
+                }
+            }
+        });
+    }
+
+    if let Some(eq) = eq {
+        let Comparison {
+            funcs: eq_funcs,
+            assertions: eq_assertions,
+            eq,
+            ..
+        } = eq;
+
+        funcs.extend(eq_funcs);
+        assertions.extend(eq_assertions);
+        trait_impls.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    #eq
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause { }
+        });
+    }
+
+    if let Some(Hashing {
+        funcs: hash_funcs,
+        assertions: hash_assertions,
+        hash,
+    }) = hash
+    {
+        let hasher = Ident::new("H", Span::mixed_site());
+
+        funcs.extend(hash_funcs);
+        assertions.extend(hash_assertions);
+        trait_impls.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+                fn hash<#hasher: ::core::hash::Hasher>(&self, state: &mut #hasher) {
+                    #hash
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #input
+
+        #assertions
+
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #funcs
+        }
+
+        #trait_impls
+    })
+}
+
+/// Generated code hashing values by keys
+struct Hashing {
+    funcs: TokenStream,
+    assertions: TokenStream,
+    /// Body of [`Hash::hash`](core::hash::Hash::hash)
+    hash: TokenStream,
+}
+
+/// Generates hashing of all keys in order, the same way as a tuple of keys is hashed. Only modes
+/// which produce keys are supported, e.g. `cmp:` expressions compare without producing a key
+fn hashing(keys: Keys, input: &DeriveInput) -> syn::Result<Hashing> {
+    let key_selector = match keys {
+        Keys::KeySelector(key_selector) => key_selector,
+        Keys::Methods(methods) => KeySelector::from_methods(methods),
+        Keys::EnumFields(span) | Keys::Transparent(span) => {
+            return Err(syn::Error::new(
+                span,
+                "`hash` keys require key expressions (e.g. `|p| p.key`), `fields`, `methods` \
+                 or `by_index` mode",
+            ))
+        }
+        keys => keys.into_key_selector(crate::struct_fields(input, "`fields` mode")?)?,
+    };
+
+    if let (ParamDefinition::Tuple(_), Data::Enum(_)) = (&key_selector.param, &input.data) {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`|(a, b, ...)|` parameter syntax is supported only for structs, \
+             use `|e| match e { ... }` for enums",
+        ));
+    }
+
+    crate::check_zero_keys(&key_selector, input)?;
+
+    let comparator = key_selector
+        .key_selectors
+        .iter()
+        .zip(&key_selector.comparators)
+        .find(|(_, comparator)| **comparator);
+
+    if let Some((key, _)) = comparator {
+        return Err(syn::Error::new(
+            key.span(),
+            "`cmp:` expressions compare values without producing a key, so they cannot be hashed",
+        ));
+    }
+
+    let name = &input.ident;
+    let (funcs, assertions, names) = crate::key_selector_funcs(
+        &key_selector,
+        name,
+        "_ord_eq_by_key_selector_hash",
+        KeyBound::Hash,
+    );
+
+    Ok(Hashing {
+        funcs,
+        assertions,
+        hash: quote! {
+            #(
+                ::core::hash::Hash::hash(&Self::#names(self), state);
+            )*
+        },
+    })
+}
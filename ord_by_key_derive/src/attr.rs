@@ -0,0 +1,1584 @@
+//! Syntax of the macro attribute
+//!
+//! This module doesn't depend on `proc_macro` (only on `syn` and `proc_macro2`), so parsing can
+//! be exercised outside of the compiler, e.g. by the fuzz target in `fuzz/`
+
+use proc_macro2::Punct;
+use proc_macro2::Spacing;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
+use syn::bracketed;
+use syn::parenthesized;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::parse_quote;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token;
+use syn::Expr;
+use syn::Field;
+use syn::Fields;
+use syn::Ident;
+use syn::LitInt;
+use syn::LitStr;
+use syn::Member;
+use syn::Token;
+use syn::Visibility;
+
+/// container for syntax of attribute
+/// section; section; ...
+/// where each section is either keys definition or comma-delimited list of options
+/// (`ord = keys` and `eq = keys` have to be the last option of their section)
+pub(crate) struct MacroAttribute {
+    pub(crate) ord_keys: Keys,
+    pub(crate) eq_keys: Option<Keys>,
+    pub(crate) extremes: Option<Extremes>,
+    pub(crate) missing_fields: Option<MissingFields>,
+    pub(crate) option_ord: Option<NonePlacement>,
+    pub(crate) vis: Option<Visibility>,
+    pub(crate) partial_only: bool,
+    pub(crate) nan: Nan,
+    pub(crate) debug_counters: bool,
+    pub(crate) debug_keys: Option<Span>,
+    pub(crate) cmp_mono: bool,
+    pub(crate) sorted: Option<Span>,
+    pub(crate) par_sort: Option<Span>,
+    pub(crate) min_queue: Option<Ident>,
+    pub(crate) max_queue: Option<Ident>,
+    pub(crate) btree_desc: Option<Ident>,
+    pub(crate) fifo_tiebreak: Option<Span>,
+    pub(crate) content_tiebreak: Option<Span>,
+    pub(crate) hash_residual: Option<HashResidual>,
+    pub(crate) has_key: Option<HasKey>,
+    pub(crate) cache: Option<Ident>,
+    pub(crate) cfg: Option<LitStr>,
+}
+
+/// container for syntax of attribute of `ord_by_key_advanced`
+/// ord = keys; eq = keys; hash = keys
+/// where every list is optional, but at least one is present
+pub(crate) struct AdvancedAttribute {
+    pub(crate) ord_keys: Option<Keys>,
+    pub(crate) eq_keys: Option<Keys>,
+    pub(crate) hash_keys: Option<Keys>,
+}
+
+/// container for syntax of `hash_residual` option
+/// hash_residual = [field, ...]
+pub(crate) struct HashResidual {
+    pub(crate) span: Span,
+    pub(crate) members: Vec<Member>,
+}
+
+/// container for syntax of `gen_has_key` option
+/// gen_has_key = Type
+pub(crate) struct HasKey {
+    pub(crate) span: Span,
+    pub(crate) ty: syn::Type,
+}
+
+/// container for syntax of `gen_option_ord` option value
+/// none_first
+/// or
+/// none_last
+pub(crate) enum NonePlacement {
+    First,
+    Last,
+}
+
+/// container for syntax of `partial_only` option modifier
+/// partial_only
+/// or
+/// partial_only(nan = none)
+/// or
+/// partial_only(nan = equal)
+#[derive(Clone, Copy)]
+pub(crate) enum Nan {
+    None,
+    Equal,
+}
+
+/// container for syntax of `gen_extremes` option value
+/// max_name / min_name
+pub(crate) struct Extremes {
+    pub(crate) max: Ident,
+    _slash: Token![/],
+    pub(crate) min: Ident,
+}
+
+/// container for syntax of `warn_missing_fields` option
+/// warn_missing_fields
+/// or
+/// warn_missing_fields(skip(member, member, ...))
+pub(crate) struct MissingFields {
+    pub(crate) skip: Vec<Member>,
+}
+
+/// container for syntax of keys definition
+/// | ident | expression, expression, ....
+/// or
+/// fields
+/// or
+/// fields(take = N)
+/// or
+/// enum_fields
+/// or
+/// methods = [ident, ident, ...]
+/// or
+/// by_index = [index, index desc, ...]
+/// or
+/// transparent
+/// or
+/// by_field_comparator = member
+pub(crate) enum Keys {
+    KeySelector(KeySelector),
+    Fields { take: Option<LitInt> },
+    EnumFields(Span),
+    Methods(Vec<Ident>),
+    ByIndex(Vec<IndexKey>),
+    Transparent(Span),
+    FieldComparator { span: Span, field: Member },
+}
+
+/// container for syntax of an element of `by_index` list
+/// index
+/// or
+/// index desc
+pub(crate) struct IndexKey {
+    pub(crate) index: LitInt,
+    pub(crate) desc: bool,
+}
+
+/// container for syntax of key selector
+/// | ident | expression, expression, ....
+/// or
+/// | ident, ident | expression, cmp: expression, ...
+/// Expressions are comma-delimited, there can be no expressions only for types without data
+/// Last comma is optional
+pub(crate) struct KeySelector {
+    _bar1: Token![|],
+    pub(crate) param: ParamDefinition,
+    _bar2: Token![|],
+    pub(crate) key_selectors: Vec<Expr>,
+    /// Whether expression with the same index is a `cmp:` expression, which compares two values
+    /// and returns `Ordering`, rather than a key
+    pub(crate) comparators: Vec<bool>,
+}
+
+pub(crate) enum ParamDefinition {
+    SingleIdentifier(Ident),
+    Tuple(Vec<Ident>),
+    /// `|a, b|`, keys bind `a`, `cmp:` expressions bind both
+    Pair(Ident, Ident),
+}
+
+impl KeySelector {
+    /// Key selector which compares all fields in order of declaration, same as `#[derive(Ord)]`.
+    /// Fields are always borrowed, which makes it work regardless of whether field types
+    /// implement [`Copy`] or not
+    fn from_fields<'a>(fields: impl Iterator<Item = &'a Field>) -> Self {
+        let param = Ident::new(
+            "_ord_eq_by_key_selector_fields",
+            proc_macro2::Span::mixed_site(),
+        );
+
+        let key_selectors: Vec<_> = fields
+            .enumerate()
+            .map(|(i, field)| {
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(i.into()),
+                };
+
+                parse_quote! { &#param.#member }
+            })
+            .collect();
+
+        KeySelector {
+            _bar1: Default::default(),
+            param: ParamDefinition::SingleIdentifier(param),
+            _bar2: Default::default(),
+            comparators: vec![false; key_selectors.len()],
+            key_selectors,
+        }
+    }
+
+    /// Key selector which calls methods of `&self` in order. Return types are not known, so results
+    /// are used as they are, e.g. a getter returning `&str` borrows and a getter returning `u32`
+    /// copies
+    pub(crate) fn from_methods(methods: Vec<Ident>) -> Self {
+        let param = Ident::new(
+            "_ord_eq_by_key_selector_methods",
+            proc_macro2::Span::mixed_site(),
+        );
+
+        let key_selectors: Vec<_> = methods
+            .iter()
+            .map(|method| parse_quote! { #param.#method() })
+            .collect();
+
+        KeySelector {
+            _bar1: Default::default(),
+            param: ParamDefinition::SingleIdentifier(param),
+            _bar2: Default::default(),
+            comparators: vec![false; key_selectors.len()],
+            key_selectors,
+        }
+    }
+
+    /// Key selector which compares the key cached in `field`, borrowing it
+    pub(crate) fn from_cache(field: &Ident) -> Self {
+        let param = Ident::new(
+            "_ord_eq_by_key_selector_cache",
+            proc_macro2::Span::mixed_site(),
+        );
+
+        let key_selectors = vec![parse_quote! { &#param.#field }];
+
+        KeySelector {
+            _bar1: Default::default(),
+            param: ParamDefinition::SingleIdentifier(param),
+            _bar2: Default::default(),
+            comparators: vec![false; key_selectors.len()],
+            key_selectors,
+        }
+    }
+
+    /// Key selector which compares unnamed fields by index in specified order, borrowing them
+    /// the same way as `fields` mode. Fields with `desc` are compared in reverse order
+    fn from_indices(keys: &[IndexKey], fields: &Fields) -> syn::Result<Self> {
+        if !matches!(fields, Fields::Unnamed(_)) {
+            return Err(syn::Error::new(
+                keys[0].index.span(),
+                "`by_index` is supported only for structs with unnamed fields",
+            ));
+        }
+
+        let param = Ident::new(
+            "_ord_eq_by_key_selector_fields",
+            proc_macro2::Span::mixed_site(),
+        );
+
+        let mut indices = Vec::new();
+        let mut key_selectors = Vec::new();
+
+        for key in keys {
+            let index: usize = key.index.base10_parse()?;
+
+            if index >= fields.len() {
+                return Err(syn::Error::new(
+                    key.index.span(),
+                    format!(
+                        "index must be less than number of fields ({})",
+                        fields.len()
+                    ),
+                ));
+            }
+
+            if indices.contains(&index) {
+                return Err(syn::Error::new(
+                    key.index.span(),
+                    format!("index {} is specified more than once", index),
+                ));
+            }
+
+            indices.push(index);
+
+            let member = Member::Unnamed(syn::Index {
+                index: index as u32,
+                span: key.index.span(),
+            });
+
+            key_selectors.push(if key.desc {
+                parse_quote! { ::core::cmp::Reverse(&#param.#member) }
+            } else {
+                parse_quote! { &#param.#member }
+            });
+        }
+
+        Ok(KeySelector {
+            _bar1: Default::default(),
+            param: ParamDefinition::SingleIdentifier(param),
+            _bar2: Default::default(),
+            comparators: vec![false; key_selectors.len()],
+            key_selectors,
+        })
+    }
+}
+
+impl Keys {
+    pub(crate) fn into_key_selector(self, fields: &Fields) -> syn::Result<KeySelector> {
+        match self {
+            Keys::KeySelector(key_selector) => Ok(key_selector),
+            Keys::Fields { take: None } => Ok(KeySelector::from_fields(fields.iter())),
+            Keys::Fields { take: Some(take) } => {
+                let n: usize = take.base10_parse()?;
+
+                if n == 0 || n > fields.len() {
+                    return Err(syn::Error::new(
+                        take.span(),
+                        format!(
+                            "`take` must be between 1 and number of fields ({})",
+                            fields.len()
+                        ),
+                    ));
+                }
+
+                Ok(KeySelector::from_fields(fields.iter().take(n)))
+            }
+            Keys::Methods(methods) => Ok(KeySelector::from_methods(methods)),
+            Keys::ByIndex(keys) => KeySelector::from_indices(&keys, fields),
+            Keys::Transparent(span) | Keys::FieldComparator { span, .. } => {
+                Err(syn::Error::new(span, "this mode doesn't use key selectors"))
+            }
+            Keys::EnumFields(span) => Err(syn::Error::new(
+                span,
+                "`enum_fields` mode is supported only for enums, use `fields` for structs",
+            )),
+        }
+    }
+}
+
+mod kw {
+    syn::custom_keyword!(fields);
+    syn::custom_keyword!(enum_fields);
+    syn::custom_keyword!(methods);
+    syn::custom_keyword!(by_index);
+    syn::custom_keyword!(transparent);
+    syn::custom_keyword!(by_field_comparator);
+    syn::custom_keyword!(unsafe_dynamic);
+    syn::custom_keyword!(desc);
+    syn::custom_keyword!(take);
+    syn::custom_keyword!(ord);
+    syn::custom_keyword!(eq);
+    syn::custom_keyword!(hash);
+    syn::custom_keyword!(unsafe_inconsistent);
+    syn::custom_keyword!(gen_extremes);
+    syn::custom_keyword!(warn_missing_fields);
+    syn::custom_keyword!(skip);
+    syn::custom_keyword!(partial_only);
+    syn::custom_keyword!(nan);
+    syn::custom_keyword!(none);
+    syn::custom_keyword!(equal);
+    syn::custom_keyword!(debug_counters);
+    syn::custom_keyword!(debug_keys);
+    syn::custom_keyword!(gen_cmp_mono);
+    syn::custom_keyword!(gen_sorted);
+    syn::custom_keyword!(gen_par_sort);
+    syn::custom_keyword!(gen_option_ord);
+    syn::custom_keyword!(gen_min_queue);
+    syn::custom_keyword!(gen_max_queue);
+    syn::custom_keyword!(gen_btree_desc);
+    syn::custom_keyword!(none_first);
+    syn::custom_keyword!(none_last);
+    syn::custom_keyword!(vis);
+    syn::custom_keyword!(fifo_tiebreak);
+    syn::custom_keyword!(content_tiebreak);
+    syn::custom_keyword!(hash_residual);
+    syn::custom_keyword!(gen_has_key);
+    syn::custom_keyword!(cache);
+    syn::custom_keyword!(cfg);
+    syn::custom_keyword!(nulls);
+    syn::custom_keyword!(cmp);
+    syn::custom_keyword!(first);
+    syn::custom_keyword!(last);
+}
+
+/// Maximum nesting depth of the attribute. `syn` parses expressions and types recursively, so
+/// pathologically nested input would overflow the stack and abort compilation instead of producing
+/// an error. Real key expressions are nowhere near this limit
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// Returns true for keywords after which `syn` parses the rest of the expression recursively,
+/// e.g. `if` of `else if` chains
+fn is_nesting_keyword(ident: &Ident) -> bool {
+    ident == "if" || ident == "return" || ident == "break" || ident == "box" || ident == "move"
+}
+
+/// Returns true for keywords which are followed by an operand, so operators after them are
+/// prefix operators
+fn is_prefix_keyword(ident: &Ident) -> bool {
+    [
+        "as", "box", "break", "const", "dyn", "else", "for", "if", "impl", "in", "let", "match",
+        "move", "mut", "ref", "return", "static", "unsafe", "where", "while", "yield",
+    ]
+    .iter()
+    .any(|keyword| ident == keyword)
+}
+
+/// Group being traversed by [`check_tokens`] and nesting levels opened in it since the last `,`
+/// or `;`
+struct Level {
+    tokens: proc_macro2::token_stream::IntoIter,
+    /// Whether the previous token ends an operand, operators after operands are binary
+    after_operand: bool,
+    /// Characters of the operator being read, joint punctuation is a single operator
+    operator: String,
+    /// Whether the operator being read follows an operand
+    binary: bool,
+    /// Whether the operator being read closes generic arguments
+    closes_angle: bool,
+    /// `<` of generic arguments and qualified paths, closed by `>`. Comparisons can't be told
+    /// from generics, so they are closed by binary operators which can't appear in types
+    angles: usize,
+    /// Prefix unary operators, which apply only to the operand after them, so they are closed by
+    /// the same binary operators as `angles`
+    unary: usize,
+    /// Closures, prefix ranges, assignments and keywords such as `return`, which take the rest of
+    /// the expression as an operand, so they are closed only by `,` or `;`
+    extending: usize,
+}
+
+impl Level {
+    fn new(tokens: TokenStream) -> Self {
+        Level {
+            tokens: tokens.into_iter(),
+            after_operand: false,
+            operator: String::new(),
+            binary: false,
+            closes_angle: false,
+            angles: 0,
+            unary: 0,
+            extending: 0,
+        }
+    }
+
+    fn nesting(&self) -> usize {
+        self.angles + self.unary + self.extending
+    }
+
+    /// Updates levels with the next character of an operator, returns true if it opens a level
+    fn punct(&mut self, punct: &Punct) -> bool {
+        let c = punct.as_char();
+        let mut opens = false;
+
+        if self.operator.is_empty() {
+            self.binary = self.after_operand;
+            self.closes_angle = false;
+        } else if self.binary && !is_operator_start(&format!("{}{}", self.operator, c)) {
+            // Binary operator ends before the character, the rest of the joint punctuation is
+            // prefix operators, e.g. `a--b` is `a - (-b)`
+            opens = self.end_binary();
+            self.binary = false;
+        }
+
+        let previous = self.operator.chars().last();
+        let dots = self
+            .operator
+            .chars()
+            .rev()
+            .take_while(|&c| c == '.')
+            .count();
+        self.operator.push(c);
+        self.after_operand = false;
+
+        opens |= match c {
+            ',' | ';' => {
+                self.angles = 0;
+                self.unary = 0;
+                self.extending = 0;
+                false
+            }
+            // Arrows `->` and `=>` don't close generics
+            '>' if previous == Some('-') || previous == Some('=') => false,
+            '>' if self.angles > 0 => {
+                self.angles -= 1;
+                self.closes_angle = true;
+                self.after_operand = true;
+                false
+            }
+            '<' => {
+                self.angles += 1;
+                true
+            }
+            '?' => {
+                self.after_operand = true;
+                false
+            }
+            // `..` of ranges, `.` of field access doesn't nest
+            '.' if dots % 2 == 0 && punct.spacing() == Spacing::Joint && !self.binary => {
+                self.extending += 1;
+                true
+            }
+            // Opening bars of closures, including empty `||`
+            '|' if !self.binary => {
+                self.extending += 1;
+                true
+            }
+            '&' | '*' | '-' | '!' if !self.binary => {
+                self.unary += 1;
+                true
+            }
+            _ => false,
+        };
+
+        if punct.spacing() == Spacing::Alone {
+            if self.binary {
+                opens |= self.end_binary();
+            }
+            self.operator.clear();
+        }
+
+        opens
+    }
+
+    /// Updates levels at the end of binary operator `self.operator`, returns true if it opens a
+    /// level
+    fn end_binary(&mut self) -> bool {
+        let operator = std::mem::take(&mut self.operator);
+
+        // Binary operators which can't appear in types end operands of preceding prefix
+        // operators, and show that preceding `<` are comparisons
+        let in_types = self.closes_angle
+            || operator.chars().all(|c| c == '<')
+            || matches!(
+                operator.as_str(),
+                "->" | "=>" | "=" | "+" | ":" | "::" | "." | "?" | "!" | "#" | "$" | "@"
+            );
+        if !in_types {
+            self.angles = 0;
+            self.unary = 0;
+        }
+
+        // Assignments are right associative, so `syn` parses chains of them recursively
+        let assignment = operator.ends_with('=')
+            && !operator.starts_with('.')
+            && !matches!(operator.as_str(), "==" | "!=" | "<=" | ">=");
+        if assignment {
+            self.extending += 1;
+        }
+
+        assignment
+    }
+}
+
+/// Returns true if `operator` is a punctuation token of Rust or its beginning
+fn is_operator_start(operator: &str) -> bool {
+    [
+        "&&", "||", "<<=", ">>=", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "==", "!=", "<=",
+        ">=", "...", "..=", "::", "->", "=>",
+    ]
+    .iter()
+    .any(|token| token.starts_with(operator))
+}
+
+/// Rejects token streams which would make `syn` panic or overflow the stack instead of returning
+/// an error:
+/// * tokens nested deeper than [`MAX_NESTING_DEPTH`]. Every group counts as a level, and so do
+///   tokens which make `syn` parse the rest of the expression or type recursively: `<` of
+///   generics, closures, prefix operators and ranges, assignments and some keywords, until the
+///   point where `syn` would return from the recursion, or until `,` or `;`. Binary operators are
+///   parsed iteratively, so they don't count, and long chains of them are fine
+/// * literals which `syn` doesn't recognize, such as C string literals `c"..."` (added to the
+///   language after `syn` 1.0) or placeholders `(/*ERROR*/)` which `proc_macro2` produces for
+///   invalid literals
+fn check_tokens(tokens: TokenStream) -> syn::Result<()> {
+    let mut stack = vec![Level::new(tokens)];
+
+    while let Some(top) = stack.last_mut() {
+        let token = match top.tokens.next() {
+            Some(token) => token,
+            None => {
+                stack.pop();
+                continue;
+            }
+        };
+
+        if !matches!(token, TokenTree::Punct(_)) && !top.operator.is_empty() {
+            top.operator.clear();
+        }
+
+        let opens = match &token {
+            TokenTree::Group(_) => true,
+            TokenTree::Punct(punct) => top.punct(punct),
+            TokenTree::Ident(ident) => {
+                let opens = is_nesting_keyword(ident);
+                if opens {
+                    top.extending += 1;
+                }
+                top.after_operand = !is_prefix_keyword(ident);
+                opens
+            }
+            TokenTree::Literal(literal) => {
+                if !is_supported_literal(&literal.to_string()) {
+                    return Err(syn::Error::new(literal.span(), "unsupported literal"));
+                }
+                top.after_operand = true;
+                false
+            }
+        };
+
+        let depth = stack.len() + stack.iter().map(Level::nesting).sum::<usize>();
+        if opens && depth > MAX_NESTING_DEPTH {
+            return Err(syn::Error::new(
+                token.span(),
+                format!(
+                    "attribute is nested too deeply (more than {} levels)",
+                    MAX_NESTING_DEPTH
+                ),
+            ));
+        }
+
+        if let TokenTree::Group(group) = token {
+            stack.last_mut().unwrap().after_operand = true;
+            stack.push(Level::new(group.stream()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Mirrors the dispatch of `syn::Lit::new`, which panics on literals it doesn't recognize
+fn is_supported_literal(repr: &str) -> bool {
+    let repr = repr.as_bytes();
+
+    match repr.first() {
+        Some(b'"') | Some(b'r') | Some(b'\'') | Some(b'-') => true,
+        Some(b'0'..=b'9') => true,
+        Some(b't') | Some(b'f') => repr == b"true" || repr == b"false",
+        Some(b'b') => matches!(repr.get(1), Some(b'"') | Some(b'r') | Some(b'\'')),
+        _ => false,
+    }
+}
+
+/// Returns true if there are no more tokens in the current section of the attribute
+pub(crate) fn is_section_end(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![;])
+}
+
+pub(crate) fn set_once<T>(
+    target: &mut Option<T>,
+    value: T,
+    span: Span,
+    name: &str,
+) -> syn::Result<()> {
+    if target.is_some() {
+        return Err(syn::Error::new(
+            span,
+            format!("{} is specified more than once", name),
+        ));
+    }
+
+    *target = Some(value);
+    Ok(())
+}
+
+impl Parse for MacroAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        check_tokens(input.fork().parse()?)?;
+
+        let mut ord_keys = None;
+        let mut eq_keys = None;
+        let mut eq_span = None;
+        let mut unsafe_inconsistent = false;
+        let mut unsafe_dynamic = false;
+        let mut extremes = None;
+        let mut missing_fields = None;
+        let mut partial_only = None;
+        let mut debug_counters = None;
+        let mut debug_keys = None;
+        let mut cmp_mono = None;
+        let mut sorted = None;
+        let mut par_sort = None;
+        let mut option_ord = None;
+        let mut min_queue = None;
+        let mut max_queue = None;
+        let mut btree_desc = None;
+        let mut vis = None;
+        let mut fifo_tiebreak = None;
+        let mut content_tiebreak = None;
+        let mut hash_residual = None;
+        let mut has_key = None;
+        let mut cache = None;
+        let mut cfg = None;
+
+        loop {
+            if input.peek(Token![|])
+                || input.peek(kw::fields)
+                || input.peek(kw::enum_fields)
+                || input.peek(kw::methods)
+                || input.peek(kw::by_index)
+                || input.peek(kw::transparent)
+                || input.peek(kw::by_field_comparator)
+            {
+                let span = input.span();
+                set_once(&mut ord_keys, input.parse()?, span, "`ord` keys")?;
+            } else {
+                loop {
+                    let lookahead = input.lookahead1();
+
+                    if lookahead.peek(kw::ord) {
+                        let span = input.parse::<kw::ord>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut ord_keys, input.parse()?, span, "`ord` keys")?;
+                    } else if lookahead.peek(kw::eq) {
+                        let span = input.parse::<kw::eq>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut eq_keys, input.parse()?, span, "`eq` keys")?;
+                        eq_span = Some(span);
+                    } else if lookahead.peek(kw::unsafe_inconsistent) {
+                        input.parse::<kw::unsafe_inconsistent>()?;
+                        unsafe_inconsistent = true;
+                    } else if lookahead.peek(kw::unsafe_dynamic) {
+                        input.parse::<kw::unsafe_dynamic>()?;
+                        unsafe_dynamic = true;
+                    } else if lookahead.peek(kw::gen_extremes) {
+                        let span = input.parse::<kw::gen_extremes>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut extremes, input.parse()?, span, "`gen_extremes`")?;
+                    } else if lookahead.peek(kw::warn_missing_fields) {
+                        let span = input.parse::<kw::warn_missing_fields>()?.span;
+                        let value = input.parse()?;
+                        set_once(&mut missing_fields, value, span, "`warn_missing_fields`")?;
+                    } else if lookahead.peek(kw::partial_only) {
+                        let span = input.parse::<kw::partial_only>()?.span;
+                        let value = (span, input.parse()?);
+                        set_once(&mut partial_only, value, span, "`partial_only`")?;
+                    } else if lookahead.peek(kw::debug_counters) {
+                        let span = input.parse::<kw::debug_counters>()?.span;
+                        set_once(&mut debug_counters, (), span, "`debug_counters`")?;
+                    } else if lookahead.peek(kw::debug_keys) {
+                        let span = input.parse::<kw::debug_keys>()?.span;
+                        set_once(&mut debug_keys, span, span, "`debug_keys`")?;
+                    } else if lookahead.peek(kw::gen_cmp_mono) {
+                        let span = input.parse::<kw::gen_cmp_mono>()?.span;
+                        set_once(&mut cmp_mono, (), span, "`gen_cmp_mono`")?;
+                    } else if lookahead.peek(kw::gen_sorted) {
+                        let span = input.parse::<kw::gen_sorted>()?.span;
+                        set_once(&mut sorted, span, span, "`gen_sorted`")?;
+                    } else if lookahead.peek(kw::gen_par_sort) {
+                        let span = input.parse::<kw::gen_par_sort>()?.span;
+                        set_once(&mut par_sort, span, span, "`gen_par_sort`")?;
+                    } else if lookahead.peek(kw::gen_option_ord) {
+                        let span = input.parse::<kw::gen_option_ord>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut option_ord, input.parse()?, span, "`gen_option_ord`")?;
+                    } else if lookahead.peek(kw::gen_min_queue) {
+                        let span = input.parse::<kw::gen_min_queue>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut min_queue, input.parse()?, span, "`gen_min_queue`")?;
+                    } else if lookahead.peek(kw::gen_max_queue) {
+                        let span = input.parse::<kw::gen_max_queue>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut max_queue, input.parse()?, span, "`gen_max_queue`")?;
+                    } else if lookahead.peek(kw::gen_btree_desc) {
+                        let span = input.parse::<kw::gen_btree_desc>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut btree_desc, input.parse()?, span, "`gen_btree_desc`")?;
+                    } else if lookahead.peek(kw::vis) {
+                        let span = input.parse::<kw::vis>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut vis, parse_visibility(input)?, span, "`vis`")?;
+                    } else if lookahead.peek(kw::fifo_tiebreak) {
+                        let span = input.parse::<kw::fifo_tiebreak>()?.span;
+                        set_once(&mut fifo_tiebreak, span, span, "`fifo_tiebreak`")?;
+                    } else if lookahead.peek(kw::content_tiebreak) {
+                        let span = input.parse::<kw::content_tiebreak>()?.span;
+                        set_once(&mut content_tiebreak, span, span, "`content_tiebreak`")?;
+                    } else if lookahead.peek(kw::hash_residual) {
+                        let span = input.parse::<kw::hash_residual>()?.span;
+                        input.parse::<Token![=]>()?;
+                        let members = parse_hash_residual(input, span)?;
+                        let value = HashResidual { span, members };
+                        set_once(&mut hash_residual, value, span, "`hash_residual`")?;
+                    } else if lookahead.peek(kw::gen_has_key) {
+                        let span = input.parse::<kw::gen_has_key>()?.span;
+                        input.parse::<Token![=]>()?;
+                        let ty = input.parse()?;
+                        set_once(&mut has_key, HasKey { span, ty }, span, "`gen_has_key`")?;
+                    } else if lookahead.peek(kw::cache) {
+                        let span = input.parse::<kw::cache>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut cache, input.parse()?, span, "`cache`")?;
+                    } else if lookahead.peek(kw::cfg) {
+                        let span = input.parse::<kw::cfg>()?.span;
+                        input.parse::<Token![=]>()?;
+                        set_once(&mut cfg, parse_feature(input)?, span, "`cfg`")?;
+                    } else {
+                        return Err(lookahead.error());
+                    }
+
+                    if is_section_end(input) {
+                        break;
+                    }
+
+                    input.parse::<Token![,]>()?;
+
+                    if is_section_end(input) {
+                        break;
+                    }
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![;]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+        }
+
+        let ord_keys = match ord_keys {
+            Some(ord_keys) => ord_keys,
+            None => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "expected key expressions, e.g. `|p| p.key`, or `fields`",
+                ))
+            }
+        };
+
+        if let (Keys::FieldComparator { span, .. }, false) = (&ord_keys, unsafe_dynamic) {
+            return Err(syn::Error::new(
+                *span,
+                "comparator stored in a field can differ between values, which breaks total order, \
+                 add `unsafe_dynamic` to acknowledge that",
+            ));
+        }
+
+        if let Some(Keys::FieldComparator { span, .. }) = &eq_keys {
+            return Err(syn::Error::new(
+                *span,
+                "`by_field_comparator` cannot be used for `eq` keys",
+            ));
+        }
+
+        if let (Some(eq_span), false) = (eq_span, unsafe_inconsistent) {
+            return Err(syn::Error::new(
+                eq_span,
+                "separate `eq` keys make `Eq` inconsistent with `Ord`, \
+                 add `unsafe_inconsistent` to acknowledge that",
+            ));
+        }
+
+        if let Some((partial_only, _)) = partial_only {
+            if eq_span.is_some()
+                || extremes.is_some()
+                || option_ord.is_some()
+                || has_key.is_some()
+                || cmp_mono.is_some()
+                || sorted.is_some()
+                || par_sort.is_some()
+                || min_queue.is_some()
+                || max_queue.is_some()
+                || btree_desc.is_some()
+            {
+                return Err(syn::Error::new(
+                    partial_only,
+                    "`partial_only` cannot be combined with separate `eq` keys, `gen_extremes`, \
+                     `gen_option_ord`, `gen_has_key`, `gen_cmp_mono`, `gen_sorted`, \
+                     `gen_par_sort`, `gen_min_queue`, `gen_max_queue` or `gen_btree_desc`",
+                ));
+            }
+        }
+
+        if let (Some(fifo_tiebreak), Keys::Transparent(_)) = (fifo_tiebreak, &ord_keys) {
+            return Err(syn::Error::new(
+                fifo_tiebreak,
+                "`fifo_tiebreak` adds a field, so it cannot be combined with `transparent` mode",
+            ));
+        }
+
+        if let (Some(fifo_tiebreak), Some(_)) = (fifo_tiebreak, &cfg) {
+            return Err(syn::Error::new(
+                fifo_tiebreak,
+                "`fifo_tiebreak` adds a field, so it cannot be combined with `cfg`, values \
+                 couldn't be created without the feature",
+            ));
+        }
+
+        let final_keys: Vec<_> = [
+            (fifo_tiebreak, "`fifo_tiebreak`"),
+            (content_tiebreak, "`content_tiebreak`"),
+            (hash_residual.as_ref().map(|h| h.span), "`hash_residual`"),
+        ]
+        .iter()
+        .filter_map(|&(span, name)| Some((span?, name)))
+        .collect();
+
+        if let [(_, first), (span, second), ..] = final_keys.as_slice() {
+            return Err(syn::Error::new(
+                *span,
+                format!(
+                    "{} and {} cannot be combined, only one final key can break ties",
+                    first, second
+                ),
+            ));
+        }
+
+        Ok(MacroAttribute {
+            ord_keys,
+            eq_keys,
+            extremes,
+            missing_fields,
+            option_ord,
+            vis,
+            partial_only: partial_only.is_some(),
+            nan: partial_only.map_or(Nan::None, |(_, nan)| nan),
+            debug_counters: debug_counters.is_some(),
+            debug_keys,
+            cmp_mono: cmp_mono.is_some(),
+            sorted,
+            par_sort,
+            min_queue,
+            max_queue,
+            btree_desc,
+            fifo_tiebreak,
+            content_tiebreak,
+            hash_residual,
+            has_key,
+            cache,
+            cfg,
+        })
+    }
+}
+
+impl Parse for AdvancedAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        check_tokens(input.fork().parse()?)?;
+
+        let mut ord_keys = None;
+        let mut eq_keys = None;
+        let mut hash_keys = None;
+
+        loop {
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::ord) {
+                let span = input.parse::<kw::ord>()?.span;
+                input.parse::<Token![=]>()?;
+                set_once(&mut ord_keys, input.parse()?, span, "`ord` keys")?;
+            } else if lookahead.peek(kw::eq) {
+                let span = input.parse::<kw::eq>()?.span;
+                input.parse::<Token![=]>()?;
+                set_once(&mut eq_keys, input.parse()?, span, "`eq` keys")?;
+            } else if lookahead.peek(kw::hash) {
+                let span = input.parse::<kw::hash>()?.span;
+                input.parse::<Token![=]>()?;
+                set_once(&mut hash_keys, input.parse()?, span, "`hash` keys")?;
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![;]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+        }
+
+        let all_keys = ord_keys.iter().chain(&eq_keys).chain(&hash_keys);
+
+        for keys in all_keys {
+            if let Keys::FieldComparator { span, .. } = keys {
+                return Err(syn::Error::new(
+                    *span,
+                    "`by_field_comparator` is not supported by `ord_by_key_advanced`",
+                ));
+            }
+        }
+
+        Ok(AdvancedAttribute {
+            ord_keys,
+            eq_keys,
+            hash_keys,
+        })
+    }
+}
+
+impl Parse for MissingFields {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if !input.peek(token::Paren) {
+            return Ok(MissingFields { skip: vec![] });
+        }
+
+        let content;
+        let _ = parenthesized!(content in input);
+
+        content.parse::<kw::skip>()?;
+
+        let members;
+        let _ = parenthesized!(members in content);
+
+        let skip: Punctuated<Member, Token![,]> = members.parse_terminated(Member::parse)?;
+
+        Ok(MissingFields {
+            skip: skip.into_iter().collect(),
+        })
+    }
+}
+
+/// Parses non-empty bracketed list of fields of `hash_residual`
+fn parse_hash_residual(input: ParseStream, span: Span) -> syn::Result<Vec<Member>> {
+    let content;
+    let _ = bracketed!(content in input);
+
+    let members: Punctuated<Member, Token![,]> = content.parse_terminated(Member::parse)?;
+
+    if members.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`hash_residual` requires at least one field, e.g. `hash_residual = [tags]`",
+        ));
+    }
+
+    Ok(members.into_iter().collect())
+}
+
+/// Parses name of the feature of `cfg` option
+fn parse_feature(input: ParseStream) -> syn::Result<LitStr> {
+    let feature: LitStr = input.parse()?;
+
+    if feature.value().is_empty() {
+        return Err(syn::Error::new(
+            feature.span(),
+            "expected name of a feature, e.g. `cfg = \"ordering\"`",
+        ));
+    }
+
+    Ok(feature)
+}
+
+/// Parses explicit visibility, private visibility has to be spelled as `pub(self)`. `syn` also
+/// accepts unstable `crate` visibility, which is rejected
+fn parse_visibility(input: ParseStream) -> syn::Result<Visibility> {
+    let span = input.span();
+
+    match input.parse()? {
+        Visibility::Inherited | Visibility::Crate(_) => Err(syn::Error::new(
+            span,
+            "expected visibility, e.g. `pub(crate)`",
+        )),
+        vis => Ok(vis),
+    }
+}
+
+impl Parse for NonePlacement {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(kw::none_first) {
+            input.parse::<kw::none_first>()?;
+            Ok(NonePlacement::First)
+        } else if lookahead.peek(kw::none_last) {
+            input.parse::<kw::none_last>()?;
+            Ok(NonePlacement::Last)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for Nan {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if !input.peek(token::Paren) {
+            return Ok(Nan::None);
+        }
+
+        let content;
+        let _ = parenthesized!(content in input);
+
+        content.parse::<kw::nan>()?;
+        content.parse::<Token![=]>()?;
+
+        let lookahead = content.lookahead1();
+
+        if lookahead.peek(kw::none) {
+            content.parse::<kw::none>()?;
+            Ok(Nan::None)
+        } else if lookahead.peek(kw::equal) {
+            content.parse::<kw::equal>()?;
+            Ok(Nan::Equal)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for Extremes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let extremes = Extremes {
+            max: input.parse()?,
+            _slash: input.parse()?,
+            min: input.parse()?,
+        };
+
+        if extremes.max == extremes.min {
+            return Err(syn::Error::new(
+                extremes.min.span(),
+                "`gen_extremes` names for maximum and minimum must be different",
+            ));
+        }
+
+        Ok(extremes)
+    }
+}
+
+impl Parse for Keys {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::fields) {
+            input.parse::<kw::fields>()?;
+
+            if !input.peek(token::Paren) {
+                return Ok(Keys::Fields { take: None });
+            }
+
+            let content;
+            let _ = parenthesized!(content in input);
+
+            content.parse::<kw::take>()?;
+            content.parse::<Token![=]>()?;
+            let take = content.parse()?;
+
+            return Ok(Keys::Fields { take: Some(take) });
+        }
+
+        if input.peek(kw::enum_fields) {
+            let span = input.parse::<kw::enum_fields>()?.span;
+            return Ok(Keys::EnumFields(span));
+        }
+
+        if input.peek(kw::transparent) {
+            let span = input.parse::<kw::transparent>()?.span;
+            return Ok(Keys::Transparent(span));
+        }
+
+        if input.peek(kw::by_field_comparator) {
+            let span = input.parse::<kw::by_field_comparator>()?.span;
+            input.parse::<Token![=]>()?;
+            let field = input.parse()?;
+            return Ok(Keys::FieldComparator { span, field });
+        }
+
+        if input.peek(kw::methods) {
+            let span = input.parse::<kw::methods>()?.span;
+            input.parse::<Token![=]>()?;
+
+            let content;
+            let _ = bracketed!(content in input);
+
+            let methods: Punctuated<Ident, Token![,]> = content.parse_terminated(Ident::parse)?;
+
+            if methods.is_empty() {
+                return Err(syn::Error::new(span, "`methods` list cannot be empty"));
+            }
+
+            return Ok(Keys::Methods(methods.into_iter().collect()));
+        }
+
+        if input.peek(kw::by_index) {
+            let span = input.parse::<kw::by_index>()?.span;
+            input.parse::<Token![=]>()?;
+
+            let content;
+            let _ = bracketed!(content in input);
+
+            let keys: Punctuated<IndexKey, Token![,]> =
+                content.parse_terminated(IndexKey::parse)?;
+
+            if keys.is_empty() {
+                return Err(syn::Error::new(span, "`by_index` list cannot be empty"));
+            }
+
+            return Ok(Keys::ByIndex(keys.into_iter().collect()));
+        }
+
+        Ok(Keys::KeySelector(input.parse()?))
+    }
+}
+
+impl Parse for IndexKey {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let index = input.parse()?;
+        let desc = input.peek(kw::desc);
+
+        if desc {
+            input.parse::<kw::desc>()?;
+        }
+
+        Ok(IndexKey { index, desc })
+    }
+}
+
+impl Parse for KeySelector {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _bar1 = input.parse()?;
+        let param = input.parse()?;
+        let _bar2 = input.parse()?;
+
+        let mut key_selectors = vec![];
+        let mut comparators = vec![];
+
+        // No keys, allowed only for types without data, which is checked with the type
+        while !is_section_end(input) {
+            // `cmp::...` is a path which starts a regular key expression
+            let comparator =
+                input.peek(kw::cmp) && input.peek2(Token![:]) && !input.peek2(Token![::]);
+
+            if comparator {
+                let span = input.parse::<kw::cmp>()?.span;
+                input.parse::<Token![:]>()?;
+
+                if !matches!(param, ParamDefinition::Pair(..)) {
+                    return Err(syn::Error::new(
+                        span,
+                        "`cmp:` expressions require two parameters, e.g. `|a, b| cmp: a.x.cmp(&b.x)`",
+                    ));
+                }
+            }
+
+            let mut expr: Expr = input.parse()?;
+
+            if !comparator && input.peek(kw::nulls) {
+                expr = parse_nulls(input, expr)?;
+            }
+
+            key_selectors.push(expr);
+            comparators.push(comparator);
+
+            if is_section_end(input) {
+                break;
+            }
+
+            let _: Token!(,) = input.parse()?;
+        }
+
+        Ok(KeySelector {
+            _bar1,
+            param,
+            _bar2,
+            key_selectors,
+            comparators,
+        })
+    }
+}
+
+/// Parses `nulls = first` or `nulls = last` modifier of key expression `expr`, and wraps the key
+/// into `NoneFirst` or `NoneLast`, which accept both `Option<T>` and `&Option<T>`
+fn parse_nulls(input: ParseStream, expr: Expr) -> syn::Result<Expr> {
+    let span = input.parse::<kw::nulls>()?.span;
+    input.parse::<Token![=]>()?;
+
+    let lookahead = input.lookahead1();
+    let wrapper = if lookahead.peek(kw::first) {
+        Ident::new("NoneFirst", input.parse::<kw::first>()?.span)
+    } else if lookahead.peek(kw::last) {
+        Ident::new("NoneLast", input.parse::<kw::last>()?.span)
+    } else {
+        return Err(lookahead.error());
+    };
+
+    if let Expr::Type(_) = expr {
+        return Err(syn::Error::new(
+            span,
+            "`nulls` cannot be combined with type annotation of the key",
+        ));
+    }
+
+    Ok(syn::parse_quote_spanned! {expr.span()=>
+        ::ord_by_key::#wrapper::from(#expr)
+    })
+}
+
+impl Parse for ParamDefinition {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(token::Paren) {
+            let content;
+            let _ = parenthesized!(content in input);
+
+            let params: Punctuated<Ident, Token![,]> = content.parse_terminated(Ident::parse)?;
+
+            let params: Vec<_> = params.into_iter().collect();
+
+            return Ok(ParamDefinition::Tuple(params));
+        }
+
+        let ident: Ident = input.parse()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            return Ok(ParamDefinition::Pair(ident, input.parse()?));
+        }
+
+        Ok(ParamDefinition::SingleIdentifier(ident))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdvancedAttribute;
+    use super::MacroAttribute;
+
+    fn parse_err(attr: &str) -> String {
+        match syn::parse_str::<MacroAttribute>(attr) {
+            Ok(_) => panic!("`{}` should not parse", attr),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_malformed() {
+        for attr in &[
+            "",
+            ";",
+            ";;",
+            "|",
+            "||",
+            "|p| ,",
+            "|p| a,,",
+            "|(,)| a",
+            "|(a,,)| a",
+            "fields()",
+            "fields(take)",
+            "fields(take = )",
+            "fields(take = 1.5)",
+            "fields(take = 1, take = 2)",
+            "ord =",
+            "eq = |p| a",
+            "|p| a; ord = fields",
+            "gen_extremes = a/",
+            "gen_extremes = a/a",
+            "|p| a; warn_missing_fields, warn_missing_fields",
+            "|p| a; warn_missing_fields()",
+            "|p| a; warn_missing_fields(skip)",
+            "|p| a; warn_missing_fields(skip(a b))",
+            "|p| a; warn_missing_fields(skip(1.5))",
+            "|p| a; partial_only, partial_only",
+            "|p| a; debug_counters, debug_counters",
+            "|p| a; gen_cmp_mono, gen_cmp_mono",
+            "|p| a; gen_cmp_mono = a",
+            "|p| a; partial_only, gen_cmp_mono",
+            "|p| a; partial_only, gen_extremes = a/b",
+            "|p| a; partial_only, gen_option_ord = none_last",
+            "|p| a; gen_option_ord",
+            "|p| a; gen_option_ord = none",
+            "|p| a; gen_option_ord = none_first, gen_option_ord = none_last",
+            "|p| a; fifo_tiebreak, fifo_tiebreak",
+            "|p| a; gen_has_key",
+            "|p| a; gen_has_key = ",
+            "|p| a; gen_has_key = u64, gen_has_key = u32",
+            "|p| a; cache",
+            "|p| a; cache = ",
+            "|p| a; cache = 0",
+            "|p| a; cache = a.b",
+            "|p| a; cache = a, cache = b",
+            "|p| cmp: a",
+            "|(p)| cmp: a",
+            "|a, b| cmp:",
+            "|a, b| cmp: a nulls = last",
+            "|a, b, c| a",
+            "|a,| a",
+            "|p| a nulls",
+            "|p| a nulls =",
+            "|p| a nulls = none",
+            "|p| a nulls = first last",
+            "|p| a nulls = first nulls = last",
+            "|p| a: Option<u32> nulls = last",
+            "|p| a; partial_only, gen_has_key = u64",
+            "|p| a; fifo_tiebreak = a",
+            "transparent; fifo_tiebreak",
+            "|p| a; content_tiebreak, content_tiebreak",
+            "|p| a; content_tiebreak = a",
+            "|p| a; fifo_tiebreak, content_tiebreak",
+            "|p| a; hash_residual",
+            "|p| a; hash_residual = ",
+            "|p| a; hash_residual = []",
+            "|p| a; hash_residual = [,]",
+            "|p| a; hash_residual = [a.b]",
+            "|p| a; hash_residual = (a)",
+            "|p| a; hash_residual = [a], hash_residual = [b]",
+            "|p| a; content_tiebreak, hash_residual = [a]",
+            "|p| a; hash_residual = [a], fifo_tiebreak",
+            "|p| a; debug_keys, debug_keys",
+            "|p| a; debug_keys = a",
+            "|p| a; debug_keys(a)",
+            "|p| a; gen_sorted, gen_sorted",
+            "|p| a; gen_sorted = a",
+            "|p| a; gen_sorted, partial_only",
+            "|p| a; gen_par_sort, gen_par_sort",
+            "|p| a; gen_par_sort = a",
+            "|p| a; gen_par_sort, partial_only",
+            "|p| a; partial_only()",
+            "|p| a; partial_only(nan)",
+            "|p| a; partial_only(nan = )",
+            "|p| a; partial_only(nan = last)",
+            "|p| a; partial_only(nan = equal, nan = none)",
+            "|p| a; partial_only = equal",
+            "|p| a; partial_only(nan = equal), partial_only",
+            "|p| a; gen_min_queue",
+            "|p| a; gen_min_queue = ",
+            "|p| a; gen_min_queue = a::B",
+            "|p| a; gen_min_queue = A, gen_min_queue = B",
+            "|p| a; gen_max_queue = \"A\"",
+            "|p| a; gen_max_queue = A, gen_max_queue = B",
+            "|p| a; gen_max_queue = A, partial_only",
+            "|p| a; partial_only, gen_min_queue = A",
+            "|p| a; gen_btree_desc",
+            "|p| a; gen_btree_desc = ",
+            "|p| a; gen_btree_desc = Map<V>",
+            "|p| a; gen_btree_desc = A, gen_btree_desc = B",
+            "|p| a; gen_btree_desc = A, partial_only",
+            "|p| a; cfg",
+            "|p| a; cfg = ",
+            "|p| a; cfg = ordering",
+            "|p| a; cfg = \"\"",
+            "|p| a; cfg = \"a\", cfg = \"b\"",
+            "|p| a; cfg(feature = \"a\")",
+            "|p| a; cfg = \"a\", fifo_tiebreak",
+            "transparent(a)",
+            "transparent = a",
+            "methods",
+            "methods = ",
+            "methods = []",
+            "methods = [,]",
+            "methods = [a.b]",
+            "methods = (a)",
+            "by_index",
+            "by_index = []",
+            "by_index = [a]",
+            "by_index = [0 asc]",
+            "by_index = [0 desc desc]",
+            "by_field_comparator",
+            "by_field_comparator = ",
+            "by_field_comparator = a.b",
+            "by_field_comparator = f",
+            "by_field_comparator = f; unsafe_inconsistent",
+            "|p| a; eq = by_field_comparator = f; unsafe_inconsistent, unsafe_dynamic",
+            "|p| a; vis",
+            "|p| a; vis =",
+            "|p| a; vis = crate",
+            "|p| a; vis = pub(crate), vis = pub",
+            "ord = |p| a; eq = |p| b; unsafe_inconsistent, partial_only",
+        ] {
+            parse_err(attr);
+        }
+    }
+
+    #[test]
+    fn test_advanced() {
+        for attr in &[
+            "ord = |p| a",
+            "eq = fields",
+            "hash = methods = [a]",
+            "ord = |p| a; eq = |p| b; hash = |p| c",
+            "hash = |p| c; ord = enum_fields;",
+        ] {
+            assert!(
+                syn::parse_str::<AdvancedAttribute>(attr).is_ok(),
+                "{}",
+                attr
+            );
+        }
+
+        for attr in &[
+            "",
+            ";",
+            "|p| a",
+            "ord",
+            "ord =",
+            "hash = |p| a eq = |p| b",
+            "ord = |p| a;; eq = |p| b",
+            "ord = |p| a; ord = |p| b",
+            "hash = |p| a; hash = |p| a",
+            "ord = |p| a; unsafe_inconsistent",
+            "ord = |p| a; partial_only",
+            "ord = by_field_comparator = f",
+            "hash = by_field_comparator = f",
+        ] {
+            if syn::parse_str::<AdvancedAttribute>(attr).is_ok() {
+                panic!("`{}` should not parse", attr);
+            }
+        }
+    }
+
+    #[test]
+    fn test_deep_nesting() {
+        // These used to overflow the stack inside of `syn`
+        for (open, close) in &[("(", ")"), ("[", "]"), ("{", "}")] {
+            let attr = format!("|p| {}a{}", open.repeat(1000), close.repeat(1000));
+            assert!(parse_err(&attr).contains("nested too deeply"));
+        }
+
+        let attr = format!("fields{}", "(".repeat(1000) + &")".repeat(1000));
+        assert!(parse_err(&attr).contains("nested too deeply"));
+
+        // Found by fuzzing, qualified paths `<<<...` are parsed recursively
+        let attr = format!("|p| r /= {}", "<".repeat(1000));
+        assert!(parse_err(&attr).contains("nested too deeply"));
+
+        let attr = format!("|p| x as {}u8{}", "Vec<".repeat(1000), ">".repeat(1000));
+        assert!(parse_err(&attr).contains("nested too deeply"));
+
+        // Found by fuzzing, nested closures
+        let attr = format!("|p| {}a", "|| ".repeat(1000));
+        assert!(parse_err(&attr).contains("nested too deeply"));
+
+        // Assignments are right associative
+        for operator in &["=", "+=", "<<="] {
+            let attr = format!("|p| {}a", format!("a {} ", operator).repeat(1000));
+            assert!(parse_err(&attr).contains("nested too deeply"));
+        }
+
+        // Joint punctuation after a binary operator is prefix operators, `a - (-(-(...)))`
+        let attr = format!("|p| a{}a", "-".repeat(1000));
+        assert!(parse_err(&attr).contains("nested too deeply"));
+
+        // Prefix operators inside of generics still count after the generic type
+        let attr = format!("|p| x as {}u8", "&&Box<dyn Fn() -> ".repeat(1000));
+        assert!(parse_err(&attr).contains("nested too deeply"));
+
+        for prefix in &["-", "!", "*", "&", "..", "return ", "if a {} else "] {
+            let attr = format!("|p| {}a", prefix.repeat(1000));
+            assert!(parse_err(&attr).contains("nested too deeply"));
+        }
+    }
+
+    #[test]
+    fn test_error_literal() {
+        // Found by fuzzing, `syn` used to panic with "Unrecognized literal: `(/*ERROR*/)`"
+        let attr = "|p| { let x =(/*ERROR*/)9 return x * x; }";
+        assert!(parse_err(attr).contains("unsupported literal"));
+
+        // Found by fuzzing, `syn` 1.0 doesn't know about C string literals
+        let attr = "|p| c\"key\"";
+        assert!(parse_err(attr).contains("unsupported literal"));
+    }
+
+    #[test]
+    fn test_nesting_within_limit() {
+        let attr = format!("|p| {}a{}", "(".repeat(32), ")".repeat(32));
+        assert!(syn::parse_str::<MacroAttribute>(&attr).is_ok());
+
+        // Closed angle brackets don't accumulate
+        let attr = format!(
+            "|p| {}",
+            ["p.a < p.b", "p.x as Vec<u8>"].repeat(100).join(", ")
+        );
+        assert!(syn::parse_str::<MacroAttribute>(&attr).is_ok());
+
+        // Field access is not a range
+        let attr = format!("|p| p{}", ".a".repeat(100));
+        assert!(syn::parse_str::<MacroAttribute>(&attr).is_ok());
+
+        // Binary operators are parsed iteratively, so long chains of them are fine
+        let clauses: Vec<_> = (0..100).map(|i| format!("p.a == {}", i)).collect();
+        let attr = format!("|p| {}", clauses.join(" || "));
+        assert!(syn::parse_str::<MacroAttribute>(&attr).is_ok());
+
+        let attr = format!("|p| p.a{}", " - p.b".repeat(100));
+        assert!(syn::parse_str::<MacroAttribute>(&attr).is_ok());
+
+        for clause in &["!p.a.is_empty()", "p.a != -1", "p.a < 1", "*p.a & 1 != 0"] {
+            let attr = format!("|p| {}", vec![*clause; 100].join(" && "));
+            assert!(syn::parse_str::<MacroAttribute>(&attr).is_ok());
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! `gen_btree_desc` option, which generates a map keyed by the type in descending order
+
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+use syn::GenericParam;
+use syn::Ident;
+use syn::Visibility;
+
+/// Returns name of the type parameter of values of the map, `V` unless the type already has a
+/// parameter with this name
+fn value_param(input: &DeriveInput) -> Ident {
+    let taken = |name: &str| {
+        input.generics.params.iter().any(|param| match param {
+            GenericParam::Type(param) => param.ident == name,
+            GenericParam::Const(param) => param.ident == name,
+            GenericParam::Lifetime(_) => false,
+        })
+    };
+
+    let name = (0..)
+        .map(|i| match i {
+            0 => "V".to_string(),
+            i => format!("V{}", i),
+        })
+        .find(|name| !taken(name))
+        .unwrap();
+
+    Ident::new(&name, Span::call_site())
+}
+
+/// Generates newtype `map` of `BTreeMap<Reverse<Self>, V>`, which iterates keys from the largest,
+/// and whose methods take and return keys of the type instead of `Reverse`
+pub(crate) fn btree_desc(map: &Ident, input: &DeriveInput, vis: &Visibility) -> TokenStream {
+    let name = &input.ident;
+    let (_, key_generics, _) = input.generics.split_for_impl();
+    let key = quote! { #name #key_generics };
+
+    let value = value_param(input);
+    let mut generics = input.generics.clone();
+    generics.params.push(syn::parse_quote! { #value });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let inner = quote! { ::std::collections::BTreeMap<::core::cmp::Reverse<#key>, #value> };
+    let doc = format!(
+        "Map keyed by [`{}`] which iterates keys in descending order",
+        name
+    );
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #map #generics (#inner) #where_clause;
+
+        #[automatically_derived]
+        impl #impl_generics #map #ty_generics #where_clause {
+            /// Creates an empty map
+            #vis fn new() -> Self {
+                #map(::std::collections::BTreeMap::new())
+            }
+
+            /// Inserts the value by the key, returns the previous value of the key
+            #vis fn insert(&mut self, key: #key, value: #value) -> ::core::option::Option<#value> {
+                self.0.insert(::core::cmp::Reverse(key), value)
+            }
+
+            /// Returns the value of the key. The key is cloned, since the map stores keys in
+            /// `Reverse`
+            #vis fn get<Q>(&self, key: &Q) -> ::core::option::Option<&#value>
+            where
+                Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = #key>,
+            {
+                self.0.get(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+            }
+
+            /// Returns mutable reference to the value of the key. The key is cloned, since the
+            /// map stores keys in `Reverse`
+            #vis fn get_mut<Q>(&mut self, key: &Q) -> ::core::option::Option<&mut #value>
+            where
+                Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = #key>,
+            {
+                self.0.get_mut(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+            }
+
+            /// Returns true if the map has the key. The key is cloned, since the map stores keys
+            /// in `Reverse`
+            #vis fn contains_key<Q>(&self, key: &Q) -> bool
+            where
+                Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = #key>,
+            {
+                self.0.contains_key(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+            }
+
+            /// Removes the key from the map, returns its value. The key is cloned, since the map
+            /// stores keys in `Reverse`
+            #vis fn remove<Q>(&mut self, key: &Q) -> ::core::option::Option<#value>
+            where
+                Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = #key>,
+            {
+                self.0.remove(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+            }
+
+            /// Returns entries with keys in `range` from the largest key. `range` is in ascending
+            /// order of keys, e.g. `a..b` returns keys from `b` (exclusive) down to `a`
+            /// (inclusive). Bounds are cloned, since the map stores keys in `Reverse`. Panics in
+            /// the same cases as [`BTreeMap::range`](std::collections::BTreeMap::range)
+            #vis fn range<R, Q>(
+                &self,
+                range: R,
+            ) -> impl ::core::iter::DoubleEndedIterator<Item = (&#key, &#value)>
+            where
+                R: ::core::ops::RangeBounds<Q>,
+                Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = #key>,
+            {
+                let reverse = |bound: ::core::ops::Bound<&Q>| match bound {
+                    ::core::ops::Bound::Included(key) => ::core::ops::Bound::Included(
+                        ::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)),
+                    ),
+                    ::core::ops::Bound::Excluded(key) => ::core::ops::Bound::Excluded(
+                        ::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)),
+                    ),
+                    ::core::ops::Bound::Unbounded => ::core::ops::Bound::Unbounded,
+                };
+                let range = (reverse(range.end_bound()), reverse(range.start_bound()));
+
+                self.0.range(range).map(|(key, value)| (&key.0, value))
+            }
+
+            /// Returns entries from the largest key
+            #vis fn iter(&self) -> impl ::core::iter::DoubleEndedIterator<Item = (&#key, &#value)> {
+                self.0.iter().map(|(key, value)| (&key.0, value))
+            }
+
+            /// Returns number of entries in the map
+            #vis fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Returns true if the map has no entries
+            #vis fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            /// Returns the underlying map
+            #vis fn as_map(&self) -> &#inner {
+                &self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::default::Default for #map #ty_generics #where_clause {
+            fn default() -> Self {
+                #map::new()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::iter::Extend<(#key, #value)> for #map #ty_generics
+            #where_clause
+        {
+            fn extend<I: ::core::iter::IntoIterator<Item = (#key, #value)>>(&mut self, entries: I) {
+                self.0.extend(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (::core::cmp::Reverse(key), value)),
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::iter::FromIterator<(#key, #value)> for #map #ty_generics
+            #where_clause
+        {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = (#key, #value)>>(entries: I) -> Self {
+                #map(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (::core::cmp::Reverse(key), value))
+                        .collect(),
+                )
+            }
+        }
+    }
+}
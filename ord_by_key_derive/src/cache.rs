@@ -0,0 +1,84 @@
+//! `cache` option, which compares keys stored in a field of the struct instead of computing them
+
+use crate::attr::Keys;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::DeriveInput;
+use syn::Ident;
+use syn::Visibility;
+
+/// Generated code maintaining the cached key
+pub(crate) struct CachedKey {
+    /// Items of the inherent impl block: methods computing and refreshing the key, and the
+    /// check of the cached key
+    pub(crate) funcs: TokenStream,
+    /// Statement checking cached keys of `self` and `other` if `debug_assertions` are enabled
+    pub(crate) check: TokenStream,
+}
+
+/// Generates `compute_{field}` returning the value of key expressions, `refresh_{field}` storing
+/// it in `field`, and the check that the cached key is equal to the computed one
+pub(crate) fn cached_key(
+    field: &Ident,
+    keys: &Keys,
+    input: &DeriveInput,
+    vis: &Visibility,
+) -> syn::Result<CachedKey> {
+    let fields = crate::struct_fields(input, "`cache`")?;
+    let ty = match fields.iter().find(|f| f.ident.as_ref() == Some(field)) {
+        Some(f) => &f.ty,
+        None => {
+            return Err(syn::Error::new(
+                field.span(),
+                format!("`cache` field `{}` doesn't exist", field),
+            ))
+        }
+    };
+
+    let key = crate::key_value(keys, field.span(), "`cache`")?;
+    let compute = format_ident!("compute_{}", field);
+    let refresh = format_ident!("refresh_{}", field);
+    let check = Ident::new("_ord_eq_by_key_selector_check_cache", Span::mixed_site());
+
+    let compute_doc = format!("Computes the key which is cached in `{}`", field);
+    let refresh_doc = format!(
+        "Stores the key in `{}`, has to be called after modifying fields which the key depends on",
+        field
+    );
+    let message = format!(
+        "cached key `{}` of `{}` is stale, call `{}` after modifying fields which the key \
+         depends on",
+        field, input.ident, refresh
+    );
+
+    let funcs = quote! {
+        #[doc = #compute_doc]
+        #vis fn #compute(&self) -> #ty {
+            #key
+        }
+
+        #[doc = #refresh_doc]
+        #vis fn #refresh(&mut self) {
+            self.#field = self.#compute();
+        }
+
+        #[cfg(debug_assertions)]
+        fn #check(&self) {
+            if self.#compute() != self.#field {
+                ::core::panic!(#message);
+            }
+        }
+    };
+
+    let check = quote! {
+        #[cfg(debug_assertions)]
+        {
+            Self::#check(self);
+            Self::#check(other);
+        }
+    };
+
+    Ok(CachedKey { funcs, check })
+}
@@ -0,0 +1,106 @@
+//! `debug_keys` option, which generates formatting of keys of a value for debugging
+
+use crate::attr::Keys;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Expr;
+use syn::Ident;
+use syn::Visibility;
+
+/// Returns the argument of key expression `Reverse(x)`, if the key is wrapped into
+/// `core::cmp::Reverse` written as `Reverse`, `core::cmp::Reverse` or `std::cmp::Reverse`
+fn reversed(key: &Expr) -> Option<&Expr> {
+    match key {
+        Expr::Paren(paren) => reversed(&paren.expr),
+        Expr::Call(call) if call.args.len() == 1 => match &*call.func {
+            Expr::Path(path) if path.qself.is_none() => {
+                let segments: Vec<_> = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| match segment.arguments {
+                        syn::PathArguments::None => segment.ident.to_string(),
+                        _ => String::new(),
+                    })
+                    .collect();
+
+                match segments.as_slice() {
+                    [name] if name == "Reverse" && path.path.leading_colon.is_none() => {
+                        call.args.first()
+                    }
+                    [krate, cmp, name]
+                        if (krate == "core" || krate == "std")
+                            && cmp == "cmp"
+                            && name == "Reverse" =>
+                    {
+                        call.args.first()
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Generates `debug_keys`, which returns keys of `keys` formatted with `Debug` as a tuple, with
+/// keys in reverse order shown as the underlying value marked with `↓`
+pub(crate) fn debug_keys_func(
+    span: Span,
+    keys: &Keys,
+    vis: &Visibility,
+) -> syn::Result<TokenStream> {
+    crate::with_key_selector(keys, span, "`debug_keys`", |key_selector| {
+        let param = crate::param_pattern(&key_selector.param);
+        let debug_keys = Ident::new("_ord_eq_by_key_selector_DebugKeys", Span::mixed_site());
+        let tuple = Ident::new("_ord_eq_by_key_selector_tuple", Span::mixed_site());
+
+        let fields = key_selector
+            .key_selectors
+            .iter()
+            .map(|key| match reversed(key) {
+                Some(value) => {
+                    let body = crate::key_body(value);
+                    quote! {
+                        #tuple.field(&::core::format_args!("↓{:?}", { #body }));
+                    }
+                }
+                None => {
+                    let body = crate::key_body(key);
+                    quote! {
+                        #tuple.field(&{ #body });
+                    }
+                }
+            });
+
+        quote! {
+            /// Returns keys of the value, which format with [`Debug`](::core::fmt::Debug) as a
+            /// tuple in order of comparison. Keys in reverse order (`Reverse(key)`) are shown
+            /// as the key marked with `↓`
+            #vis fn debug_keys(&self) -> impl ::core::fmt::Debug + '_ {
+                struct #debug_keys<F>(F);
+
+                impl<F> ::core::fmt::Debug for #debug_keys<F>
+                where
+                    F: ::core::ops::Fn(&mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+                {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        (self.0)(f)
+                    }
+                }
+
+                #debug_keys(move |f: &mut ::core::fmt::Formatter<'_>| {
+                    #[allow(unused_variables)]
+                    let #param = self;
+                    let mut #tuple = f.debug_tuple("");
+
+                    #(#fields)*
+
+                    #tuple.finish()
+                })
+            }
+        }
+    })
+}
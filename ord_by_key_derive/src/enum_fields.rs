@@ -0,0 +1,178 @@
+//! `enum_fields` mode, which orders enums by variant and then by fields of the variant, same as
+//! `#[derive(Ord)]`
+
+use crate::attr::Nan;
+use crate::Comparison;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::DataEnum;
+use syn::Ident;
+use syn::Member;
+use syn::Type;
+
+/// Generates comparison matching both values as `(self, other)`. Values of the same variant are
+/// compared field by field in order of declaration, values of different variants are compared by
+/// index of the variant in order of declaration (explicit discriminant values are not used).
+pub(crate) fn comparison(data: &DataEnum, nan: Nan) -> Comparison {
+    let variant_index = Ident::new("_ord_eq_by_key_selector_variant_index", Span::mixed_site());
+
+    let mut index_arms = TokenStream::new();
+    let mut cmp_arms = TokenStream::new();
+    let mut partial_cmp_arms = TokenStream::new();
+    let mut eq_arms = TokenStream::new();
+
+    for (index, variant) in data.variants.iter().enumerate() {
+        let name = &variant.ident;
+
+        let members: Vec<Member> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(i.into()),
+            })
+            .collect();
+
+        let bindings = |prefix: &str| -> Vec<Ident> {
+            (0..members.len())
+                .map(|i| format_ident!("{}_{}", prefix, i, span = Span::mixed_site()))
+                .collect()
+        };
+        let self_fields = bindings("_ord_eq_by_key_selector_self");
+        let other_fields = bindings("_ord_eq_by_key_selector_other");
+
+        // Braced pattern works for unit, tuple and struct variants
+        let pattern = quote! {
+            (
+                Self::#name { #(#members: #self_fields,)* },
+                Self::#name { #(#members: #other_fields,)* },
+            )
+        };
+
+        // Field type is named explicitly, so a type which doesn't implement the trait is reported
+        // at the field
+        let types: Vec<&Type> = variant.fields.iter().map(|field| &field.ty).collect();
+        let call = |tr: TokenStream, method: Ident| {
+            types
+                .iter()
+                .zip(&self_fields)
+                .zip(&other_fields)
+                .map(|((ty, s), o)| quote_spanned! {ty.span()=> <#ty as #tr>::#method(#s, #o) })
+                .collect::<Vec<_>>()
+        };
+
+        let cmps = call(quote! { ::core::cmp::Ord }, format_ident!("cmp"));
+        let partial_cmps: Vec<_> = types
+            .iter()
+            .zip(&self_fields)
+            .zip(&other_fields)
+            .map(|((ty, s), o)| crate::partial_cmp_field(nan, ty, s, o))
+            .collect();
+        // With `nan = equal` fields are equal if `partial_cmp` says so, which makes NaN equal
+        let eqs = match nan {
+            Nan::None => call(quote! { ::core::cmp::PartialEq }, format_ident!("eq")),
+            Nan::Equal => partial_cmps
+                .iter()
+                .map(|partial_cmp| {
+                    quote! {
+                        (#partial_cmp == ::core::option::Option::Some(::core::cmp::Ordering::Equal))
+                    }
+                })
+                .collect(),
+        };
+
+        index_arms.extend(quote! {
+            Self::#name { .. } => #index,
+        });
+
+        cmp_arms.extend(quote! {
+            #pattern => {
+                #(
+                    let result = #cmps;
+
+                    if result != ::core::cmp::Ordering::Equal {
+                        return result;
+                    }
+                )*
+
+                return ::core::cmp::Ordering::Equal;
+            }
+        });
+
+        partial_cmp_arms.extend(quote! {
+            #pattern => {
+                #(
+                    let result = #partial_cmps;
+
+                    if result != ::core::option::Option::Some(::core::cmp::Ordering::Equal) {
+                        return result;
+                    }
+                )*
+
+                return ::core::option::Option::Some(::core::cmp::Ordering::Equal);
+            }
+        });
+
+        eq_arms.extend(quote! {
+            #pattern => {
+                #(
+                    if !#eqs {
+                        return false;
+                    }
+                )*
+
+                return true;
+            }
+        });
+    }
+
+    let funcs = quote! {
+        fn #variant_index(&self) -> usize {
+            match *self {
+                #index_arms
+            }
+        }
+    };
+
+    let compare_variants = quote! {
+        ::core::cmp::Ord::cmp(&Self::#variant_index(self), &Self::#variant_index(other))
+    };
+
+    let cmp = quote! {
+        match (self, other) {
+            #cmp_arms
+            // Unreachable if the enum has only one variant
+            #[allow(unreachable_patterns)]
+            _ => #compare_variants,
+        }
+    };
+
+    let partial_cmp = quote! {
+        match (self, other) {
+            #partial_cmp_arms
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::Some(#compare_variants),
+        }
+    };
+
+    let eq = quote! {
+        match (self, other) {
+            #eq_arms
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    };
+
+    Comparison {
+        funcs,
+        assertions: quote! {},
+        cmp,
+        partial_cmp,
+        eq,
+    }
+}
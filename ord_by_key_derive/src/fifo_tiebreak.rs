@@ -0,0 +1,83 @@
+//! `fifo_tiebreak` option, which breaks ties between values with equal keys in order of creation
+
+use crate::tiebreak::Tiebreak;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Field;
+use syn::Fields;
+use syn::Ident;
+use syn::Visibility;
+
+/// Name of the injected sequence number field
+fn seq_field() -> Ident {
+    Ident::new("_ord_eq_by_key_selector_seq", Span::call_site())
+}
+
+/// Returns the struct with injected private sequence number field, which is the last field, so
+/// it doesn't change positions of user-defined fields
+pub(crate) fn with_seq_field(input: &DeriveInput, span: Span) -> syn::Result<DeriveInput> {
+    let mut input = input.clone();
+    let seq = seq_field();
+
+    match &mut input.data {
+        Data::Struct(syn::DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields.named.push(Field::parse_named.parse2(quote! {
+            #[doc(hidden)]
+            #seq: u64
+        })?),
+        _ => {
+            return Err(syn::Error::new(
+                span,
+                "`fifo_tiebreak` is supported only for structs with named fields",
+            ))
+        }
+    }
+
+    Ok(input)
+}
+
+/// Generates `new` taking user-defined fields in order of declaration and assigning the next
+/// sequence number, counted per type
+pub(crate) fn constructor(input: &DeriveInput, vis: &Visibility) -> TokenStream {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => unreachable!("checked by `with_seq_field`"),
+    };
+
+    let names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
+    let types = fields.iter().map(|f| &f.ty);
+    let seq = seq_field();
+
+    quote! {
+        /// Creates a value which goes after all previously created values with equal keys
+        #[allow(clippy::too_many_arguments)]
+        #vis fn new(#(#names: #types),*) -> Self {
+            static _ORD_EQ_BY_KEY_SELECTOR_SEQ: ::core::sync::atomic::AtomicU64 =
+                ::core::sync::atomic::AtomicU64::new(0);
+
+            Self {
+                #(#names,)*
+                #seq: _ORD_EQ_BY_KEY_SELECTOR_SEQ
+                    .fetch_add(1, ::core::sync::atomic::Ordering::Relaxed),
+            }
+        }
+    }
+}
+
+/// Compares sequence numbers, which are cheaper to compare than keys, so they are compared
+/// first for equality
+pub(crate) fn seq_tiebreak() -> Tiebreak {
+    let seq = seq_field();
+
+    Tiebreak {
+        cmp: quote! { self.#seq.cmp(&other.#seq) },
+        eq: quote! { self.#seq == other.#seq },
+        eq_first: true,
+    }
+}
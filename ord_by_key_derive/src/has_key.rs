@@ -0,0 +1,32 @@
+//! `gen_has_key` option, which implements `ord_by_key::HasKey` returning values of key expressions
+
+use crate::attr::HasKey;
+use crate::attr::Keys;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::DeriveInput;
+
+/// Generates implementation of `HasKey` with key of type `has_key.ty`
+pub(crate) fn has_key_impl(
+    has_key: &HasKey,
+    keys: &Keys,
+    input: &DeriveInput,
+) -> syn::Result<TokenStream> {
+    let key = crate::key_value(keys, has_key.span, "`gen_has_key`")?;
+    let name = &input.ident;
+    let ty = &has_key.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Key which doesn't match the type is reported at the type
+    Ok(quote_spanned! {ty.span()=>
+        #[automatically_derived]
+        impl #impl_generics ::ord_by_key::HasKey for #name #ty_generics #where_clause {
+            type Key = #ty;
+
+            fn key(&self) -> #ty {
+                #key
+            }
+        }
+    })
+}
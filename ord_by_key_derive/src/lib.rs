@@ -0,0 +1,2452 @@
+//! Procedural macro implementation for the [`ord_by_key`](https://docs.rs/ord_by_key) crate.
+//!
+//! This crate is not intended to be used directly, use `ord_by_key` which re-exports
+//! the macro together with key helpers it can be combined with.
+
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+mod advanced;
+mod attr;
+mod btree_desc;
+mod cache;
+mod debug_keys;
+mod enum_fields;
+mod fifo_tiebreak;
+mod has_key;
+mod missing_fields;
+mod queue;
+#[cfg(test)]
+mod snapshot_tests;
+mod sorted;
+mod tiebreak;
+mod transparent;
+
+use attr::AdvancedAttribute;
+use attr::Extremes;
+use attr::KeySelector;
+use attr::Keys;
+use attr::MacroAttribute;
+use attr::Nan;
+use attr::NonePlacement;
+use attr::ParamDefinition;
+use proc_macro::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Ident;
+
+/// Implements [`Ord`], [`PartialOrd`], [`PartialEq`] and [`Eq`] for a struct.
+///
+/// Implemented comparison logic is based on a custom key extraction expression provided.
+/// During comparison, key extraction expression will be evaluated for both values and then
+/// resulted keys will be compared with each other. `ord_eq_by_key_selector` supports
+/// multiple key expressions, which allows implement logic "sort by ..., then by ..."
+///
+/// # Syntax
+/// ```compile_fail
+/// #[ord_eq_by_key_selector(|parameter| key_expressoin, key_expressoin, ...)]
+/// pub struct MyStruct {...}
+///
+/// #[ord_eq_by_key_selector(|(parameter, parameter, ...)| key_expressoin, key_expressoin, ...)]
+/// pub struct MyStruct (...)
+///
+/// #[ord_eq_by_key_selector(|parameter, parameter| key_expressoin, cmp: comparison_expression, ...)]
+/// pub struct MyStruct ...
+///
+/// #[ord_eq_by_key_selector(fields)]
+/// pub struct MyStruct ...
+///
+/// #[ord_eq_by_key_selector(enum_fields)]
+/// pub enum MyEnum ...
+///
+/// #[ord_eq_by_key_selector(methods = [method, method, ...])]
+/// pub struct MyStruct ...
+///
+/// #[ord_eq_by_key_selector(by_index = [index, index desc, ...])]
+/// pub struct MyStruct (...)
+///
+/// #[ord_eq_by_key_selector(transparent)]
+/// pub struct MyStruct (field)
+///
+/// #[ord_eq_by_key_selector(by_field_comparator = field; unsafe_dynamic)]
+/// pub struct MyStruct ...
+///
+/// #[ord_eq_by_key_selector(ord = |parameter| key_expressoin, ...; eq = |parameter| key_expressoin, ...; unsafe_inconsistent)]
+/// pub struct MyStruct ...
+///
+/// #[ord_eq_by_key_selector(|parameter| key_expressoin, ...; option, option, ...)]
+/// pub struct MyStruct ...
+/// ```
+/// * `parameter` - definition of the parameter or parameters which key expressions can use
+///   to access underlying struct or fields within the struct. There are 3 options for defining
+///   parameters:
+///     * `|a|` - this syntax is similar to syntax of a regular closure definition. There can be
+///       only one parameter name. Key expressions can access this parameter as a variable, it will
+///       have type `&Self`. Note that you can use this option for structs either with named or unnamed
+///       fields
+///     * `|(a, b, c, ...)|` - this syntax can be used if underlying struct is a defined with unnamed
+///       fields (e.g. `struct Hello(i32, String);`) to destruct reference to struct into a few references
+///       to individual fields in the struct. Number of parameter names must match number of fields in
+///       the struct.
+///     * `|a, b|` - key expressions access `a` the same way as `|a|`, and `cmp:` expressions
+///       access both values being compared. See [Comparison expressions](#comparison-expressions)
+///
+/// * `key_expression` - expression which produces a key for comparison. Expression can
+///   access `parameter_name` input and must return `impl Ord`. Multiple expressions can be
+///   provided, comma-separated (last comma is optional). Expression can be single-line, or
+///   multi-line enclosed in `{}`. Types without data (structs without fields, e.g.
+///   `struct Marker();`, and enums with a single variant without fields) can have no key
+///   expressions at all, e.g. `|m|`, which makes all values equal. This is useful for marker
+///   types used as parameters of generic code which requires [`Ord`].
+/// * `pub struct MyStruct ...` or  - definition of struct for which [`Ord`], [`PartialOrd`],
+///   [`PartialEq`] and [`Eq`] will be implemented
+/// * `fields` - instead of key expressions, compare all fields of the struct in order of
+///   declaration. See [Fields mode](#fields-mode)
+/// * `ord = ...; eq = ...; unsafe_inconsistent` - use different keys for [`Ord`] and [`Eq`]. See
+///   [Equality coarser than ordering](#equality-coarser-than-ordering)
+/// * `option` - options controlling generated code, separated from keys with `;`. See
+///   [Options](#options)
+///
+/// # Comparison logic
+/// Let's look at [`Ord::cmp`] implementation (rest of traits have similar implementation logic)
+///
+/// When 2 values `a` and `b` are compared using auto-implemented comparison method, lexicographic
+/// comparison logic will be executed:
+///
+/// 1) use specified `key_expression`, and evaluate comparison keys for `a` and `b` (`key_a` and `key_b`)
+/// 2) compare `key_a` and `key_b` using their own [`Ord`] implementaiton
+/// 3) If comparisong result is not [`::core::cmp::Ordering::Equal`], return comparison reslut
+/// 4) Otherwise switch to next provided key expression and redo steps 1..4 using this expresison
+/// 5) If no key expressions left, return [`::core::cmp::Ordering::Equal`]
+///
+/// Note, all evaluations are lazy and happen only when they are necessary. For example, if first
+/// provided key expression gave us non-ambigious result, then rest of key expressions will not be
+/// evaluated.
+///
+/// # Key Expression implementation considerations and examples
+///
+/// In simple case, key expression returns one of fields from the underlying struct.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Person` will be ordered by it's field `age`
+/// #[ord_eq_by_key_selector(|p| p.age)]
+/// pub struct Person {
+///     pub first_name: String,
+///     pub last_name: String,
+///     pub age: usize,
+/// }
+/// ```
+///
+/// If return type does not implement [`Copy`], expression should return borrowed value
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Person` will be ordered by it's field `last_name`
+/// #[ord_eq_by_key_selector(|p| &p.last_name)]
+/// pub struct Person {
+///     pub first_name: String,
+///     pub last_name: String,
+///     pub age: usize,
+/// }
+///
+/// ```
+///
+/// Key expression can access nested fields and elements of tuples, including through smart
+/// pointers, e.g. `&w.0.1` or `&p.field.subfield.0`
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::sync::Arc;
+/// // `Wrap` will be ordered by the second element of the inner tuple
+/// #[ord_eq_by_key_selector(|(inner)| &inner.1)]
+/// pub struct Wrap(Arc<(i32, String)>);
+/// ```
+///
+/// If struct should be sorted by multiple fields, multiple expressions can be provided.
+/// Note, that parameter name should be specified only once, and each of expressions can
+/// use it
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Person` will be ordered by `last_name`, then by `first_name`
+/// #[ord_eq_by_key_selector(|p|
+///     &p.last_name,
+///     &p.first_name, )]
+/// pub struct Person {
+///     pub first_name: String,
+///     pub last_name: String,
+///     pub age: usize,
+/// }
+/// ```
+///
+/// If struct should be sorted by some keys in reverse order, you can use [`::core::cmp::Reverse`]
+/// container for values which should be sorted in reverse:
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use core::cmp::Reverse;
+/// // `Person` will be ordered by `last_name`, then by `first_name`, then by `age` in reverse
+/// #[ord_eq_by_key_selector(|p|
+///     &p.last_name,
+///     &p.first_name,
+///     Reverse(p.age),)]
+/// pub struct Person {
+///     pub first_name: String,
+///     pub last_name: String,
+///     pub age: usize,
+/// }
+/// ```
+///
+/// You can use multi-line block expression and access multiple fields. You can use explicit
+/// `return`
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // Sort Point by distance from the (0,0)
+/// #[ord_eq_by_key_selector(|p|
+///     {
+///         let x = p.x as i64;
+///         let y = p.y as i64;
+///
+///         // For sorting purposes, it's not necessary to take square root
+///         let distance = x * x + y * y;
+///         
+///         return distance;
+///     })]
+/// pub struct Point {
+///     pub x: i32,
+///     pub y: i32,
+/// }
+/// ```
+///
+/// Every key expression is evaluated by its own function, so `return` exits only the key
+/// expression it's in and produces its key, and the next keys are still compared when keys are
+/// equal. `return` extends up to the next comma, so `|p| return p.a, p.b` has two keys, `p.a`
+/// and `p.b`. Wrap a `return` of a tuple into a block or parentheses: `|p| { return (p.a, p.b) }`.
+///
+/// Key expression which doesn't return [`Ord`] is reported at the expression, e.g.
+/// ``key #2 (`p.score`) is not `Ord` ``.
+///
+/// Only keys have to implement comparison traits: other fields, e.g. a `Mutex` payload next to
+/// an `id` key, and generic parameters which keys don't use get no bounds. Clippy's
+/// `mutable_key_type` lint flags such types in ordered collections, which is a false positive
+/// as long as keys don't depend on the interior-mutable fields.
+///
+/// Note that all expressions are lazy evaluated every time comparison is triggered. In
+/// some applications that can lead to low performance if key expressions are computationally
+/// expensive and comparisons happen repeatedly.
+///
+/// Key expressions must return the same key for the same value every time, or sorting and
+/// ordered collections may produce inconsistent results. In particular, keys must not depend
+/// on the current time (e.g. priority decaying with age), since the clock moves between
+/// comparisons. Use `ord_by_key::at` to compare by a key computed relative to a captured
+/// reference time instead.
+///
+/// Arrays of any length can be used as keys, e.g. `|b| &b.cells` with `cells: [Cell; 64]`, and
+/// are compared element-wise, like slices: comparison and equality stop at the first element
+/// which differs.
+///
+/// Keys can borrow from `self` for as long as the comparison runs, including through guards, e.g.
+/// `s.cell.borrow()` for a `RefCell` field. Guards ([`Ref`](core::cell::Ref),
+/// [`RefMut`](core::cell::RefMut), `MutexGuard`, ...) don't implement [`Ord`] even if their
+/// target does, so wrap them into `ord_by_key::ByDeref`:
+/// `|s| ord_by_key::ByDeref(s.cell.borrow())`. A reference into a guard (`&*s.cell.borrow()`)
+/// doesn't work, since the guard is a temporary dropped at the end of the key expression.
+///
+/// Type of a key expression can be annotated as `expression: Type` when it can't be inferred
+/// from `impl Ord` alone, e.g. for `.parse()` or `.collect()`, instead of using turbofish.
+/// Annotation applies to a method chain or a call, binary expressions have to be parenthesized:
+/// `(a + b): u64`.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Release` will be ordered by numeric version, then by its text
+/// #[ord_eq_by_key_selector(|r| r.version.parse().ok(): Option<u32>, &r.version)]
+/// pub struct Release {
+///     pub version: String,
+/// }
+/// ```
+///
+/// A key of type `Option<T>` or `&Option<T>` can be followed by `nulls = first` or
+/// `nulls = last`, which places `None` before or after all `Some` values, like `NULLS FIRST` and
+/// `NULLS LAST` in SQL. Without the modifier, [`Option`] puts `None` first. `None` keys are equal
+/// to each other, so two rows which differ only in missing values are equal. Descending order of
+/// `Some` values is `r.a.map(Reverse) nulls = last`, which keeps `None` last
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // ORDER BY score NULLS LAST, name NULLS FIRST
+/// #[ord_eq_by_key_selector(|r| r.score nulls = last, &r.name nulls = first)]
+/// pub struct Row {
+///     pub score: Option<u32>,
+///     pub name: Option<String>,
+/// }
+///
+/// let missing = Row { score: None, name: None };
+/// let scored = Row { score: Some(7), name: Some("b".to_string()) };
+/// assert!(scored < missing);
+/// ```
+///
+/// # Comparison expressions
+/// With two parameters `|a, b|`, a key can be replaced by `cmp: expression`, which compares `a`
+/// and `b` directly and returns [`Ordering`](core::cmp::Ordering), for logic which doesn't map
+/// to a single key. It takes part in lexicographic comparison like a key: `Equal` falls through
+/// to the next key, and values are equal if all keys and `cmp:` expressions are equal. The
+/// expression must be consistent with everything else the order relies on: reversing arguments
+/// must reverse the result, and the order must stay transitive. For example, comparing optional
+/// ranks only when both are present breaks transitivity once values with and without rank are
+/// sorted together, so such an expression has to order the missing rank too.
+///
+/// `cmp:` expressions don't produce a key, so they can't be used with `gen_has_key` or `cache`.
+/// ```
+/// use core::cmp::Ordering;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Pinned tasks go first, by their pinned rank, then all tasks by title
+/// #[ord_eq_by_key_selector(|a, b|
+///     cmp: match (a.pin, b.pin) {
+///         (Some(x), Some(y)) => x.cmp(&y),
+///         (Some(_), None) => Ordering::Less,
+///         (None, Some(_)) => Ordering::Greater,
+///         (None, None) => Ordering::Equal,
+///     },
+///     &a.title)]
+/// pub struct Task {
+///     pub pin: Option<u32>,
+///     pub title: String,
+/// }
+///
+/// let task = |pin, title: &str| Task { pin, title: title.to_string() };
+/// assert!(task(Some(2), "z") < task(None, "a"));
+/// assert!(task(Some(1), "z") < task(Some(2), "a"));
+/// assert!(task(None, "a") < task(None, "b"));
+/// ```
+///
+/// # Fields mode
+/// `fields` mode generates one key expression per field of the struct, in order of declaration,
+/// which gives the same comparison semantics as `#[derive(PartialEq, Eq, PartialOrd, Ord)]`.
+/// The macro cannot know which field types implement [`Copy`], so every field is borrowed
+/// (`&self.field`) uniformly. Comparing references delegates to the referenced type, so this
+/// works regardless of `Copy`-ness and doesn't change comparison results.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Person` will be ordered by `last_name`, then by `first_name`, then by `age`
+/// #[ord_eq_by_key_selector(fields)]
+/// pub struct Person {
+///     pub last_name: String,
+///     pub first_name: String,
+///     pub age: u32,
+/// }
+/// ```
+///
+/// `fields(take = N)` uses only first `N` fields in order of declaration, which is useful when
+/// leading fields of the struct form a natural key and trailing fields are payload. `N` cannot be
+/// greater than number of fields.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Record` will be ordered by `table`, then by `id`. `payload` is not compared and doesn't
+/// // need to implement `Ord`
+/// #[ord_eq_by_key_selector(fields(take = 2))]
+/// pub struct Record {
+///     pub table: String,
+///     pub id: u64,
+///     pub payload: Vec<f64>,
+/// }
+/// ```
+///
+/// `by_index = [i, j desc, ...]` compares unnamed fields by index in the specified order, without
+/// naming every field with `|(a, b, ...)|`. Fields are borrowed the same way as in `fields`
+/// mode, fields marked with `desc` are compared in reverse order. Every index has to be less than
+/// number of fields and can be used only once.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Rgb` will be ordered by blue, then by red in reverse, then by green
+/// #[ord_eq_by_key_selector(by_index = [2, 0 desc, 1])]
+/// pub struct Rgb(u8, u8, u8);
+///
+/// assert!(Rgb(0, 0, 1) > Rgb(255, 255, 0));
+/// assert!(Rgb(1, 0, 0) < Rgb(0, 0, 0));
+/// ```
+///
+/// # Transparent mode
+/// `transparent` mode is for newtypes, structs with exactly one field, and delegates comparison to
+/// the field. Trait methods of the field are called directly (e.g. `self.0.cmp(&other.0)`) without
+/// key selector functions, so comparison is the same as hand-written delegation even without
+/// inlining, which matters for newtypes compared on hot paths. It works with and without
+/// `#[repr(transparent)]`.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(transparent)]
+/// #[repr(transparent)]
+/// pub struct Id(u64);
+///
+/// assert!(Id(1) < Id(2));
+/// ```
+///
+/// # Comparator stored in a field
+/// `by_field_comparator = field` makes comparison rule data rather than code: values are compared
+/// by calling the comparator stored in `field` of the left value, e.g. a
+/// `fn(&Self, &Self) -> Ordering` or a `Box<dyn Fn(&Self, &Self) -> Ordering>`, as
+/// `(self.field)(self, other)`. Equality is consistent with the comparator.
+///
+/// Values carrying different comparators may not be totally ordered: `a.cmp(&b)` and `b.cmp(&a)`
+/// use different rules, which breaks sorting and ordered collections the same way as an
+/// inconsistent [`Ord`] implementation. Only values which share the same comparator should be
+/// compared, and `unsafe_dynamic` has to be added to acknowledge that.
+/// ```
+/// use core::cmp::Ordering;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(by_field_comparator = rule; unsafe_dynamic)]
+/// pub struct Entry {
+///     pub name: String,
+///     pub rule: fn(&Entry, &Entry) -> Ordering,
+/// }
+///
+/// fn by_length(a: &Entry, b: &Entry) -> Ordering {
+///     a.name.len().cmp(&b.name.len())
+/// }
+///
+/// let entry = |name: &str| Entry { name: name.to_string(), rule: by_length };
+///
+/// assert!(entry("bb") < entry("aaa"));
+/// ```
+///
+/// # Methods mode
+/// `methods = [a, b, ...]` generates one key expression per method, calling methods without
+/// arguments in order, same as `|s| s.a(), s.b(), ...`. Return types of methods are not visible
+/// to the macro, so results are used as they are: getters which return references (e.g. `&str`)
+/// borrow, other getters have to return values which implement [`Ord`]. Works for both structs
+/// and enums.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Ticket` will be ordered by `priority()`, then by `title()`
+/// #[ord_eq_by_key_selector(methods = [priority, title])]
+/// pub struct Ticket {
+///     severity: u8,
+///     urgent: bool,
+///     title: String,
+/// }
+///
+/// impl Ticket {
+///     // Computed key, returned by value
+///     pub fn priority(&self) -> u32 {
+///         self.severity as u32 * if self.urgent { 10 } else { 1 }
+///     }
+///
+///     // Borrowing getter
+///     pub fn title(&self) -> &str {
+///         &self.title
+///     }
+/// }
+/// ```
+///
+/// # Enums
+/// Key expressions work for enums the same way as for structs, with `|parameter|` syntax (use
+/// `match` to extract keys from variants). `fields` mode and `|(a, b, ...)|` syntax are
+/// supported only for structs. If only some variants carry an orderable payload, map the rest to
+/// `None` and wrap the key in `ord_by_key::NoneLast` (or `NoneFirst`) to order them as a single
+/// bucket after (or before) the others.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Message` will be ordered by length of the text, `Ping` is the same as an empty text
+/// #[ord_eq_by_key_selector(|m| match m {
+///     Message::Text(text) => text.len(),
+///     Message::Ping => 0,
+/// })]
+/// pub enum Message {
+///     Text(String),
+///     Ping,
+/// }
+/// ```
+///
+/// `enum_fields` mode orders enums by variant first, and then by fields of the variant in order of
+/// declaration, which gives the same comparison semantics as
+/// `#[derive(PartialEq, Eq, PartialOrd, Ord)]`. Variants are ordered as declared, explicit
+/// discriminant values don't change the order.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // Every `Circle` is less than any `Rect`. Circles are ordered by `radius`, rectangles are
+/// // ordered by `width`, then by `height`
+/// #[ord_eq_by_key_selector(enum_fields)]
+/// pub enum Shape {
+///     Empty,
+///     Circle(u32),
+///     Rect { width: u32, height: u32 },
+/// }
+///
+/// assert!(Shape::Empty < Shape::Circle(1));
+/// assert!(Shape::Circle(100) < Shape::Rect { width: 1, height: 1 });
+/// assert!(Shape::Rect { width: 1, height: 2 } < Shape::Rect { width: 2, height: 1 });
+/// ```
+///
+/// # Equality coarser than ordering
+/// By default the same keys are used for all generated traits. It's possible to provide
+/// separate keys for [`PartialEq`] and [`Eq`] with `ord = ...; eq = ...`, for example to order
+/// values finely, but consider values equal when they fall into the same group.
+///
+/// **This breaks the consistency between [`Eq`] and [`Ord`] which is required by these traits:**
+/// `a == b` is no longer the same as `a.cmp(&b) == Ordering::Equal`. Code relying on it will
+/// silently misbehave, for example [`BTreeMap`](std::collections::BTreeMap) and
+/// [`BTreeSet`](std::collections::BTreeSet) use only [`Ord`] and so will keep "equal" values
+/// as separate entries, while [`Vec::dedup`] and [`slice::contains`] use only [`PartialEq`].
+/// Because of that, the macro requires an explicit `unsafe_inconsistent` acknowledgment.
+/// Note that `unsafe_inconsistent` cannot lead to undefined behavior, only to logic errors.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `Score` is ordered by `category`, `score` and `id`, but only `category` is used for equality
+/// #[ord_eq_by_key_selector(
+///     ord = |s| s.category, s.score, s.id;
+///     eq = |s| s.category;
+///     unsafe_inconsistent)]
+/// pub struct Score {
+///     pub category: u32,
+///     pub score: u32,
+///     pub id: u32,
+/// }
+///
+/// let mut scores = vec![
+///     Score { category: 1, score: 20, id: 0 },
+///     Score { category: 0, score: 20, id: 1 },
+///     Score { category: 1, score: 10, id: 2 },
+/// ];
+///
+/// // Sort by all keys, then keep one (lowest) score per category
+/// scores.sort();
+/// scores.dedup();
+///
+/// let ids: Vec<_> = scores.iter().map(|s| s.id).collect();
+/// assert_eq!(ids, [1, 2]);
+/// ```
+///
+/// For fully independent `ord`, `eq` and `hash` keys, e.g. to implement [`Hash`](core::hash::Hash)
+/// by `eq` keys, see `ord_by_key_advanced`.
+///
+/// Without `unsafe_inconsistent` separate `eq` keys are rejected
+/// ```compile_fail
+/// use ord_by_key::ord_eq_by_key_selector;
+/// #[ord_eq_by_key_selector(ord = |s| s.category, s.score; eq = |s| s.category)]
+/// pub struct Score {
+///     pub category: u32,
+///     pub score: u32,
+/// }
+/// ```
+///
+/// # Options
+///
+/// ## `gen_extremes = max_name/min_name`
+/// Generates domain-named inherent methods `fn max_name(self, other: Self) -> Self` and
+/// `fn min_name(self, other: Self) -> Self` delegating to [`Ord::max`] and [`Ord::min`].
+/// Generated methods have the same visibility as the struct.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// #[ord_eq_by_key_selector(|(p)| p; gen_extremes = highest/lowest)]
+/// #[derive(Debug)]
+/// pub struct Priority(u8);
+///
+/// assert_eq!(Priority(1).highest(Priority(3)).0, 3);
+/// assert_eq!(Priority(1).lowest(Priority(3)).0, 1);
+/// ```
+///
+/// ## `gen_option_ord = none_first` or `gen_option_ord = none_last`
+/// Generates a newtype `Opt{Name}(pub Option<Name>)` next to the type, with the same visibility
+/// and generics, which implements [`Ord`] placing `None` before (`none_first`) or after
+/// (`none_last`) any value, and [`From<Option<Name>>`](From). Values are ordered the same as the
+/// type itself. Useful when the type is mostly stored as optional, so `None` placement doesn't
+/// have to be spelled at every use site.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|(d)| d; gen_option_ord = none_last)]
+/// pub struct Deadline(u32);
+///
+/// let mut deadlines: Vec<OptDeadline> = vec![None, Some(Deadline(2)), Some(Deadline(1))]
+///     .into_iter()
+///     .map(OptDeadline::from)
+///     .collect();
+/// deadlines.sort();
+///
+/// let days: Vec<_> = deadlines.iter().map(|d| d.0.as_ref().map(|d| d.0)).collect();
+/// assert_eq!(days, [Some(1), Some(2), None]);
+/// ```
+///
+/// ## `gen_has_key = Type`
+/// Implements `ord_by_key::HasKey` with key of type `Type`, so generic code can get keys of
+/// values, e.g. to merge sorted sequences of different types with `ord_by_key::merge_by_key`.
+/// `HasKey::key` returns value of the key expression, or a tuple of values if there are
+/// multiple key expressions. Key is returned by value, so key expressions which borrow have to be
+/// changed to copy or clone, and `fields` and `by_index` modes (which always borrow) are not
+/// supported.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::HasKey;
+///
+/// #[ord_eq_by_key_selector(|t| t.priority, t.name.clone(); gen_has_key = (u8, String))]
+/// pub struct Task {
+///     pub priority: u8,
+///     pub name: String,
+/// }
+///
+/// let task = Task { priority: 1, name: "build".to_string() };
+/// assert_eq!(task.key(), (1, "build".to_string()));
+/// ```
+///
+/// `HasKey::Key` is `Ord`, and so `PartialEq`, which makes the same key usable to remove
+/// duplicates after sorting: `v.sort(); v.dedup_by_key(|t| t.key())` keeps the first value of
+/// every key. Since `PartialEq` of the type compares the same keys, `v.dedup()` has the same
+/// effect unless `eq` keys are specified, and works with borrowed keys too.
+///
+/// Provided methods `HasKey::lt_key`, `le_key`, `gt_key` and `ge_key` compare the key of a value
+/// with a key, so a sorted `Vec` can be searched with `partition_point` by key alone, and
+/// `ord_by_key::key_range(&v, lo..=hi)` returns the subslice of values with keys in the range.
+///
+/// ## `cache = field`
+/// Compares keys stored in `field` of the struct instead of evaluating key expressions on every
+/// comparison, for keys which are expensive to compute. Key expressions (or `methods`) define how
+/// the key is computed, and must produce a value of the type of the field (a tuple if there are
+/// multiple keys). Generates methods `compute_{field}(&self)`, which returns the computed key,
+/// and `refresh_{field}(&mut self)`, which stores it in the field. The field must be refreshed
+/// after the value is created and every time fields which the key depends on are modified.
+///
+/// A stale cached key silently breaks the order, so if `debug_assertions` are enabled, every
+/// comparison computes keys of both values and panics if they are not equal to cached ones. This
+/// makes comparison slower in debug builds than without `cache`, release builds compare cached
+/// keys only.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|d| d.title.to_lowercase(); cache = sort_key)]
+/// pub struct Document {
+///     pub title: String,
+///     sort_key: String,
+/// }
+///
+/// impl Document {
+///     pub fn new(title: &str) -> Self {
+///         let mut document = Document { title: title.to_string(), sort_key: String::new() };
+///         document.refresh_sort_key();
+///         document
+///     }
+/// }
+///
+/// let mut a = Document::new("apple");
+/// let b = Document::new("Banana");
+/// assert!(a < b);
+///
+/// a.title = "Cherry".to_string();
+/// a.refresh_sort_key();
+/// assert!(a > b);
+/// ```
+///
+/// ## `warn_missing_fields`
+/// Reports a warning for every field which is not used by any key expression, to catch fields
+/// which were forgotten when struct was extended. Fields which should not take part in
+/// comparison can be listed in `warn_missing_fields(skip(field, ...))`, fields of structs with
+/// unnamed fields are listed by index. The warning is reported as use of a deprecated item, so
+/// it can be turned into an error with `#![deny(deprecated)]`.
+///
+/// The check is heuristic: key expressions are not type-checked by the macro, so a field counts
+/// as used if its name appears anywhere in key expressions (`.0` for unnamed fields, or the
+/// parameter bound to the field with `|(a, b)|` syntax). Fields accessed indirectly, e.g. through
+/// a method call, are reported as missing and have to be skipped.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::time::Instant;
+///
+/// #[ord_eq_by_key_selector(|e| &e.name, e.id; warn_missing_fields(skip(created_at)))]
+/// pub struct Entry {
+///     pub name: String,
+///     pub id: u32,
+///     pub created_at: Instant,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // error: field `id` is not used by any key of `ord_eq_by_key_selector`
+/// #[ord_eq_by_key_selector(|e| &e.name; warn_missing_fields)]
+/// pub struct Entry {
+///     pub name: String,
+///     pub id: u32,
+/// }
+/// ```
+///
+/// ## `partial_only`
+/// Implements only [`PartialOrd`] and [`PartialEq`] for domains which are partially ordered by
+/// nature, e.g. sets ordered by inclusion, where some pairs of values are incomparable. Key
+/// expressions must return `impl PartialOrd`. Keys are compared lexicographically, the same as
+/// in total order mode, but comparison stops at the first key which is not equal, so if a key
+/// is incomparable (`partial_cmp` returns `None`) the values are incomparable and not equal.
+///
+/// Unlike the default mode, [`Ord`] and [`Eq`] are not implemented, so such types cannot be
+/// sorted with [`slice::sort`] or stored in [`BTreeMap`](std::collections::BTreeMap). Don't use
+/// this mode for keys which are totally ordered, but don't implement [`Ord`] (e.g. [`f64`]), use
+/// a key wrapper providing total order instead. `partial_only` cannot be combined with separate
+/// `eq` keys, `gen_extremes`, `gen_option_ord`, `gen_has_key`, `gen_cmp_mono`, `gen_sorted`,
+/// `gen_par_sort`, `gen_min_queue`, `gen_max_queue` or `gen_btree_desc`.
+///
+/// Keys which are totally ordered can be mixed with partially ordered keys, since every [`Ord`]
+/// type implements [`PartialOrd`]. Combined with `fields` or `enum_fields` mode, comparison is
+/// the same as `#[derive(PartialEq, PartialOrd)]`, so partiality of fields (e.g. NaN in [`f64`]
+/// fields) propagates to the whole value.
+/// ```
+/// use core::cmp::Ordering;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// /// Set of permissions, ordered by inclusion
+/// #[derive(PartialEq)]
+/// pub struct Permissions(u8);
+///
+/// impl PartialOrd for Permissions {
+///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+///         let union = self.0 | other.0;
+///         match (union == other.0, union == self.0) {
+///             (true, true) => Some(Ordering::Equal),
+///             (true, false) => Some(Ordering::Less),
+///             (false, true) => Some(Ordering::Greater),
+///             (false, false) => None,
+///         }
+///     }
+/// }
+///
+/// #[ord_eq_by_key_selector(|r| &r.permissions; partial_only)]
+/// pub struct Role {
+///     pub name: String,
+///     pub permissions: Permissions,
+/// }
+///
+/// let reader = Role { name: "reader".to_string(), permissions: Permissions(0b001) };
+/// let writer = Role { name: "writer".to_string(), permissions: Permissions(0b010) };
+/// let admin = Role { name: "admin".to_string(), permissions: Permissions(0b011) };
+///
+/// assert!(reader < admin);
+/// assert!(writer < admin);
+/// assert_eq!(reader.partial_cmp(&writer), None);
+/// assert!(reader != writer);
+/// ```
+///
+/// ### NaN handling
+/// Floats are partially ordered only because of NaN, and applications treat it differently, so
+/// the option accepts a choice of comparison of keys:
+///
+/// * `partial_only(nan = none)`, which is the same as `partial_only`, compares keys by
+///   [`PartialOrd::partial_cmp`], so a comparison with NaN makes values incomparable, following
+///   IEEE 754
+/// * `partial_only(nan = equal)` compares keys by `ord_by_key::nan_equal_cmp`, which makes NaN
+///   equal to NaN and greater than any other key, and [`PartialEq`] agrees with it. Keys which
+///   are incomparable for other reasons (e.g. sets ordered by inclusion) still make values
+///   incomparable
+///
+/// The choice applies to every key, including fields of `fields` and `enum_fields` modes, and
+/// a key is checked for NaN as a whole, so floats in a tuple key should be separate keys instead.
+/// ```
+/// use core::cmp::Ordering;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|r| r.value; partial_only(nan = none))]
+/// pub struct Reading {
+///     pub value: f64,
+/// }
+///
+/// #[ord_eq_by_key_selector(|r| r.value; partial_only(nan = equal))]
+/// pub struct Sample {
+///     pub value: f64,
+/// }
+///
+/// let reading = |value| Reading { value };
+/// assert_eq!(reading(f64::NAN).partial_cmp(&reading(1.0)), None);
+/// assert!(reading(f64::NAN) != reading(f64::NAN));
+///
+/// let sample = |value| Sample { value };
+/// assert_eq!(sample(f64::NAN).partial_cmp(&sample(1.0)), Some(Ordering::Greater));
+/// assert!(sample(f64::NAN) == sample(f64::NAN));
+/// assert!(sample(1.0) < sample(f64::NAN));
+/// ```
+///
+/// ## `debug_counters`
+/// Counts comparisons to help diagnosing slow sorting, e.g. to see whether key expressions are
+/// evaluated too often and caching is needed. Generates an inherent method
+/// `fn comparison_count() -> u64` which returns number of comparisons ([`Ord::cmp`], or
+/// [`PartialOrd::partial_cmp`] with `partial_only`) of values of the type made by the current
+/// thread. The counter is thread-local and requires `std`. It's maintained only if
+/// `debug_assertions` are enabled, otherwise no counter is generated and `comparison_count`
+/// always returns 0. For generic structs the counter is shared by all instantiations.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|(s)| s.len(); debug_counters)]
+/// pub struct ByLen(String);
+///
+/// let before = ByLen::comparison_count();
+/// let _ = ByLen("a".to_string()) < ByLen("b".to_string());
+///
+/// if cfg!(debug_assertions) {
+///     assert_eq!(ByLen::comparison_count() - before, 1);
+/// }
+/// ```
+///
+/// ## `debug_keys`
+/// Generates an inherent method `fn debug_keys(&self) -> impl Debug + '_`, which shows the keys
+/// a value is compared by, e.g. to find out why values are sorted in an unexpected order. Keys
+/// are formatted as a tuple in order of comparison, and keys in reverse order show the value
+/// inside [`Reverse`](core::cmp::Reverse) marked with `↓` instead of `Reverse(...)`, so mixed
+/// directions are clear at a glance. A key is recognized as reversed if its expression is a call
+/// of `Reverse`, `core::cmp::Reverse` or `std::cmp::Reverse`. Keys must implement
+/// [`Debug`](core::fmt::Debug). Supported only for key expressions and `methods` mode, without
+/// `cmp:` keys.
+/// ```
+/// use core::cmp::Reverse;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|p| &p.name, Reverse(p.score); debug_keys)]
+/// pub struct Player {
+///     pub name: String,
+///     pub score: u32,
+/// }
+///
+/// let player = Player { name: "ann".to_string(), score: 7 };
+/// assert_eq!(format!("{:?}", player.debug_keys()), "(\"ann\", ↓7)");
+/// ```
+///
+/// ## `gen_cmp_mono`
+/// Generates an inherent method `fn cmp_mono(&self, other: &Self) -> Ordering` marked
+/// `#[inline(always)]`, which compares the same way as [`Ord::cmp`]. Passing `Type::cmp_mono` to
+/// `sort_by` (or `binary_search_by`) in a hot loop makes sure comparison is inlined into the
+/// sorting code, while `Ord::cmp` is inlined at the discretion of the compiler, which can give up
+/// e.g. for large key expressions or across crate boundaries. Measure before using it: forced
+/// inlining increases code size, and often makes no difference. See `benches/cmp_mono.rs`.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|e| e.bucket, e.offset; gen_cmp_mono)]
+/// pub struct Event {
+///     pub bucket: u32,
+///     pub offset: u64,
+/// }
+///
+/// let mut events = vec![Event { bucket: 2, offset: 0 }, Event { bucket: 1, offset: 5 }];
+/// events.sort_by(Event::cmp_mono);
+/// assert_eq!(events[0].bucket, 1);
+/// ```
+///
+/// ## `gen_sorted`
+/// Generates inherent constructors of sorted vectors, for code which collects values only to
+/// sort them. Requires `std`.
+///
+/// * `fn sorted(values: impl IntoIterator<Item = Self>) -> Vec<Self>` sorts by [`Ord`] with the
+///   stable `sort`
+/// * `fn sorted_by_cached_key(values: impl IntoIterator<Item = Self>) -> Vec<Self>` sorts with
+///   `sort_by_cached_key`, which computes keys once per value instead of twice on every
+///   comparison. Use it for keys which are expensive to compute, e.g. allocate
+///
+/// The cached variant keeps keys by value, so key expressions must not borrow from the value
+/// (use e.g. `p.name.clone()` instead of `&p.name`), and only key expressions without `cmp:`
+/// keys and `methods` mode are supported. It orders by declared keys only: final keys
+/// (`fifo_tiebreak`, `content_tiebreak`, `hash_residual`) are not compared, and values with
+/// equal keys keep their order.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|f| f.path.to_lowercase(); gen_sorted)]
+/// pub struct File {
+///     pub path: String,
+/// }
+///
+/// let file = |path: &str| File { path: path.to_string() };
+/// let files = File::sorted_by_cached_key(vec![file("b.rs"), file("README"), file("a.rs")]);
+///
+/// let paths: Vec<_> = files.iter().map(|f| f.path.as_str()).collect();
+/// assert_eq!(paths, ["a.rs", "b.rs", "README"]);
+/// assert!(File::sorted(vec![file("B"), file("a")])[0].path == "a");
+/// ```
+///
+/// ## `gen_par_sort`
+/// Generates an inherent method `fn par_sort(values: &mut [Self])`, which sorts a slice in
+/// parallel with `rayon`'s `par_sort_unstable` by [`Ord`] of the type. Requires `rayon` feature
+/// of `ord_by_key`, which brings `rayon` as a dependency, so the crate using the macro doesn't
+/// have to depend on it. Generated comparison has no state shared between calls (counters of
+/// `debug_counters` are thread-local, and the sequence number of `fifo_tiebreak` is assigned
+/// on creation), so the type is [`Send`] and [`Sync`] whenever its fields are, and values can
+/// be compared from many threads at once. The method requires the type to be [`Send`]: generic
+/// types get it only for parameters which make them [`Send`], and for non-generic types which
+/// aren't [`Send`] (e.g. with an [`Rc`](std::rc::Rc) field) the option is rejected with an error
+/// that the type can't be sent between threads.
+/// ```ignore
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // `Cargo.toml` enables `rayon` feature of `ord_by_key`
+/// #[ord_eq_by_key_selector(|e| e.score; gen_par_sort)]
+/// pub struct Entry {
+///     pub score: u64,
+/// }
+///
+/// let mut entries: Vec<_> = (0..100_000).rev().map(|score| Entry { score }).collect();
+/// Entry::par_sort(&mut entries);
+/// assert_eq!(entries[0].score, 0);
+/// ```
+///
+/// ## `gen_min_queue = Name` and `gen_max_queue = Name`
+/// Generates newtype `Name` of [`BinaryHeap`](std::collections::BinaryHeap), which is a priority
+/// queue returning values of the type from the smallest (`gen_min_queue`) or from the largest
+/// (`gen_max_queue`). The min queue keeps values in [`Reverse`](core::cmp::Reverse), which is
+/// hidden by its methods: `new`, `push`, `pop`, `peek`, `len`, `is_empty` and `clear` take and
+/// return values of the type. Queues also implement [`Default`], [`Extend`] and
+/// [`FromIterator`](core::iter::FromIterator). Both options can be used together with different
+/// names. Requires `std`.
+///
+/// `BinaryHeap` returns values with equal keys in unspecified order, combine the option with
+/// `fifo_tiebreak` to return them first in, first out.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|t| t.priority; fifo_tiebreak, gen_min_queue = TaskQueue)]
+/// pub struct Task {
+///     pub priority: u32,
+///     pub name: &'static str,
+/// }
+///
+/// let mut queue = TaskQueue::new();
+/// queue.push(Task::new(1, "first"));
+/// queue.push(Task::new(0, "urgent"));
+/// queue.push(Task::new(1, "second"));
+///
+/// assert_eq!(queue.peek().map(|t| t.name), Some("urgent"));
+/// let order: Vec<_> = std::iter::from_fn(|| queue.pop()).map(|t| t.name).collect();
+/// assert_eq!(order, ["urgent", "first", "second"]);
+/// ```
+///
+/// ## `gen_btree_desc = Name`
+/// Generates newtype `Name<V>` of [`BTreeMap<Reverse<Self>, V>`](std::collections::BTreeMap),
+/// which is a map iterating keys of the type in descending order. Methods take and return keys
+/// of the type, wrapping them into [`Reverse`](core::cmp::Reverse) and unwrapping as needed:
+/// `new`, `insert`, `get`, `get_mut`, `contains_key`, `remove`, `range`, `iter`, `len` and
+/// `is_empty`, and the map implements [`Default`], [`Extend`] and
+/// [`FromIterator`](core::iter::FromIterator). `as_map` returns the underlying map for everything
+/// else. Requires `std`.
+///
+/// `&Self` can't be borrowed as `&Reverse<Self>`, so lookups take a reference to the key and
+/// clone it. `range` takes bounds in ascending order of keys as usual (e.g. `a..=b`) and returns
+/// entries from the largest key.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|(y)| *y; gen_btree_desc = Releases)]
+/// #[derive(Clone, Debug)]
+/// pub struct Year(u32);
+///
+/// let mut releases = Releases::new();
+/// releases.insert(Year(2019), "1.0");
+/// releases.insert(Year(2023), "3.0");
+/// releases.insert(Year(2021), "2.0");
+///
+/// let latest: Vec<_> = releases.iter().map(|(year, _)| year.0).collect();
+/// assert_eq!(latest, [2023, 2021, 2019]);
+/// assert_eq!(releases.get(&Year(2021)), Some(&"2.0"));
+///
+/// let range: Vec<_> = releases.range(Year(2019)..Year(2023)).map(|(_, v)| *v).collect();
+/// assert_eq!(range, ["2.0", "1.0"]);
+/// ```
+///
+/// ## `cfg = "feature"`
+/// Generates trait implementations and helpers only when `feature` of the crate using the macro
+/// is enabled, e.g. for a library which needs ordering of its types only in some builds. The
+/// struct itself is always present, every generated item is marked with
+/// `#[cfg(feature = "...")]`, the same as if it was written under the feature by hand.
+/// Warnings of `warn_missing_fields` are also reported only when the feature is enabled. Code
+/// which uses the generated traits (sorting, ordered collections, `HasKey`) has to be gated
+/// the same way. The feature has to be declared in `Cargo.toml` of the crate, otherwise
+/// `unexpected_cfgs` lint warns about it. Cannot be combined with `fifo_tiebreak`, which adds a
+/// field needed to create values.
+/// ```ignore
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // `Cargo.toml` declares `[features] ordering = []`
+/// #[ord_eq_by_key_selector(|r| r.id; cfg = "ordering")]
+/// pub struct Record {
+///     pub id: u32,
+/// }
+///
+/// #[cfg(feature = "ordering")]
+/// pub fn sorted(mut records: Vec<Record>) -> Vec<Record> {
+///     records.sort();
+///     records
+/// }
+/// ```
+///
+/// ## `vis = VIS`
+/// Sets visibility of generated helpers (methods of `gen_extremes`, `debug_counters`,
+/// `debug_keys`, `gen_cmp_mono`, `gen_sorted`, `gen_par_sort` and `cache`, newtypes of
+/// `gen_option_ord`, `gen_min_queue`, `gen_max_queue` and `gen_btree_desc`, constructor of
+/// `fifo_tiebreak`), which by default have the same visibility as the type. Use it to keep
+/// helpers of a public type out of the public API of the crate. Private visibility is spelled as
+/// `pub(self)`. Trait implementations are not affected.
+/// ```
+/// mod inventory {
+///     use ord_by_key::ord_eq_by_key_selector;
+///
+///     #[ord_eq_by_key_selector(|(n)| n; gen_extremes = larger/smaller, vis = pub(crate))]
+///     pub struct Count(pub u32);
+/// }
+///
+/// use inventory::Count;
+///
+/// assert_eq!(Count(1).larger(Count(2)).0, 2);
+/// ```
+///
+/// ## `fifo_tiebreak`
+/// Breaks ties between values with equal keys in order of creation, e.g. to make a priority queue
+/// return items of the same priority first in, first out. Supported only for structs with named
+/// fields.
+///
+/// This changes the struct: a private `u64` sequence number field is added after declared
+/// fields, and values have to be created with the generated constructor
+/// `fn new(field, field, ...) -> Self`, which takes declared fields in order of declaration and
+/// assigns the next number from a per-type atomic counter. Struct literals (and derives which
+/// create values, e.g. `Default` or `Deserialize`) can't set the field, so they are not
+/// available or produce values with the same number.
+///
+/// Sequence number is compared after all keys, so values are equal only if they have the same
+/// number, i.e. one is a clone of the other. Keys (e.g. `fields` mode and `warn_missing_fields`)
+/// see only the declared fields.
+/// ```
+/// use core::cmp::Reverse;
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::collections::BinaryHeap;
+///
+/// #[ord_eq_by_key_selector(|j| j.priority; fifo_tiebreak)]
+/// pub struct Job {
+///     pub priority: u32,
+///     pub name: &'static str,
+/// }
+///
+/// let mut queue = BinaryHeap::new();
+/// queue.push(Reverse(Job::new(1, "first")));
+/// queue.push(Reverse(Job::new(0, "urgent")));
+/// queue.push(Reverse(Job::new(1, "second")));
+///
+/// let order: Vec<_> = std::iter::from_fn(|| queue.pop()).map(|Reverse(j)| j.name).collect();
+/// assert_eq!(order, ["urgent", "first", "second"]);
+/// ```
+///
+/// ## `content_tiebreak`
+/// Breaks ties between values with equal keys by `ord_by_key::StableHash` of the whole value,
+/// which requires `Self: Hash`. The resulting order doesn't depend on insertion order or
+/// addresses, and is the same across runs and platforms, e.g. for reproducible output. The hash
+/// is computed only when all keys are equal. Cannot be combined with `fifo_tiebreak` or
+/// `hash_residual`.
+///
+/// Values with equal keys are equal only if their hashes are equal. Different values can collide
+/// and compare equal, so the order is total, but not necessarily strict for distinct contents.
+/// Type parameters of generic structs have to be bounded by `Hash` in the definition of the
+/// struct.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|t| t.priority; content_tiebreak)]
+/// #[derive(Hash)]
+/// pub struct Task {
+///     pub priority: u32,
+///     pub name: &'static str,
+/// }
+///
+/// let a = Task { priority: 1, name: "a" };
+/// let b = Task { priority: 1, name: "b" };
+/// assert!(a != b);
+/// assert!(Task { priority: 0, name: "b" } < a.min(b));
+/// ```
+///
+/// ## `hash_residual = [field, ...]`
+/// Same as `content_tiebreak`, but hashes only the listed fields of a struct, which have to be
+/// `Hash`, instead of the whole value. Useful for fields which can't be meaningfully ordered,
+/// but still have to distinguish values, e.g. a set of tags: values with equal keys are ordered
+/// by the stable hash of the listed fields (in the listed order), and are equal only if the
+/// hashes are equal. `Self` doesn't need to be `Hash`. Fields used by `hash_residual` count as
+/// used for `warn_missing_fields`. Cannot be combined with `fifo_tiebreak` or
+/// `content_tiebreak`.
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::collections::HashSet;
+///
+/// #[ord_eq_by_key_selector(|i| i.priority; hash_residual = [labels])]
+/// pub struct Issue {
+///     pub priority: u32,
+///     pub labels: Vec<&'static str>,
+///     pub watchers: HashSet<u64>,
+/// }
+///
+/// let issue = |priority, labels: &[&'static str]| Issue {
+///     priority,
+///     labels: labels.to_vec(),
+///     watchers: HashSet::new(),
+/// };
+/// assert!(issue(1, &["bug"]) != issue(1, &["docs"]));
+/// assert!(issue(1, &["bug"]) == issue(1, &["bug"]));
+/// assert!(issue(0, &["docs"]) < issue(1, &["bug"]));
+/// ```
+///
+/// # Combining with other macros
+/// Generated impls are regular items next to the struct, and traits are resolved only after all
+/// macros are expanded, so derives and attribute macros which generate code requiring [`Ord`]
+/// (e.g. sorting helpers or ordered collection wrappers) work regardless of whether
+/// `ord_eq_by_key_selector` is placed above or below them. No companion derive is needed.
+///
+/// Like impls generated by `#[derive]`, all impls are marked `#[automatically_derived]`, so
+/// coverage tools, lints and IDEs treat them as generated code rather than as user code.
+///
+/// The only requirement is that other macros must not implement any of [`Ord`], [`PartialOrd`],
+/// [`PartialEq`] and [`Eq`] themselves, e.g. `#[derive(PartialEq)]` conflicts with generated
+/// impls (`error[E0119]`). With `partial_only` option [`Ord`] and [`Eq`] are not implemented, so
+/// only those two may be derived alongside it, [`PartialEq`] still conflicts:
+///
+/// ```compile_fail,E0119
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|m| m.value; partial_only)]
+/// #[derive(PartialEq)]
+/// pub struct Measurement {
+///     pub value: f64,
+/// }
+/// ```
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|v| v.id; partial_only)]
+/// #[derive(Eq)]
+/// pub struct Version {
+///     pub id: u32,
+/// }
+///
+/// assert!(Version { id: 1 } < Version { id: 2 });
+/// ```
+///
+/// Macros placed below `ord_eq_by_key_selector` see the struct after expansion, without the
+/// attribute. Macros placed above it see the attribute as is, which is fine for derives, but
+/// attribute macros which inspect or rewrite attributes of the item may need to be placed below.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Stands for any derive which requires `Ord`
+/// macro_rules! sorted_helpers {
+///     ($name:ident) => {
+///         impl $name {
+///             pub fn sort_all(values: &mut [Self]) {
+///                 values.sort();
+///             }
+///         }
+///     };
+/// }
+///
+/// #[ord_eq_by_key_selector(|t| t.priority)]
+/// #[derive(Debug)]
+/// pub struct Task {
+///     pub priority: u32,
+/// }
+///
+/// sorted_helpers!(Task);
+///
+/// let mut tasks = [Task { priority: 2 }, Task { priority: 1 }];
+/// Task::sort_all(&mut tasks);
+/// assert_eq!(tasks[0].priority, 1);
+/// ```
+///
+/// # Custom sorting logic for existing structs
+/// One of use case is introduction of custom sorting logic to existing structs or different
+/// sorting logic for different cases. Example how custom logic is introduces in core library
+/// is [`::core::cmp::Reverse`] container (which reverses existing comparison logic), but we can
+/// implement more complicated containers.
+///
+/// Let's say, we want to sort integers by their absolute value, and then by the actual value.
+/// We cannot introduce new sorting logic to [`i32`] because it already implements [`Ord`], but we can
+/// introduce container for [`i32`] which has custom sorting logic. Note that struct is defined with
+/// unnamed fields and we are using syntax to destruct it to individual fields
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // Container for `i32` will be ordered by absolute value, then by actual value
+/// #[ord_eq_by_key_selector(|(i)| i.abs(), i)]
+/// pub struct I32ByAbs(i32);
+///
+/// let a = I32ByAbs(10);
+/// let b = I32ByAbs(-11);
+///
+/// assert!(a < b);
+/// ```
+///
+/// Generic parameters of the struct (lifetimes, types and constants) are used for generated
+/// implementations as they are, the macro doesn't add any bounds. Type parameters which
+/// participate in keys have to be bounded by [`Ord`] in the struct definition, while the rest
+/// don't need any bounds
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // `T` is compared, so it must implement `Ord`
+/// #[ord_eq_by_key_selector(|(s, t)| s, t)]
+/// pub struct Entry<'a, T: Ord>(&'a str, T);
+///
+/// // `T` is not compared and can be any type
+/// #[ord_eq_by_key_selector(|(s, t)| s)]
+/// pub struct Tagged<'a, T>(&'a str, T);
+///
+/// // Unit of measure is a phantom type, only the value is compared
+/// #[ord_eq_by_key_selector(|l| ord_by_key::TotalF64(l.0))]
+/// pub struct Length<Unit>(f64, core::marker::PhantomData<Unit>);
+/// ```
+///
+/// Keys of associated types work the same way: the bounds which make the key [`Ord`] come from
+/// the struct definition, either from the trait (`type Key: Ord`) or from a `where` clause on
+/// the associated type
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// pub trait Keyed {
+///     type Key;
+///
+///     fn key(&self) -> Self::Key;
+/// }
+///
+/// #[ord_eq_by_key_selector(|(w)| w.key())]
+/// pub struct Wrapper<T: Keyed>(T)
+/// where
+///     T::Key: Ord;
+/// ```
+///
+/// Const generic parameters are in scope of key expressions and can be used as keys or as
+/// a part of key expressions
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// // Ordered by number of free bytes, then by used bytes
+/// #[ord_eq_by_key_selector(|b| N - b.used, &b.data[..b.used])]
+/// pub struct Buf<const N: usize> {
+///     pub data: [u8; N],
+///     pub used: usize,
+/// }
+/// ```
+///
+/// You can use more complicated containers with generic and constraints
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use core::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+/// use std::fmt::Debug;
+///
+/// // Merges sorted iterator and prints final sorted sequence to the console
+/// fn merge_sorted_iterators<T: Ord + Debug, I: Iterator<Item = T>>(iterators: Vec<I>) {
+///     let mut heap = BinaryHeap::new();
+///
+///     // Container for iterator and it's last value, ordered by value
+///     #[ord_eq_by_key_selector(|(value, iter)| value)]
+///     // Note that inner struct cannot use generic parameters from the outer function
+///     // (error[E0401]) so they have to be re-defined with constraints.
+///     // For the sorting container, the only constraint which we care about is `T : Ord`
+///     // because we are using the value as a sorting key
+///     struct ItemIter<T: Ord, I>(T, I);
+///
+///     for mut iterator in iterators {
+///         if let Some(item) = iterator.next() {
+///             // Need to Reverse here because BinaryHeap by default works as max heap
+///             // and we need min heap
+///             heap.push(Reverse(ItemIter(item, iterator)));
+///         }
+///     }
+///
+///     while let Some(Reverse(ItemIter(item, mut iterator))) = heap.pop() {
+///         println!("{:?}", item);
+///
+///         if let Some(item) = iterator.next() {
+///             heap.push(Reverse(ItemIter(item, iterator)));
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ord_eq_by_key_selector(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = syn::parse_macro_input!(attr as MacroAttribute);
+    let input = syn::parse_macro_input!(item as DeriveInput);
+
+    match expand(attr, input) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Implements [`Ord`], [`Eq`] and [`Hash`](core::hash::Hash) by fully independent lists of keys
+///
+/// ```ignore
+/// #[ord_by_key_advanced(ord = keys; eq = keys; hash = keys)]
+/// pub struct MyStruct ...
+/// ```
+/// Every list is optional, and has the same syntax as keys of `ord_eq_by_key_selector` (key
+/// expressions, `fields`, `methods`, `by_index`, `enum_fields`, ...), with a few restrictions:
+/// `by_field_comparator` is not supported, and `hash` keys can't use `enum_fields` and
+/// `transparent` modes or `cmp:` expressions, since they have to produce keys. Only traits with
+/// keys are generated:
+///
+/// * `ord` implements [`Ord`] and [`PartialOrd`]
+/// * `eq` implements [`PartialEq`] and [`Eq`]
+/// * `hash` implements [`Hash`](core::hash::Hash), keys are hashed in order, the same way as a
+///   tuple of keys
+///
+/// A trait without keys has to be implemented (or derived) manually when needed, e.g. `ord`
+/// alone doesn't compile without an [`Eq`] implementation, since it's a supertrait of [`Ord`].
+/// No options are supported, this macro is the escape hatch for power users, for everything
+/// else use `ord_eq_by_key_selector`.
+///
+/// # Invariant hazards
+/// Standard library (and most other code) assumes that these traits agree with each other, and
+/// `ord_eq_by_key_selector` derives all of them from the same keys to guarantee that. This macro
+/// doesn't check anything, so **it's up to the user to decide which invariants can be broken**:
+///
+/// * `a == b` must be the same as `a.cmp(&b) == Ordering::Equal`. When `ord` and `eq` keys
+///   differ it's not: [`BTreeMap`](std::collections::BTreeMap),
+///   [`BTreeSet`](std::collections::BTreeSet) and [`slice::binary_search`] use only [`Ord`],
+///   while [`Vec::dedup`], [`slice::contains`] and `==` use only [`PartialEq`], so the same two
+///   values are duplicates for one and distinct for another. See
+///   [Equality coarser than ordering](ord_eq_by_key_selector#equality-coarser-than-ordering)
+///   for the same mode of `ord_eq_by_key_selector`.
+/// * `a == b` must imply `hash(a) == hash(b)`. [`HashMap`](std::collections::HashMap) and
+///   [`HashSet`](std::collections::HashSet) compare only values with equal hashes, so values
+///   which are equal but hash differently are silently kept as separate entries, and lookups
+///   of equal values fail. This invariant can't be usefully broken: make `hash` keys the
+///   same as `eq` keys, or a subset of them (e.g. only `id` of `id, name`), which keeps it and
+///   only increases collisions.
+/// * [`Ord`] has to be a total order, and [`Eq`] an equivalence on its own. Keys which are not
+///   (e.g. floats) are rejected the same way as by `ord_eq_by_key_selector`.
+///
+/// Breaking the invariants cannot lead to undefined behavior, only to logic errors: wrong
+/// results, panics or endless loops in the code relying on them.
+/// ```
+/// use ord_by_key::ord_by_key_advanced;
+/// use std::collections::HashSet;
+///
+/// // Players are ranked by score, but identified by `id`: updated scores of the same player are
+/// // equal and hash the same
+/// #[ord_by_key_advanced(ord = |p| p.score, p.id; eq = |p| p.id; hash = |p| p.id)]
+/// pub struct Player {
+///     pub id: u32,
+///     pub score: u32,
+/// }
+///
+/// let mut players = HashSet::new();
+/// players.insert(Player { id: 1, score: 10 });
+/// players.insert(Player { id: 2, score: 5 });
+/// players.replace(Player { id: 1, score: 20 });
+///
+/// let mut ranking: Vec<_> = players.into_iter().collect();
+/// ranking.sort();
+///
+/// let ids: Vec<_> = ranking.iter().map(|p| (p.id, p.score)).collect();
+/// assert_eq!(ids, [(2, 5), (1, 20)]);
+/// ```
+///
+/// `hash` keys alone implement only [`Hash`](core::hash::Hash), e.g. for a type which derives
+/// comparison traits, but hashes only a part of fields
+/// ```
+/// use ord_by_key::ord_by_key_advanced;
+///
+/// #[ord_by_key_advanced(hash = |e| e.id)]
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// pub struct Entry {
+///     pub id: u64,
+///     pub payload: Vec<u8>,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ord_by_key_advanced(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = syn::parse_macro_input!(attr as AdvancedAttribute);
+    let input = syn::parse_macro_input!(item as DeriveInput);
+
+    match advanced::expand(attr, input) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(attr: MacroAttribute, input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if let Data::Union(data) = &input.data {
+        return Err(syn::Error::new(
+            data.union_token.span,
+            "`ord_eq_by_key_selector` supports only structs and enums",
+        ));
+    }
+
+    let name = &input.ident;
+    // Visibility of generated helpers, doesn't affect trait implementations
+    let vis = attr.vis.as_ref().unwrap_or(&input.vis);
+
+    // Keys are generated for the struct as declared, and the struct is emitted with the injected
+    // sequence number field
+    let (seq_item, constructor) = match attr.fifo_tiebreak {
+        Some(span) => (
+            Some(fifo_tiebreak::with_seq_field(&input, span)?),
+            fifo_tiebreak::constructor(&input, vis),
+        ),
+        None => (None, quote! {}),
+    };
+    let item = seq_item.as_ref().unwrap_or(&input);
+
+    // With `cache`, key expressions compute the key stored in the field, and values are compared
+    // by the field
+    let cache_keys = attr
+        .cache
+        .as_ref()
+        .map(|field| Keys::KeySelector(KeySelector::from_cache(field)));
+
+    let hashed = match &attr.hash_residual {
+        Some(hash_residual) => {
+            let fields = struct_fields(&input, "`hash_residual`")?;
+            tiebreak::check_hash_residual(hash_residual, fields)?;
+
+            &hash_residual.members[..]
+        }
+        None => &[],
+    };
+
+    let missing_fields_warnings = match &attr.missing_fields {
+        Some(missing_fields) => {
+            let keys: Vec<_> = core::iter::once(&attr.ord_keys)
+                .chain(attr.eq_keys.as_ref())
+                .chain(cache_keys.as_ref())
+                .collect();
+            let fields = struct_fields(&input, "`warn_missing_fields`")?;
+
+            missing_fields::missing_fields_warnings(missing_fields, &keys, hashed, fields)?
+        }
+        None => quote! {},
+    };
+
+    let has_key_impl = match &attr.has_key {
+        Some(has_key) => has_key::has_key_impl(has_key, &attr.ord_keys, &input)?,
+        None => quote! {},
+    };
+
+    let cached_key = match &attr.cache {
+        Some(field) => Some(cache::cached_key(field, &attr.ord_keys, &input, vis)?),
+        None => None,
+    };
+
+    let sorted_funcs = match attr.sorted {
+        Some(span) => sorted::sorted_funcs(span, &attr.ord_keys, vis)?,
+        None => quote! {},
+    };
+
+    let debug_keys_func = match attr.debug_keys {
+        Some(span) => debug_keys::debug_keys_func(span, &attr.ord_keys, vis)?,
+        None => quote! {},
+    };
+
+    let par_sort_func = match attr.par_sort {
+        Some(span) => sorted::par_sort_func(span, vis)?,
+        None => quote! {},
+    };
+
+    let queues = [
+        (&attr.min_queue, queue::QueueOrder::Min),
+        (&attr.max_queue, queue::QueueOrder::Max),
+    ];
+    let queues = queues
+        .iter()
+        .filter_map(|&(name, order)| Some(queue::queue(name.as_ref()?, order, &input, vis)));
+
+    let btree_desc = attr
+        .btree_desc
+        .as_ref()
+        .map(|map| btree_desc::btree_desc(map, &input, vis));
+
+    let ord = comparison(
+        cache_keys.unwrap_or(attr.ord_keys),
+        &input,
+        "_ord_eq_by_key_selector",
+        if attr.partial_only {
+            KeyBound::PartialOrd(attr.nan)
+        } else {
+            KeyBound::Ord
+        },
+    )?;
+
+    let eq = match attr.eq_keys {
+        Some(eq_keys) => Some(comparison(
+            eq_keys,
+            &input,
+            "_ord_eq_by_key_selector_eq",
+            KeyBound::Eq,
+        )?),
+        None => None,
+    };
+
+    let extremes_funcs = match &attr.extremes {
+        Some(Extremes { max, min, .. }) => {
+            quote! {
+                /// Returns the greater of two values, same as [`Ord::max`]
+                #vis fn #max(self, other: Self) -> Self {
+                    ::core::cmp::Ord::max(self, other)
+                }
+
+                /// Returns the lesser of two values, same as [`Ord::min`]
+                #vis fn #min(self, other: Self) -> Self {
+                    ::core::cmp::Ord::min(self, other)
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let option_ord = match &attr.option_ord {
+        Some(placement) => {
+            let generics = &input.generics;
+            let option_name = format_ident!("Opt{}", name);
+
+            let (none_order, doc) = match placement {
+                NonePlacement::First => (
+                    quote! { ::core::cmp::Ordering::Less },
+                    format!("[`Option<{}>`] ordered with `None` before any value", name),
+                ),
+                NonePlacement::Last => (
+                    quote! { ::core::cmp::Ordering::Greater },
+                    format!("[`Option<{}>`] ordered with `None` after any value", name),
+                ),
+            };
+
+            quote! {
+                #[doc = #doc]
+                #vis struct #option_name #generics (
+                    pub ::core::option::Option<#name #ty_generics>,
+                ) #where_clause;
+
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<::core::option::Option<#name #ty_generics>>
+                    for #option_name #ty_generics #where_clause
+                {
+                    fn from(value: ::core::option::Option<#name #ty_generics>) -> Self {
+                        #option_name(value)
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics ::core::cmp::PartialEq for #option_name #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.0 == other.0
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics ::core::cmp::Eq for #option_name #ty_generics #where_clause { }
+
+                #[automatically_derived]
+                impl #impl_generics ::core::cmp::Ord for #option_name #ty_generics #where_clause {
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        match (&self.0, &other.0) {
+                            (::core::option::Option::Some(value), ::core::option::Option::Some(other)) => {
+                                ::core::cmp::Ord::cmp(value, other)
+                            }
+                            (::core::option::Option::None, ::core::option::Option::None) => {
+                                ::core::cmp::Ordering::Equal
+                            }
+                            (::core::option::Option::None, ::core::option::Option::Some(_)) => #none_order,
+                            (::core::option::Option::Some(_), ::core::option::Option::None) => {
+                                #none_order.reverse()
+                            }
+                        }
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics ::core::cmp::PartialOrd for #option_name #ty_generics #where_clause {
+                    fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                        ::core::option::Option::Some(self.cmp(other))
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let (counter_funcs, count_comparison) = if attr.debug_counters {
+        let comparisons = Ident::new(
+            "_ord_eq_by_key_selector_comparisons",
+            proc_macro2::Span::mixed_site(),
+        );
+
+        let funcs = quote! {
+            #[cfg(debug_assertions)]
+            fn #comparisons() -> &'static ::std::thread::LocalKey<::core::cell::Cell<u64>> {
+                ::std::thread_local! {
+                    static COMPARISONS: ::core::cell::Cell<u64> = ::core::cell::Cell::new(0);
+                }
+
+                &COMPARISONS
+            }
+
+            /// Returns number of comparisons of values of this type made by the current thread.
+            /// Always returns 0 if `debug_assertions` are disabled
+            #vis fn comparison_count() -> u64 {
+                #[cfg(debug_assertions)]
+                return Self::#comparisons().with(|count| count.get());
+
+                #[cfg(not(debug_assertions))]
+                return 0;
+            }
+        };
+
+        let count_comparison = quote! {
+            #[cfg(debug_assertions)]
+            Self::#comparisons().with(|count| count.set(count.get() + 1));
+        };
+
+        (funcs, count_comparison)
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let Comparison {
+        funcs: ord_key_selector_funcs,
+        assertions: ord_key_assertions,
+        cmp: cmp_body,
+        partial_cmp: partial_cmp_body,
+        eq: ord_eq_body,
+    } = ord;
+
+    let (cache_funcs, cmp_body, partial_cmp_body, ord_eq_body) = match cached_key {
+        Some(cache::CachedKey { funcs, check }) => (
+            funcs,
+            quote! { #check #cmp_body },
+            quote! { #check #partial_cmp_body },
+            quote! { #check #ord_eq_body },
+        ),
+        None => (quote! {}, cmp_body, partial_cmp_body, ord_eq_body),
+    };
+
+    let (eq_key_selector_funcs, eq_key_assertions, eq_body) = match eq {
+        Some(eq) => (eq.funcs, eq.assertions, eq.eq),
+        None => (quote! {}, quote! {}, ord_eq_body),
+    };
+
+    let final_key = match (
+        attr.fifo_tiebreak,
+        attr.content_tiebreak,
+        &attr.hash_residual,
+    ) {
+        (Some(_), _, _) => Some(fifo_tiebreak::seq_tiebreak()),
+        (None, Some(span), _) => Some(tiebreak::content_tiebreak(span)),
+        (None, None, Some(hash_residual)) => Some(tiebreak::hash_residual(&hash_residual.members)),
+        (None, None, None) => None,
+    };
+
+    let (tiebreak_funcs, cmp_body, partial_cmp_body, eq_body) = match final_key {
+        Some(final_key) => {
+            let tiebreak = tiebreak::tiebreak(
+                cmp_body,
+                partial_cmp_body,
+                eq_body,
+                attr.partial_only,
+                final_key,
+            );
+
+            (
+                tiebreak.funcs,
+                tiebreak.cmp,
+                tiebreak.partial_cmp,
+                tiebreak.eq,
+            )
+        }
+        None => (quote! {}, cmp_body, partial_cmp_body, eq_body),
+    };
+
+    let cmp_mono_func = if attr.cmp_mono {
+        quote! {
+            /// Same as [`Ord::cmp`], but always inlined, to be passed to `sort_by` in hot loops
+            #[inline(always)]
+            #vis fn cmp_mono(&self, other: &Self) -> ::core::cmp::Ordering {
+                #count_comparison
+
+                #cmp_body
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let trait_impls = if attr.partial_only {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    #eq_body
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    #count_comparison
+
+                    #partial_cmp_body
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    #eq_body
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause { }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    #count_comparison
+
+                    #cmp_body
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(self.cmp(other))
+                }
+            }
+        }
+    };
+
+    let generated = quote! {
+        #missing_fields_warnings
+
+        #ord_key_assertions
+        #eq_key_assertions
+
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #ord_key_selector_funcs
+            #eq_key_selector_funcs
+            #extremes_funcs
+            #counter_funcs
+            #debug_keys_func
+            #cmp_mono_func
+            #sorted_funcs
+            #par_sort_func
+            #cache_funcs
+            #tiebreak_funcs
+            #constructor
+        }
+
+        #trait_impls
+        #has_key_impl
+
+        #option_ord
+        #(#queues)*
+        #btree_desc
+    };
+
+    let generated = match &attr.cfg {
+        Some(feature) => cfg_gate(generated, feature)?,
+        None => generated,
+    };
+
+    Ok(quote! {
+        #item
+
+        #generated
+    })
+}
+
+/// Marks every generated item with `#[cfg(feature = ...)]` of `cfg` option
+fn cfg_gate(
+    generated: proc_macro2::TokenStream,
+    feature: &syn::LitStr,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let file: syn::File = syn::parse2(generated)?;
+    let items = file.items.iter();
+
+    Ok(quote! {
+        #(
+            #[cfg(feature = #feature)]
+            #items
+        )*
+    })
+}
+
+/// Returns fields of the struct, or an error saying that `what` is supported only for structs
+fn struct_fields<'a>(input: &'a DeriveInput, what: &str) -> syn::Result<&'a Fields> {
+    match &input.data {
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("{} is supported only for structs", what),
+        )),
+    }
+}
+
+/// Generated code comparing values by keys
+pub(crate) struct Comparison {
+    /// Items of the inherent impl block used by comparison
+    pub(crate) funcs: proc_macro2::TokenStream,
+    /// Items next to the inherent impl block used by comparison
+    pub(crate) assertions: proc_macro2::TokenStream,
+    /// Body of [`Ord::cmp`]
+    pub(crate) cmp: proc_macro2::TokenStream,
+    /// Body of [`PartialOrd::partial_cmp`] for `partial_only` mode
+    pub(crate) partial_cmp: proc_macro2::TokenStream,
+    /// Body of [`PartialEq::eq`]
+    pub(crate) eq: proc_macro2::TokenStream,
+}
+
+fn comparison(
+    keys: Keys,
+    input: &DeriveInput,
+    prefix: &str,
+    bound: KeyBound,
+) -> syn::Result<Comparison> {
+    let key_selector = match (keys, &input.data) {
+        (Keys::EnumFields(_), Data::Enum(data)) => {
+            return Ok(enum_fields::comparison(data, nan(bound)))
+        }
+        (Keys::Transparent(span), Data::Struct(data)) => {
+            return transparent::comparison(&data.fields, span, nan(bound))
+        }
+        (Keys::FieldComparator { field, .. }, Data::Struct(_)) => {
+            return Ok(field_comparator(&field))
+        }
+        (Keys::FieldComparator { span, .. }, _) => {
+            return Err(syn::Error::new(
+                span,
+                "`by_field_comparator` is supported only for structs",
+            ))
+        }
+        (Keys::Transparent(span), _) => {
+            return Err(syn::Error::new(
+                span,
+                "`transparent` mode is supported only for structs",
+            ))
+        }
+        (Keys::KeySelector(key_selector), Data::Enum(_)) => {
+            if let ParamDefinition::Tuple(_) = &key_selector.param {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "`|(a, b, ...)|` parameter syntax is supported only for structs, \
+                     use `|e| match e { ... }` for enums",
+                ));
+            }
+
+            key_selector
+        }
+        (Keys::Fields { .. }, Data::Enum(_)) => {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`fields` mode is supported only for structs, use `enum_fields` for enums",
+            ))
+        }
+        (Keys::ByIndex(_), Data::Enum(_)) => {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`by_index` is supported only for structs with unnamed fields",
+            ))
+        }
+        (Keys::Methods(methods), _) => KeySelector::from_methods(methods),
+        (keys, _) => keys.into_key_selector(struct_fields(input, "`fields` mode")?)?,
+    };
+
+    check_zero_keys(&key_selector, input)?;
+
+    let name = &input.ident;
+    let (funcs, assertions, names) = key_selector_funcs(&key_selector, name, prefix, bound);
+
+    // Statements which compute `result` of comparison by each of the keys with `compare`
+    // expression of `key_self` and `key_other`, `cmp:` expressions compare values themselves
+    let steps = |compare: proc_macro2::TokenStream, from_ordering: proc_macro2::TokenStream| {
+        names
+            .iter()
+            .zip(&key_selector.comparators)
+            .map(|(key, comparator)| {
+                if *comparator {
+                    quote! {
+                        let ordering = Self::#key(self, other);
+                        let result = #from_ordering;
+                    }
+                } else {
+                    quote! {
+                        let key_self = Self::#key(self);
+                        let key_other = Self::#key(other);
+
+                        let result = #compare;
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let cmp_steps = steps(quote! { key_self.cmp(&key_other) }, quote! { ordering });
+    let cmp = quote! {
+        #(
+            #cmp_steps
+
+            if result != ::core::cmp::Ordering::Equal {
+                return result;
+            }
+        )*
+
+        return ::core::cmp::Ordering::Equal;
+    };
+
+    // Unlike total order, comparison stops at the first key which is not equal, including
+    // incomparable keys, and returns the key's result as is
+    let partial_cmp_steps = steps(
+        match nan(bound) {
+            Nan::None => quote! { key_self.partial_cmp(&key_other) },
+            Nan::Equal => quote! { ::ord_by_key::nan_equal_cmp(&key_self, &key_other) },
+        },
+        quote! { ::core::option::Option::Some(ordering) },
+    );
+    let partial_cmp = quote! {
+        #(
+            #partial_cmp_steps
+
+            if result != ::core::option::Option::Some(::core::cmp::Ordering::Equal) {
+                return result;
+            }
+        )*
+
+        return ::core::option::Option::Some(::core::cmp::Ordering::Equal);
+    };
+
+    let eq_steps = steps(
+        match nan(bound) {
+            Nan::None => quote! { key_self.eq(&key_other) },
+            Nan::Equal => quote! {
+                ::ord_by_key::nan_equal_cmp(&key_self, &key_other)
+                    == ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+            },
+        },
+        quote! { ordering == ::core::cmp::Ordering::Equal },
+    );
+    let eq = quote! {
+        #(
+            #eq_steps
+
+            if result != true {
+                return result;
+            }
+        )*
+
+        return true;
+    };
+
+    Ok(Comparison {
+        funcs,
+        assertions,
+        cmp,
+        partial_cmp,
+        eq,
+    })
+}
+
+/// Checks that key selector without keys is used only for a type without data: a struct without
+/// fields, or an enum with at most one variant without fields. All its values are equal, so
+/// there is nothing to compare
+fn check_zero_keys(key_selector: &KeySelector, input: &DeriveInput) -> syn::Result<()> {
+    if !key_selector.key_selectors.is_empty() {
+        return Ok(());
+    }
+
+    let without_data = match &input.data {
+        Data::Struct(data) => data.fields.is_empty(),
+        Data::Enum(data) => {
+            data.variants.len() <= 1 && data.variants.iter().all(|v| v.fields.is_empty())
+        }
+        Data::Union(_) => false,
+    };
+
+    if without_data {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "expected key expressions, e.g. `|p| p.key`, keys can be omitted only for structs \
+             without fields and enums with a single variant without fields",
+        ))
+    }
+}
+
+/// Generates comparison which calls comparator stored in `field` of `self`
+fn field_comparator(field: &syn::Member) -> Comparison {
+    let cmp = quote! { (self.#field)(self, other) };
+
+    Comparison {
+        funcs: quote! {},
+        assertions: quote! {},
+        partial_cmp: quote! { ::core::option::Option::Some(#cmp) },
+        eq: quote! { #cmp == ::core::cmp::Ordering::Equal },
+        cmp,
+    }
+}
+
+/// Returns NaN handling of `partial_only` keys, which is irrelevant for other bounds
+fn nan(bound: KeyBound) -> Nan {
+    match bound {
+        KeyBound::PartialOrd(nan) => nan,
+        _ => Nan::None,
+    }
+}
+
+/// Generates [`PartialOrd::partial_cmp`] of references `a` and `b` to fields of type `ty` for
+/// `partial_only` mode, with `nan = equal` fields are compared by `ord_by_key::nan_equal_cmp`.
+/// The type is named explicitly, so a type which doesn't implement the trait is reported at the
+/// field
+fn partial_cmp_field(
+    nan: Nan,
+    ty: &syn::Type,
+    a: impl quote::ToTokens,
+    b: impl quote::ToTokens,
+) -> proc_macro2::TokenStream {
+    match nan {
+        Nan::None => quote_spanned! {ty.span()=>
+            <#ty as ::core::cmp::PartialOrd>::partial_cmp(#a, #b)
+        },
+        Nan::Equal => quote_spanned! {ty.span()=>
+            ::ord_by_key::nan_equal_cmp::<#ty>(#a, #b)
+        },
+    }
+}
+
+/// Trait which keys have to implement
+#[derive(Clone, Copy)]
+enum KeyBound {
+    Ord,
+    Eq,
+    /// Keys of `partial_only` mode, compared according to NaN handling
+    PartialOrd(Nan),
+    Hash,
+}
+
+/// Generates a key selector function for each of key expressions. Functions are named
+/// `{prefix}_{index}`, and return `impl {key trait}`.
+///
+/// Key trait is generated for each of key expressions as a subtrait of [`Ord`] (or [`Eq`]) with
+/// a blanket implementation, which makes compiler report key which doesn't implement the trait
+/// with a custom message pointing at the key expression rather than at generated code.
+fn key_selector_funcs(
+    key_selector: &KeySelector,
+    structure_name: &Ident,
+    prefix: &str,
+    bound: KeyBound,
+) -> (
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    Vec<Ident>,
+) {
+    let key_selector_param = param_pattern(&key_selector.param);
+
+    let (bound, bound_name, key_name) = match bound {
+        KeyBound::Ord => (quote! { ::core::cmp::Ord }, "Ord", "key"),
+        KeyBound::Eq => (quote! { ::core::cmp::Eq }, "Eq", "`eq` key"),
+        KeyBound::PartialOrd(_) => (quote! { ::core::cmp::PartialOrd }, "PartialOrd", "key"),
+        KeyBound::Hash => (quote! { ::core::hash::Hash }, "Hash", "`hash` key"),
+    };
+
+    // Most keys which are not `Ord` or `Eq` contain floats
+    let note = match bound_name {
+        "Ord" => quote! {
+            note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+        },
+        "Eq" => quote! {
+            note = "if the key contains floats (alone or in a tuple), they are only `PartialEq`: \
+                    wrap them into `ord_by_key::TotalF64`"
+        },
+        _ => quote! {},
+    };
+
+    let key_selectors = &key_selector.key_selectors;
+    let key_bodies: Vec<_> = key_selectors.iter().map(key_body).collect();
+    let key_selector_func_names: Vec<_> = (0..key_selectors.len())
+        .map(|i| format!("{}_{}", prefix, i))
+        .map(|n| Ident::new(&n, proc_macro2::Span::mixed_site()))
+        .collect();
+
+    let mut assertions = quote! {};
+    let mut funcs = quote! {};
+
+    for (i, key) in key_selectors.iter().enumerate() {
+        let func_name = &key_selector_func_names[i];
+        let key_body = &key_bodies[i];
+
+        if key_selector.comparators[i] {
+            let (a, b) = match &key_selector.param {
+                ParamDefinition::Pair(a, b) => (a, b),
+                _ => unreachable!("checked by the parser"),
+            };
+            let ordering = quote_spanned! {key.span()=> ::core::cmp::Ordering };
+
+            funcs.extend(quote! {
+                fn #func_name(
+                    _ord_eq_by_key_selector_do_not_use: &Self,
+                    _ord_eq_by_key_selector_other: &Self,
+                ) -> #ordering {
+                    #[allow(unused_variables)]
+                    let #a = _ord_eq_by_key_selector_do_not_use;
+                    #[allow(unused_variables)]
+                    let #b = _ord_eq_by_key_selector_other;
+
+                    #key_body
+                }
+            });
+
+            continue;
+        }
+
+        let key_trait = Ident::new(
+            &format!("{}_{}_{}", prefix, structure_name, i),
+            proc_macro2::Span::mixed_site(),
+        );
+
+        let label = format!("`{{Self}}` does not implement `{}`", bound_name);
+        let assertion = |key_trait: &Ident, message: String| {
+            quote! {
+                #[diagnostic::on_unimplemented(message = #message, label = #label, #note)]
+                #[allow(non_camel_case_types)]
+                #[doc(hidden)]
+                trait #key_trait: #bound {}
+
+                #[automatically_derived]
+                impl<T: #bound + ?::core::marker::Sized> #key_trait for T {}
+            }
+        };
+
+        let return_type = match key {
+            // Tuple which is not `Ord` is reported by the element type which is not, without the
+            // message of the key trait, so elements of tuple keys are checked one by one
+            syn::Expr::Tuple(tuple) if !tuple.elems.is_empty() => {
+                let mut elements = vec![];
+
+                for (j, element) in tuple.elems.iter().enumerate() {
+                    let element_trait = Ident::new(
+                        &format!("{}_{}", key_trait, j),
+                        proc_macro2::Span::mixed_site(),
+                    );
+                    let message = match key_text(element) {
+                        Some(text) => format!(
+                            "element #{} (`{}`) of {} #{} is not `{}`",
+                            j + 1,
+                            text,
+                            key_name,
+                            i + 1,
+                            bound_name
+                        ),
+                        None => format!(
+                            "element #{} of {} #{} is not `{}`",
+                            j + 1,
+                            key_name,
+                            i + 1,
+                            bound_name
+                        ),
+                    };
+
+                    assertions.extend(assertion(&element_trait, message));
+                    elements.push(quote_spanned! {element.span()=> impl #element_trait + '_ });
+                }
+
+                quote! { ( #( #elements , )* ) }
+            }
+            key => {
+                let message = match key_text(key) {
+                    Some(text) => format!(
+                        "{} #{} (`{}`) is not `{}`",
+                        key_name,
+                        i + 1,
+                        text,
+                        bound_name
+                    ),
+                    None => format!("{} #{} is not `{}`", key_name, i + 1, bound_name),
+                };
+
+                assertions.extend(assertion(&key_trait, message));
+                quote_spanned! {key.span()=> impl #key_trait + '_ }
+            }
+        };
+
+        funcs.extend(quote! {
+            fn #func_name(_ord_eq_by_key_selector_do_not_use: &Self) -> #return_type {
+                // We should allow unused variables here to avoid unnecessary warnings in case caller is
+                // using syntax |(a,b,c)| to destruct tuple type but not using all of components of the
+                // tuple in key construction
+                #[allow(unused_variables)]
+                let #key_selector_param = _ord_eq_by_key_selector_do_not_use;
+
+                // TODO: Re-define input parameter _ord_eq_by_key_selector_do_not_use, so key
+                // selector won't be able to do unintentional access to it (all accesses should
+                // go through user-defined parameter names)
+
+                #key_body
+            }
+        });
+    }
+
+    (funcs, assertions, key_selector_func_names)
+}
+
+/// Body of a method of `&self` which returns the key by value: a tuple of values of key
+/// expressions, or the value of the only key expression. Keys of `fields` and `by_index` modes
+/// are always borrowed, so only key expressions and `methods` mode are supported by `what`
+fn key_value(
+    keys: &Keys,
+    span: proc_macro2::Span,
+    what: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    key_value_of(keys, &quote! { self }, span, what)
+}
+
+/// Same as [`key_value`], but returns the key of `value` of type `&Self` instead of `self`
+fn key_value_of(
+    keys: &Keys,
+    value: &proc_macro2::TokenStream,
+    span: proc_macro2::Span,
+    what: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    with_key_selector(keys, span, what, |key_selector| {
+        let param = param_pattern(&key_selector.param);
+        let bodies: Vec<_> = key_selector.key_selectors.iter().map(key_body).collect();
+
+        let key = match bodies.as_slice() {
+            [body] => quote! { { #body } },
+            bodies => quote! { ( #( { #bodies } ),* ) },
+        };
+
+        quote! {
+            #[allow(unused_variables)]
+            let #param = #value;
+
+            #key
+        }
+    })
+}
+
+/// Calls `f` with key selector of `keys`, which have to be key expressions or `methods` mode
+/// without `cmp:` expressions, since `what` needs values of keys
+fn with_key_selector<R>(
+    keys: &Keys,
+    span: proc_macro2::Span,
+    what: &str,
+    f: impl FnOnce(&KeySelector) -> R,
+) -> syn::Result<R> {
+    let from_methods;
+    let key_selector = match keys {
+        Keys::KeySelector(key_selector) => key_selector,
+        Keys::Methods(methods) => {
+            from_methods = KeySelector::from_methods(methods.clone());
+            &from_methods
+        }
+        _ => {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "{} requires key expressions (e.g. `|p| p.key`) or `methods` mode",
+                    what
+                ),
+            ))
+        }
+    };
+
+    if key_selector.comparators.contains(&true) {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "{} requires keys, `cmp:` expressions compare values without producing a key",
+                what
+            ),
+        ));
+    }
+
+    Ok(f(key_selector))
+}
+
+/// Pattern binding the parameter of key selector to `&Self`
+fn param_pattern(param: &ParamDefinition) -> proc_macro2::TokenStream {
+    match param {
+        ParamDefinition::SingleIdentifier(ident) | ParamDefinition::Pair(ident, _) => {
+            quote! {#ident}
+        }
+        ParamDefinition::Tuple(tuple) => {
+            quote! {
+                Self (
+                    #(
+                        #tuple ,
+                    )*
+                )
+
+            }
+        }
+    }
+}
+
+/// Body of the key selector function. Key expression with type annotation `expr: Type` is
+/// parsed by `syn` as type ascription, which is not valid Rust, so it's turned into a variable
+/// of that type to guide inference
+fn key_body(key: &syn::Expr) -> proc_macro2::TokenStream {
+    match key {
+        syn::Expr::Type(syn::ExprType { expr, ty, .. }) => {
+            let key = Ident::new(
+                "_ord_eq_by_key_selector_key",
+                proc_macro2::Span::mixed_site(),
+            );
+
+            // `(a + b): Type` is the way to annotate binary expressions, parentheses are not
+            // needed without ascription
+            let expr = match &**expr {
+                syn::Expr::Paren(syn::ExprParen { expr, .. }) => expr,
+                expr => expr,
+            };
+
+            quote! {
+                let #key: #ty = #expr;
+                #key
+            }
+        }
+        key => quote! { #key },
+    }
+}
+
+/// Text of the key expression for diagnostics, if it's short enough to fit in a message.
+/// Source text is not available on stable for expressions which consist of multiple tokens, so
+/// text is reconstructed from tokens with conventional spacing, e.g. `&p.name`
+fn key_text(key: &syn::Expr) -> Option<String> {
+    let mut text = String::new();
+    write_tokens(&mut text, quote! { #key });
+
+    if text.len() > 40 {
+        return None;
+    }
+
+    // `on_unimplemented` message is a format string
+    Some(text.replace('{', "{{").replace('}', "}}"))
+}
+
+fn write_tokens(text: &mut String, tokens: proc_macro2::TokenStream) {
+    use proc_macro2::Delimiter;
+    use proc_macro2::Spacing;
+    use proc_macro2::TokenTree;
+
+    // Whether the next token should be separated with a space
+    let mut space = false;
+    // Whether the previous token ends an operand, operators after operands are binary
+    let mut after_operand = false;
+
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) => {
+                let c = punct.as_char();
+                // `::` of paths is glued to both sides
+                let path_separator = c == ':' && punct.spacing() == Spacing::Joint;
+                let path_separator_end = c == ':' && text.ends_with(':');
+
+                if space && !path_separator && !matches!(c, '.' | ',' | ';' | '?') {
+                    text.push(' ');
+                }
+                text.push(c);
+
+                let unary = matches!(c, '&' | '*' | '!' | '-') && !after_operand;
+                space =
+                    punct.spacing() == Spacing::Alone && c != '.' && !unary && !path_separator_end;
+                after_operand = c == '?';
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+
+                // Calls and indexing are glued to the callee
+                if space && (!after_operand || group.delimiter() == Delimiter::Brace) {
+                    text.push(' ');
+                }
+                text.push_str(open);
+                write_tokens(text, group.stream());
+                text.push_str(close);
+                space = true;
+                after_operand = true;
+            }
+            _ => {
+                if space {
+                    text.push(' ');
+                }
+                text.push_str(&token.to_string());
+                space = true;
+                after_operand = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+    use super::key_text;
+
+    #[test]
+    fn test_key_text() {
+        for (key, expected) in &[
+            ("p.score", Some("p.score")),
+            ("&p.last_name", Some("&p.last_name")),
+            ("Reverse(p.age)", Some("Reverse(p.age)")),
+            ("p.0 .1", Some("p.0.1")),
+            ("-p.x * !p.y", Some("-p.x * !p.y")),
+            ("a - b", Some("a - b")),
+            ("p.items[0]", Some("p.items[0]")),
+            ("(p.a, &p.b)", Some("(p.a, &p.b)")),
+            (
+                "::core::cmp::Reverse(p.a)",
+                Some("::core::cmp::Reverse(p.a)"),
+            ),
+            ("p.get()?.len()", Some("p.get()?.len()")),
+            ("{ p.x }", Some("{{ p.x }}")),
+            ("p.a_very_long_field_name.another_long_field_name", None),
+        ] {
+            let key = syn::parse_str(key).unwrap();
+            assert_eq!(key_text(&key).as_deref(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_automatically_derived() {
+        let item = "pub struct Item { pub a: u32, pub b: Option<String>, pub c: u64 }";
+
+        for attr in &[
+            "|i| i.a, &i.b",
+            "|i| i.a; partial_only",
+            "fields",
+            "|i| i.a; gen_extremes = max/min, gen_option_ord = none_last, gen_has_key = u32",
+            "|i| i.a; cache = c, debug_counters",
+            "|i| i.a; fifo_tiebreak",
+            "ord = |i| i.a; eq = |i| i.a; unsafe_inconsistent",
+        ] {
+            let output =
+                expand(syn::parse_str(attr).unwrap(), syn::parse_str(item).unwrap()).unwrap();
+            let file: syn::File = syn::parse2(output).unwrap();
+            let mut impls = 0;
+
+            for item in &file.items {
+                if let syn::Item::Impl(item) = item {
+                    impls += 1;
+                    assert!(
+                        item.attrs
+                            .iter()
+                            .any(|a| a.path.is_ident("automatically_derived")),
+                        "`{}`: impl without `#[automatically_derived]`",
+                        attr
+                    );
+                }
+            }
+
+            assert!(impls > 0);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Best-effort detection of fields which are not used by any of key expressions, see
+//! `warn_missing_fields` option
+
+use crate::attr::Keys;
+use crate::attr::MissingFields;
+use crate::attr::ParamDefinition;
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
+use quote::format_ident;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::Fields;
+use syn::Member;
+
+/// Generates a use of a deprecated item for every field of the struct which doesn't appear in
+/// any of `keys`, is not one of `hashed` fields of `hash_residual` and is not listed in
+/// `skip(...)`. Stable Rust doesn't allow procedural macros to emit warnings, but deprecation
+/// warnings are reported at the span of the use, which is set to the span of the field.
+pub(crate) fn missing_fields_warnings(
+    options: &MissingFields,
+    keys: &[&Keys],
+    hashed: &[Member],
+    fields: &Fields,
+) -> syn::Result<TokenStream> {
+    let members: Vec<Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        })
+        .collect();
+
+    for skipped in &options.skip {
+        if !members.contains(skipped) {
+            return Err(syn::Error::new(
+                skipped.span(),
+                "`warn_missing_fields` skips a field which doesn't exist",
+            ));
+        }
+    }
+
+    let mut warnings = TokenStream::new();
+
+    for (i, (member, field)) in members.iter().zip(fields.iter()).enumerate() {
+        if options.skip.contains(member)
+            || hashed.contains(member)
+            || keys.iter().any(|keys| uses_field(keys, i, member))
+        {
+            continue;
+        }
+
+        let note = format!(
+            "field `{}` is not used by any key of `ord_eq_by_key_selector`, use it in a key or \
+             add it to `warn_missing_fields(skip(...))`",
+            member.to_token_stream()
+        );
+
+        let span = match &field.ident {
+            Some(ident) => ident.span(),
+            None => field.ty.span(),
+        };
+
+        let name = match member {
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(index) => format_ident!("field_{}", index.index, span = span),
+        };
+
+        warnings.extend(quote_spanned! {span=>
+            const _: () = {
+                #[deprecated(note = #note)]
+                #[allow(non_upper_case_globals)]
+                const #name: () = ();
+                #name
+            };
+        });
+    }
+
+    Ok(warnings)
+}
+
+/// Returns true if field `index` with name `member` might be used by `keys`. Key expressions are
+/// not resolved, field is considered used if its name appears anywhere in them:
+/// * `|p|` or `|a, b|` with named fields - identifier with the same name as the field, e.g.
+///   `p.name`
+/// * `|p|` or `|a, b|` with unnamed fields - `.index`, e.g. `p.0`
+/// * `|(a, b)|` - parameter bound to the field
+fn uses_field(keys: &Keys, index: usize, member: &Member) -> bool {
+    let key_selector = match keys {
+        Keys::KeySelector(key_selector) => key_selector,
+        // `fields` mode uses fields by definition and `take = N` excludes the rest explicitly
+        Keys::Fields { .. } => return true,
+        // Only supported for enums, which are rejected by `warn_missing_fields`
+        Keys::EnumFields(_) => return true,
+        // The only field is compared
+        Keys::Transparent(_) => return true,
+        // Bodies of methods and comparators are not visible to the macro
+        Keys::Methods(_) | Keys::FieldComparator { .. } => return true,
+        Keys::ByIndex(keys) => {
+            return keys
+                .iter()
+                .any(|key| key.index.base10_parse::<usize>().ok() == Some(index))
+        }
+    };
+
+    let mut tokens = TokenStream::new();
+    for key in &key_selector.key_selectors {
+        key.to_tokens(&mut tokens);
+    }
+
+    match (&key_selector.param, member) {
+        (ParamDefinition::Tuple(params), _) => match params.get(index) {
+            Some(param) => contains_ident(tokens, &param.to_string()),
+            None => false,
+        },
+        (
+            ParamDefinition::SingleIdentifier(_) | ParamDefinition::Pair(..),
+            Member::Named(ident),
+        ) => contains_ident(tokens, &ident.to_string()),
+        (
+            ParamDefinition::SingleIdentifier(_) | ParamDefinition::Pair(..),
+            Member::Unnamed(index),
+        ) => contains_index(tokens, &index.index.to_string()),
+    }
+}
+
+fn contains_ident(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => contains_ident(group.stream(), name),
+        _ => false,
+    })
+}
+
+/// Looks for `.index`. Note that `p.0.1` is tokenized as `p`, `.`, `0.1`
+fn contains_index(tokens: TokenStream, index: &str) -> bool {
+    let mut after_dot = false;
+
+    tokens.into_iter().any(|token| {
+        let found = match &token {
+            TokenTree::Literal(literal) if after_dot => {
+                literal.to_string().split('.').next() == Some(index)
+            }
+            TokenTree::Group(group) => contains_index(group.stream(), index),
+            _ => false,
+        };
+
+        after_dot = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '.');
+        found
+    })
+}
@@ -0,0 +1,126 @@
+//! `gen_min_queue` and `gen_max_queue` options, which generate priority queue newtypes of the
+//! type
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+use syn::Ident;
+use syn::Visibility;
+
+/// Which value the queue returns first
+#[derive(Clone, Copy)]
+pub(crate) enum QueueOrder {
+    Min,
+    Max,
+}
+
+/// Generates newtype `queue` of `BinaryHeap` which returns values of the type from the smallest
+/// or from the largest, depending on `order`
+pub(crate) fn queue(
+    queue: &Ident,
+    order: QueueOrder,
+    input: &DeriveInput,
+    vis: &Visibility,
+) -> TokenStream {
+    let name = &input.ident;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let value = quote! { #name #ty_generics };
+
+    // Min queue keeps values in `Reverse`, since `BinaryHeap` is a max-heap
+    let (element, wrap, unwrap, unwrap_ref, doc, first) = match order {
+        QueueOrder::Min => (
+            quote! { ::core::cmp::Reverse<#value> },
+            quote! { ::core::cmp::Reverse(value) },
+            quote! { value.0 },
+            quote! { &value.0 },
+            format!(
+                "Priority queue of [`{}`] which returns the smallest value first",
+                name
+            ),
+            "smallest",
+        ),
+        QueueOrder::Max => (
+            quote! { #value },
+            quote! { value },
+            quote! { value },
+            quote! { value },
+            format!(
+                "Priority queue of [`{}`] which returns the largest value first",
+                name
+            ),
+            "largest",
+        ),
+    };
+
+    let pop_doc = format!("Removes the {} value from the queue and returns it", first);
+    let peek_doc = format!("Returns the {} value without removing it", first);
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #queue #generics (
+            ::std::collections::BinaryHeap<#element>,
+        ) #where_clause;
+
+        #[automatically_derived]
+        impl #impl_generics #queue #ty_generics #where_clause {
+            /// Creates an empty queue
+            #vis fn new() -> Self {
+                #queue(::std::collections::BinaryHeap::new())
+            }
+
+            /// Adds the value to the queue
+            #vis fn push(&mut self, value: #value) {
+                self.0.push(#wrap)
+            }
+
+            #[doc = #pop_doc]
+            #vis fn pop(&mut self) -> ::core::option::Option<#value> {
+                self.0.pop().map(|value| #unwrap)
+            }
+
+            #[doc = #peek_doc]
+            #vis fn peek(&self) -> ::core::option::Option<&#value> {
+                self.0.peek().map(|value| #unwrap_ref)
+            }
+
+            /// Returns number of values in the queue
+            #vis fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Returns true if the queue has no values
+            #vis fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            /// Removes all values from the queue
+            #vis fn clear(&mut self) {
+                self.0.clear()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::default::Default for #queue #ty_generics #where_clause {
+            fn default() -> Self {
+                #queue::new()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::iter::Extend<#value> for #queue #ty_generics #where_clause {
+            fn extend<I: ::core::iter::IntoIterator<Item = #value>>(&mut self, values: I) {
+                self.0.extend(values.into_iter().map(|value| #wrap))
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::iter::FromIterator<#value> for #queue #ty_generics
+            #where_clause
+        {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #value>>(values: I) -> Self {
+                #queue(values.into_iter().map(|value| #wrap).collect())
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Snapshot tests of generated code
+//!
+//! Every `tests/expand/{name}.rs` contains items, one of which is annotated with
+//! `#[ord_eq_by_key_selector(...)]` or `#[ord_by_key_advanced(...)]`. The annotated item is
+//! replaced with its expansion, and the pretty-printed result is compared with
+//! `tests/expand/{name}.expanded.rs`. Run with `ORD_BY_KEY_SNAPSHOTS=overwrite` to create or update
+//! snapshots after intended changes of generated code, and review the diff.
+
+use crate::advanced;
+use crate::expand;
+use quote::ToTokens;
+use std::fs;
+use std::path::Path;
+
+const ATTRIBUTE: &str = "ord_eq_by_key_selector";
+const ADVANCED_ATTRIBUTE: &str = "ord_by_key_advanced";
+
+/// Replaces the annotated item of `source` with its expansion and pretty-prints the result
+fn expand_source(source: &str) -> String {
+    let file: syn::File = syn::parse_str(source).expect("snapshot source doesn't parse");
+    let mut tokens = proc_macro2::TokenStream::new();
+    let mut expanded = 0;
+
+    for item in &file.items {
+        let mut input: syn::DeriveInput = match item {
+            syn::Item::Struct(_) | syn::Item::Enum(_) => {
+                syn::parse2(item.to_token_stream()).expect("struct or enum is a valid derive input")
+            }
+            _ => {
+                item.to_tokens(&mut tokens);
+                continue;
+            }
+        };
+
+        let annotated = input
+            .attrs
+            .iter()
+            .position(|a| a.path.is_ident(ATTRIBUTE) || a.path.is_ident(ADVANCED_ATTRIBUTE));
+
+        match annotated {
+            Some(index) => {
+                let attr = input.attrs.remove(index);
+                let expansion = if attr.path.is_ident(ATTRIBUTE) {
+                    expand(attr.parse_args().unwrap(), input)
+                } else {
+                    advanced::expand(attr.parse_args().unwrap(), input)
+                };
+
+                tokens.extend(expansion.unwrap());
+                expanded += 1;
+            }
+            None => input.to_tokens(&mut tokens),
+        }
+    }
+
+    assert_eq!(
+        expanded, 1,
+        "snapshot source must have exactly one annotated item"
+    );
+
+    let file: syn2::File = syn2::parse2(tokens).expect("expansion doesn't parse");
+    prettyplease::unparse(&file)
+}
+
+#[test]
+fn test_snapshots() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+    let overwrite = std::env::var("ORD_BY_KEY_SNAPSHOTS").as_deref() == Ok("overwrite");
+
+    let mut sources: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_str().unwrap();
+            name.ends_with(".rs") && !name.ends_with(".expanded.rs")
+        })
+        .collect();
+    sources.sort();
+
+    assert!(!sources.is_empty());
+
+    let mut mismatches = vec![];
+
+    for source in &sources {
+        let actual = expand_source(&fs::read_to_string(source).unwrap());
+        let snapshot = source.with_extension("expanded.rs");
+
+        if overwrite {
+            fs::write(&snapshot, &actual).unwrap();
+            continue;
+        }
+
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => {
+                let line = expected
+                    .lines()
+                    .zip(actual.lines())
+                    .position(|(expected, actual)| expected != actual)
+                    .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+
+                mismatches.push(format!(
+                    "{} differs at line {}",
+                    snapshot.display(),
+                    line + 1
+                ));
+            }
+            Err(_) => mismatches.push(format!("{} is missing", snapshot.display())),
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "generated code changed, run with `ORD_BY_KEY_SNAPSHOTS=overwrite` to update \
+         snapshots:\n{}",
+        mismatches.join("\n")
+    );
+}
@@ -0,0 +1,64 @@
+//! `gen_sorted` option, which generates constructors of sorted `Vec`s of the type, and
+//! `gen_par_sort` option, which generates parallel sorting of slices of the type
+
+use crate::attr::Keys;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::quote_spanned;
+use syn::Ident;
+use syn::Visibility;
+
+/// Generates `sorted`, which sorts by `Ord`, and `sorted_by_cached_key`, which sorts by values of
+/// `keys` computed once per value
+pub(crate) fn sorted_funcs(span: Span, keys: &Keys, vis: &Visibility) -> syn::Result<TokenStream> {
+    let value = Ident::new("_ord_eq_by_key_selector_value", Span::mixed_site());
+    let key = crate::key_value_of(keys, &quote! { #value }, span, "`gen_sorted`")?;
+
+    Ok(quote! {
+        /// Collects `values` into a `Vec` sorted by [`Ord`] of the type, values which are equal
+        /// keep their order
+        #vis fn sorted<I>(values: I) -> ::std::vec::Vec<Self>
+        where
+            I: ::core::iter::IntoIterator<Item = Self>,
+        {
+            let mut values: ::std::vec::Vec<Self> = values.into_iter().collect();
+            values.sort();
+            values
+        }
+
+        /// Collects `values` into a `Vec` sorted by keys, which are computed once per value with
+        /// `sort_by_cached_key` instead of on every comparison. Values with equal keys keep
+        /// their order
+        #vis fn sorted_by_cached_key<I>(values: I) -> ::std::vec::Vec<Self>
+        where
+            I: ::core::iter::IntoIterator<Item = Self>,
+        {
+            let mut values: ::std::vec::Vec<Self> = values.into_iter().collect();
+            values.sort_by_cached_key(|#value| { #key });
+            values
+        }
+    })
+}
+
+/// Generates `par_sort`, which sorts a slice in parallel with `ord_by_key::par_sort`. Requires
+/// `rayon` feature, which `ord_by_key` enables for the macro with its own `rayon` feature
+pub(crate) fn par_sort_func(span: Span, vis: &Visibility) -> syn::Result<TokenStream> {
+    if !cfg!(feature = "rayon") {
+        return Err(syn::Error::new(
+            span,
+            "`gen_par_sort` requires `rayon` feature of `ord_by_key`",
+        ));
+    }
+
+    Ok(quote_spanned! {span=>
+        /// Sorts `values` in parallel with `rayon` by [`Ord`] of the type. The sort is
+        /// unstable, values which are equal may be reordered
+        #vis fn par_sort(values: &mut [Self])
+        where
+            Self: ::core::marker::Send,
+        {
+            ::ord_by_key::par_sort(values)
+        }
+    })
+}
@@ -0,0 +1,172 @@
+//! Final key compared after all declared keys, shared by `fifo_tiebreak`, `content_tiebreak` and
+//! `hash_residual`
+
+use crate::attr::HashResidual;
+use crate::Comparison;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Fields;
+use syn::Ident;
+use syn::Member;
+
+/// Comparison of the final key of `self` and `other`
+pub(crate) struct Tiebreak {
+    pub(crate) cmp: TokenStream,
+    pub(crate) eq: TokenStream,
+    /// Whether the key is checked for equality before the declared keys
+    pub(crate) eq_first: bool,
+}
+
+/// Compares stable hashes of the whole values, which requires `Self: Hash`. The hash is more
+/// expensive than most keys, so it's computed only when declared keys are equal
+pub(crate) fn content_tiebreak(span: Span) -> Tiebreak {
+    Tiebreak {
+        cmp: quote_spanned! {span=>
+            ::ord_by_key::StableHash(self).cmp(&::ord_by_key::StableHash(other))
+        },
+        eq: quote_spanned! {span=>
+            ::ord_by_key::StableHash(self) == ::ord_by_key::StableHash(other)
+        },
+        eq_first: false,
+    }
+}
+
+/// Compares stable hashes of `members`, which requires them to be `Hash`. Fields are hashed as
+/// nested pairs, which hash the same as a flat tuple but aren't limited in length. Every field
+/// passes through a function requiring `Hash`, so a field which isn't is reported at its name
+pub(crate) fn hash_residual(members: &[Member]) -> Tiebreak {
+    let hashed = Ident::new("_ord_eq_by_key_selector_hashed", Span::mixed_site());
+    let fields = |value: &str| {
+        members.iter().rev().fold(quote! { () }, |rest, member| {
+            let value = Ident::new(value, member.span());
+            quote_spanned! {member.span()=> (#hashed(&#value.#member), #rest) }
+        })
+    };
+
+    let this = fields("self");
+    let other = fields("other");
+    let hashed_fn = quote! {
+        fn #hashed<T: ::core::hash::Hash + ?::core::marker::Sized>(value: &T) -> &T {
+            value
+        }
+    };
+
+    Tiebreak {
+        cmp: quote! {{
+            #hashed_fn
+            ::ord_by_key::StableHash(&#this).cmp(&::ord_by_key::StableHash(&#other))
+        }},
+        eq: quote! {{
+            #hashed_fn
+            ::ord_by_key::StableHash(&#this) == ::ord_by_key::StableHash(&#other)
+        }},
+        eq_first: false,
+    }
+}
+
+/// Checks that all fields of `hash_residual` exist, so the error is reported at the listed field
+/// instead of the generated field access
+pub(crate) fn check_hash_residual(
+    hash_residual: &HashResidual,
+    fields: &Fields,
+) -> syn::Result<()> {
+    for (i, member) in hash_residual.members.iter().enumerate() {
+        let exists = fields
+            .iter()
+            .enumerate()
+            .any(|(index, field)| match (member, &field.ident) {
+                (Member::Named(name), Some(ident)) => name == ident,
+                (Member::Unnamed(unnamed), None) => unnamed.index as usize == index,
+                _ => false,
+            });
+
+        if !exists {
+            return Err(syn::Error::new(
+                member.span(),
+                "`hash_residual` lists a field which doesn't exist",
+            ));
+        }
+
+        if hash_residual.members[..i].contains(member) {
+            return Err(syn::Error::new(
+                member.span(),
+                "`hash_residual` lists the same field twice",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves comparison by keys into hidden functions, and compares the final key when keys are
+/// equal. Only the body used by the generated traits is produced, since `partial_only` keys
+/// don't implement `Ord`
+pub(crate) fn tiebreak(
+    cmp: TokenStream,
+    partial_cmp: TokenStream,
+    eq: TokenStream,
+    partial_only: bool,
+    tiebreak: Tiebreak,
+) -> Comparison {
+    let keys_cmp = Ident::new("_ord_eq_by_key_selector_keys_cmp", Span::mixed_site());
+    let keys_eq = Ident::new("_ord_eq_by_key_selector_keys_eq", Span::mixed_site());
+    let Tiebreak {
+        cmp: tie_cmp,
+        eq: tie_eq,
+        eq_first,
+    } = tiebreak;
+
+    let eq_funcs = quote! {
+        fn #keys_eq(&self, other: &Self) -> bool {
+            #eq
+        }
+    };
+
+    let eq = if eq_first {
+        quote! { #tie_eq && Self::#keys_eq(self, other) }
+    } else {
+        quote! { Self::#keys_eq(self, other) && #tie_eq }
+    };
+
+    if partial_only {
+        Comparison {
+            funcs: quote! {
+                fn #keys_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    #partial_cmp
+                }
+
+                #eq_funcs
+            },
+            assertions: quote! {},
+            cmp: quote! {},
+            partial_cmp: quote! {
+                match Self::#keys_cmp(self, other) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => {
+                        ::core::option::Option::Some(#tie_cmp)
+                    }
+                    result => result,
+                }
+            },
+            eq,
+        }
+    } else {
+        Comparison {
+            funcs: quote! {
+                fn #keys_cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    #cmp
+                }
+
+                #eq_funcs
+            },
+            assertions: quote! {},
+            cmp: quote! {
+                Self::#keys_cmp(self, other).then_with(|| #tie_cmp)
+            },
+            partial_cmp: quote! {},
+            eq,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! `transparent` mode, which delegates comparison of a single-field struct to the field
+
+use crate::attr::Nan;
+use crate::Comparison;
+use proc_macro2::Span;
+use quote::quote;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Fields;
+use syn::Member;
+
+/// Generates comparison which calls trait methods of the only field directly, without key
+/// selector functions, so it compiles to the same code as hand-written delegation
+pub(crate) fn comparison(fields: &Fields, span: Span, nan: Nan) -> syn::Result<Comparison> {
+    let field = match fields.iter().next() {
+        Some(field) if fields.len() == 1 => field,
+        _ => {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "`transparent` mode requires a struct with exactly one field, found {}",
+                    fields.len()
+                ),
+            ))
+        }
+    };
+
+    let ty = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+
+    let partial_cmp =
+        crate::partial_cmp_field(nan, ty, quote! { &self.#member }, quote! { &other.#member });
+
+    // Field type is named explicitly, so a type which doesn't implement the trait is reported at
+    // the field. With `nan = equal` values are equal if `partial_cmp` says so, which makes NaN
+    // equal
+    Ok(Comparison {
+        funcs: quote! {},
+        assertions: quote! {},
+        cmp: quote_spanned! {ty.span()=>
+            <#ty as ::core::cmp::Ord>::cmp(&self.#member, &other.#member)
+        },
+        partial_cmp: partial_cmp.clone(),
+        eq: match nan {
+            Nan::None => quote_spanned! {ty.span()=>
+                <#ty as ::core::cmp::PartialEq>::eq(&self.#member, &other.#member)
+            },
+            Nan::Equal => quote! {
+                #partial_cmp == ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+            },
+        },
+    })
+}
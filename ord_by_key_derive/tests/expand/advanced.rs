@@ -0,0 +1,7 @@
+use ord_by_key::ord_by_key_advanced;
+
+#[ord_by_key_advanced(ord = |p| p.score, p.id; eq = |p| p.id; hash = |p| p.id)]
+pub struct Player {
+    pub id: u32,
+    pub score: u32,
+}
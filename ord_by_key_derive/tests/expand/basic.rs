@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|p| p.age)]
+pub struct Person {
+    pub name: String,
+    pub age: u32,
+}
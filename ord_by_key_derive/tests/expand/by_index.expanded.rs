@@ -0,0 +1,92 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Score(String, u32);
+#[diagnostic::on_unimplemented(
+    message = "key #1 is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Score_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Score_0
+for T {}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`&_ord_eq_by_key_selector_fields.0`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Score_1: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Score_1
+for T {}
+#[automatically_derived]
+impl Score {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Score_0 + '_ {
+        #[allow(unused_variables)]
+        let _ord_eq_by_key_selector_fields = _ord_eq_by_key_selector_do_not_use;
+        ::core::cmp::Reverse(&_ord_eq_by_key_selector_fields.1)
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Score_1 + '_ {
+        #[allow(unused_variables)]
+        let _ord_eq_by_key_selector_fields = _ord_eq_by_key_selector_do_not_use;
+        &_ord_eq_by_key_selector_fields.0
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Score {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Score {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Score {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,4 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(by_index = [1 desc, 0])]
+pub struct Score(String, u32);
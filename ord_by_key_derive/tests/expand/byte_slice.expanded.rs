@@ -0,0 +1,93 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Blob {
+    pub data: Vec<u8>,
+    pub digest: [u8; 32],
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`&b.data[..]`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Blob_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Blob_0 for T {}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`ord_by_key::Bytes(&b.digest)`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Blob_1: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Blob_1 for T {}
+#[automatically_derived]
+impl Blob {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Blob_0 + '_ {
+        #[allow(unused_variables)]
+        let b = _ord_eq_by_key_selector_do_not_use;
+        &b.data[..]
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Blob_1 + '_ {
+        #[allow(unused_variables)]
+        let b = _ord_eq_by_key_selector_do_not_use;
+        ord_by_key::Bytes(&b.digest)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Blob {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Blob {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Blob {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Blob {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
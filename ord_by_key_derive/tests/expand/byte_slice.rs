@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|b| &b.data[..], ord_by_key::Bytes(&b.digest))]
+pub struct Blob {
+    pub data: Vec<u8>,
+    pub digest: [u8; 32],
+}
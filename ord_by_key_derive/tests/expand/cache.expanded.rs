@@ -0,0 +1,91 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Document {
+    pub title: String,
+    sort_key: String,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`&_ord_eq_by_key_selector_cache.sort_key`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Document_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Document_0
+for T {}
+#[automatically_derived]
+impl Document {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Document_0 + '_ {
+        #[allow(unused_variables)]
+        let _ord_eq_by_key_selector_cache = _ord_eq_by_key_selector_do_not_use;
+        &_ord_eq_by_key_selector_cache.sort_key
+    }
+    ///Computes the key which is cached in `sort_key`
+    pub fn compute_sort_key(&self) -> String {
+        #[allow(unused_variables)]
+        let d = self;
+        { d.title.to_lowercase() }
+    }
+    ///Stores the key in `sort_key`, has to be called after modifying fields which the key depends on
+    pub fn refresh_sort_key(&mut self) {
+        self.sort_key = self.compute_sort_key();
+    }
+    #[cfg(debug_assertions)]
+    fn _ord_eq_by_key_selector_check_cache(&self) {
+        if self.compute_sort_key() != self.sort_key {
+            ::core::panic!(
+                "cached key `sort_key` of `Document` is stale, call `refresh_sort_key` after modifying fields which the key depends on"
+            );
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Document {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(debug_assertions)]
+        {
+            Self::_ord_eq_by_key_selector_check_cache(self);
+            Self::_ord_eq_by_key_selector_check_cache(other);
+        }
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Document {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Document {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        #[cfg(debug_assertions)]
+        {
+            Self::_ord_eq_by_key_selector_check_cache(self);
+            Self::_ord_eq_by_key_selector_check_cache(other);
+        }
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Document {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|d| d.title.to_lowercase(); cache = sort_key)]
+pub struct Document {
+    pub title: String,
+    sort_key: String,
+}
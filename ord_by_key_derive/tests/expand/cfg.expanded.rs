@@ -0,0 +1,69 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Record {
+    pub id: u32,
+}
+#[cfg(feature = "ordering")]
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`r.id`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Record_0: ::core::cmp::Ord {}
+#[cfg(feature = "ordering")]
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Record_0
+for T {}
+#[cfg(feature = "ordering")]
+#[automatically_derived]
+impl Record {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Record_0 + '_ {
+        #[allow(unused_variables)]
+        let r = _ord_eq_by_key_selector_do_not_use;
+        r.id
+    }
+}
+#[cfg(feature = "ordering")]
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[cfg(feature = "ordering")]
+#[automatically_derived]
+impl ::core::cmp::Eq for Record {}
+#[cfg(feature = "ordering")]
+#[automatically_derived]
+impl ::core::cmp::Ord for Record {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[cfg(feature = "ordering")]
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Record {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,6 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|r| r.id; cfg = "ordering")]
+pub struct Record {
+    pub id: u32,
+}
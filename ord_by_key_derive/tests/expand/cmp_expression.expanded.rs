@@ -0,0 +1,82 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Task {
+    pub pin: Option<u32>,
+    pub title: String,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`&a.title`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Task_1: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Task_1 for T {}
+#[automatically_derived]
+impl Task {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+        _ord_eq_by_key_selector_other: &Self,
+    ) -> ::core::cmp::Ordering {
+        #[allow(unused_variables)]
+        let a = _ord_eq_by_key_selector_do_not_use;
+        #[allow(unused_variables)]
+        let b = _ord_eq_by_key_selector_other;
+        a.pin.cmp(&b.pin).reverse()
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Task_1 + '_ {
+        #[allow(unused_variables)]
+        let a = _ord_eq_by_key_selector_do_not_use;
+        &a.title
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        let ordering = Self::_ord_eq_by_key_selector_0(self, other);
+        let result = ordering == ::core::cmp::Ordering::Equal;
+        if result != true {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Task {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Task {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let ordering = Self::_ord_eq_by_key_selector_0(self, other);
+        let result = ordering;
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Task {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
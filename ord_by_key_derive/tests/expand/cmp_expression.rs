@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|a, b| cmp: a.pin.cmp(&b.pin).reverse(), &a.title)]
+pub struct Task {
+    pub pin: Option<u32>,
+    pub title: String,
+}
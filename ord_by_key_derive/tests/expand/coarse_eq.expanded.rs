@@ -0,0 +1,108 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Sample {
+    pub category: u8,
+    pub score: u32,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`s.category`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Sample_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Sample_0
+for T {}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`s.score`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Sample_1: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Sample_1
+for T {}
+#[diagnostic::on_unimplemented(
+    message = "`eq` key #1 (`s.category`) is not `Eq`",
+    label = "`{Self}` does not implement `Eq`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialEq`: \
+                    wrap them into `ord_by_key::TotalF64`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_eq_Sample_0: ::core::cmp::Eq {}
+#[automatically_derived]
+impl<T: ::core::cmp::Eq + ?::core::marker::Sized> _ord_eq_by_key_selector_eq_Sample_0
+for T {}
+#[automatically_derived]
+impl Sample {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Sample_0 + '_ {
+        #[allow(unused_variables)]
+        let s = _ord_eq_by_key_selector_do_not_use;
+        s.category
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Sample_1 + '_ {
+        #[allow(unused_variables)]
+        let s = _ord_eq_by_key_selector_do_not_use;
+        s.score
+    }
+    fn _ord_eq_by_key_selector_eq_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_eq_Sample_0 + '_ {
+        #[allow(unused_variables)]
+        let s = _ord_eq_by_key_selector_do_not_use;
+        s.category
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Sample {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_eq_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_eq_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Sample {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Sample {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Sample {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
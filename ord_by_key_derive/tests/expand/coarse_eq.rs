@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(ord = |s| s.category, s.score; eq = |s| s.category; unsafe_inconsistent)]
+pub struct Sample {
+    pub category: u8,
+    pub score: u32,
+}
@@ -0,0 +1,73 @@
+use ord_by_key::ord_eq_by_key_selector;
+#[derive(Hash)]
+pub struct Task {
+    pub priority: u32,
+    pub name: &'static str,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`t.priority`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Task_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Task_0 for T {}
+#[automatically_derived]
+impl Task {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Task_0 + '_ {
+        #[allow(unused_variables)]
+        let t = _ord_eq_by_key_selector_do_not_use;
+        t.priority
+    }
+    fn _ord_eq_by_key_selector_keys_cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+    fn _ord_eq_by_key_selector_keys_eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        Self::_ord_eq_by_key_selector_keys_eq(self, other)
+            && ::ord_by_key::StableHash(self) == ::ord_by_key::StableHash(other)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Task {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Task {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        Self::_ord_eq_by_key_selector_keys_cmp(self, other)
+            .then_with(|| {
+                ::ord_by_key::StableHash(self).cmp(&::ord_by_key::StableHash(other))
+            })
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Task {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,8 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|t| t.priority; content_tiebreak)]
+#[derive(Hash)]
+pub struct Task {
+    pub priority: u32,
+    pub name: &'static str,
+}
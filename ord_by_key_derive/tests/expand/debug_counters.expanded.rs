@@ -0,0 +1,79 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct ByLen(String);
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`s.len()`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_ByLen_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_ByLen_0
+for T {}
+#[automatically_derived]
+impl ByLen {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_ByLen_0 + '_ {
+        #[allow(unused_variables)]
+        let Self(s) = _ord_eq_by_key_selector_do_not_use;
+        s.len()
+    }
+    #[cfg(debug_assertions)]
+    fn _ord_eq_by_key_selector_comparisons() -> &'static ::std::thread::LocalKey<
+        ::core::cell::Cell<u64>,
+    > {
+        ::std::thread_local! {
+            static COMPARISONS : ::core::cell::Cell < u64 > = ::core::cell::Cell::new(0);
+        }
+        &COMPARISONS
+    }
+    /// Returns number of comparisons of values of this type made by the current thread.
+    /// Always returns 0 if `debug_assertions` are disabled
+    pub fn comparison_count() -> u64 {
+        #[cfg(debug_assertions)]
+        return Self::_ord_eq_by_key_selector_comparisons().with(|count| count.get());
+        #[cfg(not(debug_assertions))] return 0;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ByLen {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ByLen {}
+#[automatically_derived]
+impl ::core::cmp::Ord for ByLen {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        #[cfg(debug_assertions)]
+        Self::_ord_eq_by_key_selector_comparisons()
+            .with(|count| count.set(count.get() + 1));
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for ByLen {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,4 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|(s)| s.len(); debug_counters)]
+pub struct ByLen(String);
@@ -0,0 +1,119 @@
+use core::cmp::Reverse;
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Player {
+    pub name: String,
+    pub score: u32,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`&p.name`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Player_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Player_0
+for T {}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`Reverse(p.score)`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Player_1: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Player_1
+for T {}
+#[automatically_derived]
+impl Player {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Player_0 + '_ {
+        #[allow(unused_variables)]
+        let p = _ord_eq_by_key_selector_do_not_use;
+        &p.name
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Player_1 + '_ {
+        #[allow(unused_variables)]
+        let p = _ord_eq_by_key_selector_do_not_use;
+        Reverse(p.score)
+    }
+    /// Returns keys of the value, which format with [`Debug`](::core::fmt::Debug) as a
+    /// tuple in order of comparison. Keys in reverse order (`Reverse(key)`) are shown
+    /// as the key marked with `↓`
+    pub fn debug_keys(&self) -> impl ::core::fmt::Debug + '_ {
+        struct _ord_eq_by_key_selector_DebugKeys<F>(F);
+        impl<F> ::core::fmt::Debug for _ord_eq_by_key_selector_DebugKeys<F>
+        where
+            F: ::core::ops::Fn(&mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                (self.0)(f)
+            }
+        }
+        _ord_eq_by_key_selector_DebugKeys(move |f: &mut ::core::fmt::Formatter<'_>| {
+            #[allow(unused_variables)]
+            let p = self;
+            let mut _ord_eq_by_key_selector_tuple = f.debug_tuple("");
+            _ord_eq_by_key_selector_tuple.field(&{ &p.name });
+            _ord_eq_by_key_selector_tuple
+                .field(&::core::format_args!("↓{:?}", { p.score }));
+            _ord_eq_by_key_selector_tuple.finish()
+        })
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Player {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Player {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Player {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Player {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
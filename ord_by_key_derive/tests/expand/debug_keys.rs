@@ -0,0 +1,8 @@
+use core::cmp::Reverse;
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|p| &p.name, Reverse(p.score); debug_keys)]
+pub struct Player {
+    pub name: String,
+    pub score: u32,
+}
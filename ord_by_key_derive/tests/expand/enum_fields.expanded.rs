@@ -0,0 +1,131 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub enum Shape {
+    Point,
+    Circle(u32),
+    Rect { width: u32, height: u32 },
+}
+#[automatically_derived]
+impl Shape {
+    fn _ord_eq_by_key_selector_variant_index(&self) -> usize {
+        match *self {
+            Self::Point { .. } => 0usize,
+            Self::Circle { .. } => 1usize,
+            Self::Rect { .. } => 2usize,
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Shape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Point {}, Self::Point {}) => {
+                return true;
+            }
+            (
+                Self::Circle { 0: _ord_eq_by_key_selector_self_0 },
+                Self::Circle { 0: _ord_eq_by_key_selector_other_0 },
+            ) => {
+                if !<u32 as ::core::cmp::PartialEq>::eq(
+                    _ord_eq_by_key_selector_self_0,
+                    _ord_eq_by_key_selector_other_0,
+                ) {
+                    return false;
+                }
+                return true;
+            }
+            (
+                Self::Rect {
+                    width: _ord_eq_by_key_selector_self_0,
+                    height: _ord_eq_by_key_selector_self_1,
+                },
+                Self::Rect {
+                    width: _ord_eq_by_key_selector_other_0,
+                    height: _ord_eq_by_key_selector_other_1,
+                },
+            ) => {
+                if !<u32 as ::core::cmp::PartialEq>::eq(
+                    _ord_eq_by_key_selector_self_0,
+                    _ord_eq_by_key_selector_other_0,
+                ) {
+                    return false;
+                }
+                if !<u32 as ::core::cmp::PartialEq>::eq(
+                    _ord_eq_by_key_selector_self_1,
+                    _ord_eq_by_key_selector_other_1,
+                ) {
+                    return false;
+                }
+                return true;
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Shape {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Shape {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        match (self, other) {
+            (Self::Point {}, Self::Point {}) => {
+                return ::core::cmp::Ordering::Equal;
+            }
+            (
+                Self::Circle { 0: _ord_eq_by_key_selector_self_0 },
+                Self::Circle { 0: _ord_eq_by_key_selector_other_0 },
+            ) => {
+                let result = <u32 as ::core::cmp::Ord>::cmp(
+                    _ord_eq_by_key_selector_self_0,
+                    _ord_eq_by_key_selector_other_0,
+                );
+                if result != ::core::cmp::Ordering::Equal {
+                    return result;
+                }
+                return ::core::cmp::Ordering::Equal;
+            }
+            (
+                Self::Rect {
+                    width: _ord_eq_by_key_selector_self_0,
+                    height: _ord_eq_by_key_selector_self_1,
+                },
+                Self::Rect {
+                    width: _ord_eq_by_key_selector_other_0,
+                    height: _ord_eq_by_key_selector_other_1,
+                },
+            ) => {
+                let result = <u32 as ::core::cmp::Ord>::cmp(
+                    _ord_eq_by_key_selector_self_0,
+                    _ord_eq_by_key_selector_other_0,
+                );
+                if result != ::core::cmp::Ordering::Equal {
+                    return result;
+                }
+                let result = <u32 as ::core::cmp::Ord>::cmp(
+                    _ord_eq_by_key_selector_self_1,
+                    _ord_eq_by_key_selector_other_1,
+                );
+                if result != ::core::cmp::Ordering::Equal {
+                    return result;
+                }
+                return ::core::cmp::Ordering::Equal;
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                ::core::cmp::Ord::cmp(
+                    &Self::_ord_eq_by_key_selector_variant_index(self),
+                    &Self::_ord_eq_by_key_selector_variant_index(other),
+                )
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Shape {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
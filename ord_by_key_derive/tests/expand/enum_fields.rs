@@ -0,0 +1,8 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(enum_fields)]
+pub enum Shape {
+    Point,
+    Circle(u32),
+    Rect { width: u32, height: u32 },
+}
@@ -0,0 +1,8 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(fields(take = 2))]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub label: String,
+}
@@ -0,0 +1,87 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Job {
+    pub priority: u32,
+    pub name: &'static str,
+    #[doc(hidden)]
+    _ord_eq_by_key_selector_seq: u64,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`j.priority`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Job_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Job_0 for T {}
+#[automatically_derived]
+impl Job {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Job_0 + '_ {
+        #[allow(unused_variables)]
+        let j = _ord_eq_by_key_selector_do_not_use;
+        j.priority
+    }
+    fn _ord_eq_by_key_selector_keys_cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+    fn _ord_eq_by_key_selector_keys_eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+    /// Creates a value which goes after all previously created values with equal keys
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(priority: u32, name: &'static str) -> Self {
+        static _ORD_EQ_BY_KEY_SELECTOR_SEQ: ::core::sync::atomic::AtomicU64 = ::core::sync::atomic::AtomicU64::new(
+            0,
+        );
+        Self {
+            priority,
+            name,
+            _ord_eq_by_key_selector_seq: _ORD_EQ_BY_KEY_SELECTOR_SEQ
+                .fetch_add(1, ::core::sync::atomic::Ordering::Relaxed),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Job {
+    fn eq(&self, other: &Self) -> bool {
+        self._ord_eq_by_key_selector_seq == other._ord_eq_by_key_selector_seq
+            && Self::_ord_eq_by_key_selector_keys_eq(self, other)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Job {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Job {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        Self::_ord_eq_by_key_selector_keys_cmp(self, other)
+            .then_with(|| {
+                self._ord_eq_by_key_selector_seq.cmp(&other._ord_eq_by_key_selector_seq)
+            })
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Job {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|j| j.priority; fifo_tiebreak)]
+pub struct Job {
+    pub priority: u32,
+    pub name: &'static str,
+}
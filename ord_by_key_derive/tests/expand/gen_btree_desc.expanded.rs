@@ -0,0 +1,189 @@
+use ord_by_key::ord_eq_by_key_selector;
+#[derive(Clone)]
+pub struct Key<V> {
+    pub name: String,
+    pub tag: V,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`&v.name`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Key_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Key_0 for T {}
+#[automatically_derived]
+impl<V> Key<V> {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Key_0 + '_ {
+        #[allow(unused_variables)]
+        let v = _ord_eq_by_key_selector_do_not_use;
+        &v.name
+    }
+}
+#[automatically_derived]
+impl<V> ::core::cmp::PartialEq for Key<V> {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl<V> ::core::cmp::Eq for Key<V> {}
+#[automatically_derived]
+impl<V> ::core::cmp::Ord for Key<V> {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl<V> ::core::cmp::PartialOrd for Key<V> {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
+///Map keyed by [`Key`] which iterates keys in descending order
+pub struct DescMap<V, V1>(
+    ::std::collections::BTreeMap<::core::cmp::Reverse<Key<V>>, V1>,
+);
+#[automatically_derived]
+impl<V, V1> DescMap<V, V1> {
+    /// Creates an empty map
+    pub fn new() -> Self {
+        DescMap(::std::collections::BTreeMap::new())
+    }
+    /// Inserts the value by the key, returns the previous value of the key
+    pub fn insert(&mut self, key: Key<V>, value: V1) -> ::core::option::Option<V1> {
+        self.0.insert(::core::cmp::Reverse(key), value)
+    }
+    /// Returns the value of the key. The key is cloned, since the map stores keys in
+    /// `Reverse`
+    pub fn get<Q>(&self, key: &Q) -> ::core::option::Option<&V1>
+    where
+        Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = Key<V>>,
+    {
+        self.0.get(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+    }
+    /// Returns mutable reference to the value of the key. The key is cloned, since the
+    /// map stores keys in `Reverse`
+    pub fn get_mut<Q>(&mut self, key: &Q) -> ::core::option::Option<&mut V1>
+    where
+        Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = Key<V>>,
+    {
+        self.0.get_mut(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+    }
+    /// Returns true if the map has the key. The key is cloned, since the map stores keys
+    /// in `Reverse`
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = Key<V>>,
+    {
+        self.0.contains_key(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+    }
+    /// Removes the key from the map, returns its value. The key is cloned, since the map
+    /// stores keys in `Reverse`
+    pub fn remove<Q>(&mut self, key: &Q) -> ::core::option::Option<V1>
+    where
+        Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = Key<V>>,
+    {
+        self.0.remove(&::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)))
+    }
+    /// Returns entries with keys in `range` from the largest key. `range` is in ascending
+    /// order of keys, e.g. `a..b` returns keys from `b` (exclusive) down to `a`
+    /// (inclusive). Bounds are cloned, since the map stores keys in `Reverse`. Panics in
+    /// the same cases as [`BTreeMap::range`](std::collections::BTreeMap::range)
+    pub fn range<R, Q>(
+        &self,
+        range: R,
+    ) -> impl ::core::iter::DoubleEndedIterator<Item = (&Key<V>, &V1)>
+    where
+        R: ::core::ops::RangeBounds<Q>,
+        Q: ?::core::marker::Sized + ::std::borrow::ToOwned<Owned = Key<V>>,
+    {
+        let reverse = |bound: ::core::ops::Bound<&Q>| match bound {
+            ::core::ops::Bound::Included(key) => {
+                ::core::ops::Bound::Included(
+                    ::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)),
+                )
+            }
+            ::core::ops::Bound::Excluded(key) => {
+                ::core::ops::Bound::Excluded(
+                    ::core::cmp::Reverse(::std::borrow::ToOwned::to_owned(key)),
+                )
+            }
+            ::core::ops::Bound::Unbounded => ::core::ops::Bound::Unbounded,
+        };
+        let range = (reverse(range.end_bound()), reverse(range.start_bound()));
+        self.0.range(range).map(|(key, value)| (&key.0, value))
+    }
+    /// Returns entries from the largest key
+    pub fn iter(&self) -> impl ::core::iter::DoubleEndedIterator<Item = (&Key<V>, &V1)> {
+        self.0.iter().map(|(key, value)| (&key.0, value))
+    }
+    /// Returns number of entries in the map
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Returns true if the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Returns the underlying map
+    pub fn as_map(
+        &self,
+    ) -> &::std::collections::BTreeMap<::core::cmp::Reverse<Key<V>>, V1> {
+        &self.0
+    }
+}
+#[automatically_derived]
+impl<V, V1> ::core::default::Default for DescMap<V, V1> {
+    fn default() -> Self {
+        DescMap::new()
+    }
+}
+#[automatically_derived]
+impl<V, V1> ::core::iter::Extend<(Key<V>, V1)> for DescMap<V, V1> {
+    fn extend<I: ::core::iter::IntoIterator<Item = (Key<V>, V1)>>(
+        &mut self,
+        entries: I,
+    ) {
+        self.0
+            .extend(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (::core::cmp::Reverse(key), value)),
+            )
+    }
+}
+#[automatically_derived]
+impl<V, V1> ::core::iter::FromIterator<(Key<V>, V1)> for DescMap<V, V1> {
+    fn from_iter<I: ::core::iter::IntoIterator<Item = (Key<V>, V1)>>(
+        entries: I,
+    ) -> Self {
+        DescMap(
+            entries
+                .into_iter()
+                .map(|(key, value)| (::core::cmp::Reverse(key), value))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,8 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|v| &v.name; gen_btree_desc = DescMap)]
+#[derive(Clone)]
+pub struct Key<V> {
+    pub name: String,
+    pub tag: V,
+}
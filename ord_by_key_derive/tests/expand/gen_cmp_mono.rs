@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|e| e.bucket, e.offset; gen_cmp_mono)]
+pub struct Event {
+    pub bucket: u32,
+    pub offset: u64,
+}
@@ -0,0 +1,68 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Priority(u8);
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`p`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Priority_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Priority_0
+for T {}
+#[automatically_derived]
+impl Priority {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Priority_0 + '_ {
+        #[allow(unused_variables)]
+        let Self(p) = _ord_eq_by_key_selector_do_not_use;
+        p
+    }
+    /// Returns the greater of two values, same as [`Ord::max`]
+    pub(crate) fn highest(self, other: Self) -> Self {
+        ::core::cmp::Ord::max(self, other)
+    }
+    /// Returns the lesser of two values, same as [`Ord::min`]
+    pub(crate) fn lowest(self, other: Self) -> Self {
+        ::core::cmp::Ord::min(self, other)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Priority {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Priority {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Priority {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Priority {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
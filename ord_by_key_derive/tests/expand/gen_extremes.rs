@@ -0,0 +1,4 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|(p)| p; gen_extremes = highest/lowest, vis = pub(crate))]
+pub struct Priority(u8);
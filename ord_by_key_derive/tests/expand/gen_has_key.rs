@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|t| t.priority, t.name.clone(); gen_has_key = (u8, String))]
+pub struct Task {
+    pub priority: u8,
+    pub name: String,
+}
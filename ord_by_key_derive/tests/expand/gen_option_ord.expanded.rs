@@ -0,0 +1,105 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Deadline(u64);
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`d`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Deadline_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Deadline_0
+for T {}
+#[automatically_derived]
+impl Deadline {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Deadline_0 + '_ {
+        #[allow(unused_variables)]
+        let Self(d) = _ord_eq_by_key_selector_do_not_use;
+        d
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Deadline {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Deadline {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Deadline {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Deadline {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
+///[`Option<Deadline>`] ordered with `None` after any value
+pub struct OptDeadline(pub ::core::option::Option<Deadline>);
+#[automatically_derived]
+impl ::core::convert::From<::core::option::Option<Deadline>> for OptDeadline {
+    fn from(value: ::core::option::Option<Deadline>) -> Self {
+        OptDeadline(value)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for OptDeadline {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for OptDeadline {}
+#[automatically_derived]
+impl ::core::cmp::Ord for OptDeadline {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        match (&self.0, &other.0) {
+            (
+                ::core::option::Option::Some(value),
+                ::core::option::Option::Some(other),
+            ) => ::core::cmp::Ord::cmp(value, other),
+            (::core::option::Option::None, ::core::option::Option::None) => {
+                ::core::cmp::Ordering::Equal
+            }
+            (::core::option::Option::None, ::core::option::Option::Some(_)) => {
+                ::core::cmp::Ordering::Greater
+            }
+            (::core::option::Option::Some(_), ::core::option::Option::None) => {
+                ::core::cmp::Ordering::Greater.reverse()
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for OptDeadline {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
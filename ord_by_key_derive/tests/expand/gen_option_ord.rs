@@ -0,0 +1,4 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|(d)| d; gen_option_ord = none_last)]
+pub struct Deadline(u64);
@@ -0,0 +1,164 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Task<T> {
+    pub priority: u32,
+    pub payload: T,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`t.priority`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Task_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Task_0 for T {}
+#[automatically_derived]
+impl<T> Task<T> {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Task_0 + '_ {
+        #[allow(unused_variables)]
+        let t = _ord_eq_by_key_selector_do_not_use;
+        t.priority
+    }
+}
+#[automatically_derived]
+impl<T> ::core::cmp::PartialEq for Task<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl<T> ::core::cmp::Eq for Task<T> {}
+#[automatically_derived]
+impl<T> ::core::cmp::Ord for Task<T> {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl<T> ::core::cmp::PartialOrd for Task<T> {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
+///Priority queue of [`Task`] which returns the smallest value first
+pub struct MinTasks<T>(::std::collections::BinaryHeap<::core::cmp::Reverse<Task<T>>>);
+#[automatically_derived]
+impl<T> MinTasks<T> {
+    /// Creates an empty queue
+    pub fn new() -> Self {
+        MinTasks(::std::collections::BinaryHeap::new())
+    }
+    /// Adds the value to the queue
+    pub fn push(&mut self, value: Task<T>) {
+        self.0.push(::core::cmp::Reverse(value))
+    }
+    ///Removes the smallest value from the queue and returns it
+    pub fn pop(&mut self) -> ::core::option::Option<Task<T>> {
+        self.0.pop().map(|value| value.0)
+    }
+    ///Returns the smallest value without removing it
+    pub fn peek(&self) -> ::core::option::Option<&Task<T>> {
+        self.0.peek().map(|value| &value.0)
+    }
+    /// Returns number of values in the queue
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Returns true if the queue has no values
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Removes all values from the queue
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+#[automatically_derived]
+impl<T> ::core::default::Default for MinTasks<T> {
+    fn default() -> Self {
+        MinTasks::new()
+    }
+}
+#[automatically_derived]
+impl<T> ::core::iter::Extend<Task<T>> for MinTasks<T> {
+    fn extend<I: ::core::iter::IntoIterator<Item = Task<T>>>(&mut self, values: I) {
+        self.0.extend(values.into_iter().map(|value| ::core::cmp::Reverse(value)))
+    }
+}
+#[automatically_derived]
+impl<T> ::core::iter::FromIterator<Task<T>> for MinTasks<T> {
+    fn from_iter<I: ::core::iter::IntoIterator<Item = Task<T>>>(values: I) -> Self {
+        MinTasks(values.into_iter().map(|value| ::core::cmp::Reverse(value)).collect())
+    }
+}
+///Priority queue of [`Task`] which returns the largest value first
+pub struct MaxTasks<T>(::std::collections::BinaryHeap<Task<T>>);
+#[automatically_derived]
+impl<T> MaxTasks<T> {
+    /// Creates an empty queue
+    pub fn new() -> Self {
+        MaxTasks(::std::collections::BinaryHeap::new())
+    }
+    /// Adds the value to the queue
+    pub fn push(&mut self, value: Task<T>) {
+        self.0.push(value)
+    }
+    ///Removes the largest value from the queue and returns it
+    pub fn pop(&mut self) -> ::core::option::Option<Task<T>> {
+        self.0.pop().map(|value| value)
+    }
+    ///Returns the largest value without removing it
+    pub fn peek(&self) -> ::core::option::Option<&Task<T>> {
+        self.0.peek().map(|value| value)
+    }
+    /// Returns number of values in the queue
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Returns true if the queue has no values
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Removes all values from the queue
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+#[automatically_derived]
+impl<T> ::core::default::Default for MaxTasks<T> {
+    fn default() -> Self {
+        MaxTasks::new()
+    }
+}
+#[automatically_derived]
+impl<T> ::core::iter::Extend<Task<T>> for MaxTasks<T> {
+    fn extend<I: ::core::iter::IntoIterator<Item = Task<T>>>(&mut self, values: I) {
+        self.0.extend(values.into_iter().map(|value| value))
+    }
+}
+#[automatically_derived]
+impl<T> ::core::iter::FromIterator<Task<T>> for MaxTasks<T> {
+    fn from_iter<I: ::core::iter::IntoIterator<Item = Task<T>>>(values: I) -> Self {
+        MaxTasks(values.into_iter().map(|value| value).collect())
+    }
+}
@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|t| t.priority; gen_min_queue = MinTasks, gen_max_queue = MaxTasks)]
+pub struct Task<T> {
+    pub priority: u32,
+    pub payload: T,
+}
@@ -0,0 +1,119 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct File {
+    pub path: String,
+    pub size: u64,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`f.path.to_lowercase()`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_File_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_File_0 for T {}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`f.size`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_File_1: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_File_1 for T {}
+#[automatically_derived]
+impl File {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_File_0 + '_ {
+        #[allow(unused_variables)]
+        let f = _ord_eq_by_key_selector_do_not_use;
+        f.path.to_lowercase()
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_File_1 + '_ {
+        #[allow(unused_variables)]
+        let f = _ord_eq_by_key_selector_do_not_use;
+        f.size
+    }
+    /// Collects `values` into a `Vec` sorted by [`Ord`] of the type, values which are equal
+    /// keep their order
+    pub fn sorted<I>(values: I) -> ::std::vec::Vec<Self>
+    where
+        I: ::core::iter::IntoIterator<Item = Self>,
+    {
+        let mut values: ::std::vec::Vec<Self> = values.into_iter().collect();
+        values.sort();
+        values
+    }
+    /// Collects `values` into a `Vec` sorted by keys, which are computed once per value with
+    /// `sort_by_cached_key` instead of on every comparison. Values with equal keys keep
+    /// their order
+    pub fn sorted_by_cached_key<I>(values: I) -> ::std::vec::Vec<Self>
+    where
+        I: ::core::iter::IntoIterator<Item = Self>,
+    {
+        let mut values: ::std::vec::Vec<Self> = values.into_iter().collect();
+        values
+            .sort_by_cached_key(|_ord_eq_by_key_selector_value| {
+                #[allow(unused_variables)]
+                let f = _ord_eq_by_key_selector_value;
+                ({ f.path.to_lowercase() }, { f.size })
+            });
+        values
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for File {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for File {}
+#[automatically_derived]
+impl ::core::cmp::Ord for File {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for File {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|f| f.path.to_lowercase(), f.size; gen_sorted)]
+pub struct File {
+    pub path: String,
+    pub size: u64,
+}
@@ -0,0 +1,10 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|e| e.name, &e.value)]
+pub struct Named<'a, T>
+where
+    T: Ord,
+{
+    pub name: &'a str,
+    pub value: T,
+}
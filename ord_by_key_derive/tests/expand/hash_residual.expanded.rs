@@ -0,0 +1,110 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Issue {
+    pub priority: u32,
+    pub labels: Vec<&'static str>,
+    pub author: &'static str,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`i.priority`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Issue_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Issue_0
+for T {}
+#[automatically_derived]
+impl Issue {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Issue_0 + '_ {
+        #[allow(unused_variables)]
+        let i = _ord_eq_by_key_selector_do_not_use;
+        i.priority
+    }
+    fn _ord_eq_by_key_selector_keys_cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+    fn _ord_eq_by_key_selector_keys_eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Issue {
+    fn eq(&self, other: &Self) -> bool {
+        Self::_ord_eq_by_key_selector_keys_eq(self, other)
+            && {
+                fn _ord_eq_by_key_selector_hashed<
+                    T: ::core::hash::Hash + ?::core::marker::Sized,
+                >(value: &T) -> &T {
+                    value
+                }
+                ::ord_by_key::StableHash(
+                    &(
+                        _ord_eq_by_key_selector_hashed(&self.labels),
+                        (_ord_eq_by_key_selector_hashed(&self.author), ()),
+                    ),
+                )
+                    == ::ord_by_key::StableHash(
+                        &(
+                            _ord_eq_by_key_selector_hashed(&other.labels),
+                            (_ord_eq_by_key_selector_hashed(&other.author), ()),
+                        ),
+                    )
+            }
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Issue {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Issue {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        Self::_ord_eq_by_key_selector_keys_cmp(self, other)
+            .then_with(|| {
+                fn _ord_eq_by_key_selector_hashed<
+                    T: ::core::hash::Hash + ?::core::marker::Sized,
+                >(value: &T) -> &T {
+                    value
+                }
+                ::ord_by_key::StableHash(
+                        &(
+                            _ord_eq_by_key_selector_hashed(&self.labels),
+                            (_ord_eq_by_key_selector_hashed(&self.author), ()),
+                        ),
+                    )
+                    .cmp(
+                        &::ord_by_key::StableHash(
+                            &(
+                                _ord_eq_by_key_selector_hashed(&other.labels),
+                                (_ord_eq_by_key_selector_hashed(&other.author), ()),
+                            ),
+                        ),
+                    )
+            })
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Issue {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,8 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|i| i.priority; hash_residual = [labels, author])]
+pub struct Issue {
+    pub priority: u32,
+    pub labels: Vec<&'static str>,
+    pub author: &'static str,
+}
@@ -0,0 +1,60 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Keyed<K: Ord, V>(pub K, pub V);
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`k`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Keyed_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Keyed_0
+for T {}
+#[automatically_derived]
+impl<K: Ord, V> Keyed<K, V> {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Keyed_0 + '_ {
+        #[allow(unused_variables)]
+        let Self(k, v) = _ord_eq_by_key_selector_do_not_use;
+        k
+    }
+}
+#[automatically_derived]
+impl<K: Ord, V> ::core::cmp::PartialEq for Keyed<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl<K: Ord, V> ::core::cmp::Eq for Keyed<K, V> {}
+#[automatically_derived]
+impl<K: Ord, V> ::core::cmp::Ord for Keyed<K, V> {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl<K: Ord, V> ::core::cmp::PartialOrd for Keyed<K, V> {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,4 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|(k, v)| k)]
+pub struct Keyed<K: Ord, V>(pub K, pub V);
@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(methods = [priority, title])]
+pub struct Task {
+    priority: u8,
+    title: String,
+}
@@ -0,0 +1,9 @@
+use core::cmp::Reverse;
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|p| &p.last_name, &p.first_name, Reverse(p.age))]
+pub struct Person {
+    pub first_name: String,
+    pub last_name: String,
+    pub age: u32,
+}
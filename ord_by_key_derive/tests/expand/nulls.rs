@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|r| r.score nulls = last, &r.name nulls = first)]
+pub struct Row {
+    pub score: Option<u32>,
+    pub name: Option<String>,
+}
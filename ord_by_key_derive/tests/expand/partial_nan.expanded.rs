@@ -0,0 +1,85 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Sample {
+    pub value: f64,
+    pub weight: f32,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`s.value`) is not `PartialOrd`",
+    label = "`{Self}` does not implement `PartialOrd`",
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Sample_0: ::core::cmp::PartialOrd {}
+#[automatically_derived]
+impl<
+    T: ::core::cmp::PartialOrd + ?::core::marker::Sized,
+> _ord_eq_by_key_selector_Sample_0 for T {}
+#[diagnostic::on_unimplemented(
+    message = "key #2 (`s.weight`) is not `PartialOrd`",
+    label = "`{Self}` does not implement `PartialOrd`",
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Sample_1: ::core::cmp::PartialOrd {}
+#[automatically_derived]
+impl<
+    T: ::core::cmp::PartialOrd + ?::core::marker::Sized,
+> _ord_eq_by_key_selector_Sample_1 for T {}
+#[automatically_derived]
+impl Sample {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Sample_0 + '_ {
+        #[allow(unused_variables)]
+        let s = _ord_eq_by_key_selector_do_not_use;
+        s.value
+    }
+    fn _ord_eq_by_key_selector_1(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Sample_1 + '_ {
+        #[allow(unused_variables)]
+        let s = _ord_eq_by_key_selector_do_not_use;
+        s.weight
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Sample {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = ::ord_by_key::nan_equal_cmp(&key_self, &key_other)
+            == ::core::option::Option::Some(::core::cmp::Ordering::Equal);
+        if result != true {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = ::ord_by_key::nan_equal_cmp(&key_self, &key_other)
+            == ::core::option::Option::Some(::core::cmp::Ordering::Equal);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Sample {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = ::ord_by_key::nan_equal_cmp(&key_self, &key_other);
+        if result != ::core::option::Option::Some(::core::cmp::Ordering::Equal) {
+            return result;
+        }
+        let key_self = Self::_ord_eq_by_key_selector_1(self);
+        let key_other = Self::_ord_eq_by_key_selector_1(other);
+        let result = ::ord_by_key::nan_equal_cmp(&key_self, &key_other);
+        if result != ::core::option::Option::Some(::core::cmp::Ordering::Equal) {
+            return result;
+        }
+        return ::core::option::Option::Some(::core::cmp::Ordering::Equal);
+    }
+}
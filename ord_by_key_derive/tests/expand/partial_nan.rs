@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|s| s.value, s.weight; partial_only(nan = equal))]
+pub struct Sample {
+    pub value: f64,
+    pub weight: f32,
+}
@@ -0,0 +1,52 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Measurement {
+    pub value: f64,
+}
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`m.value`) is not `PartialOrd`",
+    label = "`{Self}` does not implement `PartialOrd`",
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Measurement_0: ::core::cmp::PartialOrd {}
+#[automatically_derived]
+impl<
+    T: ::core::cmp::PartialOrd + ?::core::marker::Sized,
+> _ord_eq_by_key_selector_Measurement_0 for T {}
+#[automatically_derived]
+impl Measurement {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Measurement_0 + '_ {
+        #[allow(unused_variables)]
+        let m = _ord_eq_by_key_selector_do_not_use;
+        m.value
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Measurement {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Measurement {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.partial_cmp(&key_other);
+        if result != ::core::option::Option::Some(::core::cmp::Ordering::Equal) {
+            return result;
+        }
+        return ::core::option::Option::Some(::core::cmp::Ordering::Equal);
+    }
+}
@@ -0,0 +1,6 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|m| m.value; partial_only)]
+pub struct Measurement {
+    pub value: f64,
+}
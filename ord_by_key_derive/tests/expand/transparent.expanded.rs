@@ -0,0 +1,28 @@
+use ord_by_key::ord_eq_by_key_selector;
+#[repr(transparent)]
+pub struct Id(u64);
+#[automatically_derived]
+impl Id {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Id {
+    fn eq(&self, other: &Self) -> bool {
+        <u64 as ::core::cmp::PartialEq>::eq(&self.0, &other.0)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Id {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Id {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        <u64 as ::core::cmp::Ord>::cmp(&self.0, &other.0)
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Id {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,5 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(transparent)]
+#[repr(transparent)]
+pub struct Id(u64);
@@ -0,0 +1,4 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|(name, _count)| name.len(), name)]
+pub struct Entry(String, u32);
@@ -0,0 +1,7 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|p| (p.rank, &p.name))]
+pub struct Player {
+    pub rank: u32,
+    pub name: String,
+}
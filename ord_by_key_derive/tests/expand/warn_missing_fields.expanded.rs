@@ -0,0 +1,72 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Employee {
+    pub name: String,
+    pub id: u32,
+    pub created_at: u64,
+}
+const _: () = {
+    #[deprecated(
+        note = "field `id` is not used by any key of `ord_eq_by_key_selector`, use it in a key or add it to `warn_missing_fields(skip(...))`"
+    )]
+    #[allow(non_upper_case_globals)]
+    const id: () = ();
+    id
+};
+#[diagnostic::on_unimplemented(
+    message = "key #1 (`&e.name`) is not `Ord`",
+    label = "`{Self}` does not implement `Ord`",
+    note = "if the key contains floats (alone or in a tuple), they are only `PartialOrd`: \
+                    wrap them into `ord_by_key::TotalF64`, or add `partial_only` option to \
+                    implement only `PartialOrd`"
+)]
+#[allow(non_camel_case_types)]
+#[doc(hidden)]
+trait _ord_eq_by_key_selector_Employee_0: ::core::cmp::Ord {}
+#[automatically_derived]
+impl<T: ::core::cmp::Ord + ?::core::marker::Sized> _ord_eq_by_key_selector_Employee_0
+for T {}
+#[automatically_derived]
+impl Employee {
+    fn _ord_eq_by_key_selector_0(
+        _ord_eq_by_key_selector_do_not_use: &Self,
+    ) -> impl _ord_eq_by_key_selector_Employee_0 + '_ {
+        #[allow(unused_variables)]
+        let e = _ord_eq_by_key_selector_do_not_use;
+        &e.name
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Employee {
+    fn eq(&self, other: &Self) -> bool {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.eq(&key_other);
+        if result != true {
+            return result;
+        }
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Employee {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Employee {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        let key_self = Self::_ord_eq_by_key_selector_0(self);
+        let key_other = Self::_ord_eq_by_key_selector_0(other);
+        let result = key_self.cmp(&key_other);
+        if result != ::core::cmp::Ordering::Equal {
+            return result;
+        }
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Employee {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,8 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|e| &e.name; warn_missing_fields(skip(created_at)))]
+pub struct Employee {
+    pub name: String,
+    pub id: u32,
+    pub created_at: u64,
+}
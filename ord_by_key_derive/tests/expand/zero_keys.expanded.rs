@@ -0,0 +1,27 @@
+use ord_by_key::ord_eq_by_key_selector;
+pub struct Marker();
+#[automatically_derived]
+impl Marker {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Marker {
+    fn eq(&self, other: &Self) -> bool {
+        return true;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Marker {}
+#[automatically_derived]
+impl ::core::cmp::Ord for Marker {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        return ::core::cmp::Ordering::Equal;
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialOrd for Marker {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,60 @@
+use core::cmp::Ordering;
+
+/// Key which orders strings by a custom alphabet
+///
+/// Strings are compared character by character, by positions of characters in the alphabet.
+/// Characters which are not in the alphabet go after all characters of the alphabet, and are
+/// ordered by their Unicode scalar values. Position of a character is found by linear search, so
+/// the alphabet is meant to be small, e.g. ranks of playing cards. Alphabet should be the same
+/// for all compared values, and is usually a `static` array.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// static RANKS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+///
+/// #[ord_eq_by_key_selector(|h| ord_by_key::WithAlphabet(&h.cards, &RANKS))]
+/// pub struct Hand {
+///     pub cards: String,
+/// }
+///
+/// let hand = |cards: &str| Hand { cards: cards.to_string() };
+///
+/// assert!(hand("K") < hand("A"));
+/// assert!(hand("9") < hand("T"));
+/// assert!(hand("AK") < hand("AA"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WithAlphabet<'a>(pub &'a str, pub &'static [char]);
+
+impl<'a> WithAlphabet<'a> {
+    /// Characters of the alphabet go first, ordered by position, then others by value
+    fn weights(&self) -> impl Iterator<Item = (bool, u32)> + '_ {
+        self.0
+            .chars()
+            .map(move |c| match self.1.iter().position(|&a| a == c) {
+                Some(position) => (false, position as u32),
+                None => (true, c as u32),
+            })
+    }
+}
+
+impl<'a> Ord for WithAlphabet<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weights().cmp(other.weights())
+    }
+}
+
+impl<'a> PartialOrd for WithAlphabet<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for WithAlphabet<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for WithAlphabet<'a> {}
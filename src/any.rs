@@ -0,0 +1,118 @@
+use core::any::Any;
+use core::cmp::Ordering;
+use core::fmt;
+
+/// Object-safe comparison of values of any `Ord` type
+trait DynOrd {
+    fn as_any(&self) -> &dyn Any;
+    fn cmp_dyn(&self, other: &dyn Any) -> Ordering;
+}
+
+impl<T: Ord + Any> DynOrd for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn cmp_dyn(&self, other: &dyn Any) -> Ordering {
+        match other.downcast_ref::<T>() {
+            Some(other) => self.cmp(other),
+            None => unreachable!("types are compared first"),
+        }
+    }
+}
+
+/// Key which borrows a value of any [`Ord`] type, so keys of different types can be compared
+///
+/// Values of the same type are compared with their own [`Ord`]. Values of different types are
+/// ordered by their [`TypeId`](core::any::TypeId), which is a consistent total order, but an
+/// arbitrary one, which can change between builds of the program. So `AnyOrd` is meant to go
+/// after a key which already separates types, normally a rank of enum variant, see [`tagged`].
+///
+/// Value has to be `'static` (i.e. not contain borrows), since types are compared with
+/// [`Any`], but `AnyOrd` itself only borrows it.
+///
+/// ```
+/// use ord_by_key::AnyOrd;
+///
+/// assert!(AnyOrd::new(&1u64) < AnyOrd::new(&2u64));
+/// assert!(AnyOrd::new(&"a") == AnyOrd::new(&"a"));
+/// assert!(AnyOrd::new(&1u64) != AnyOrd::new(&1u32));
+/// ```
+#[derive(Clone, Copy)]
+pub struct AnyOrd<'a>(&'a dyn DynOrd);
+
+impl<'a> AnyOrd<'a> {
+    /// Creates key which compares `value`
+    pub fn new<T: Ord + Any>(value: &'a T) -> Self {
+        AnyOrd(value)
+    }
+}
+
+impl<'a> fmt::Debug for AnyOrd<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnyOrd").finish_non_exhaustive()
+    }
+}
+
+impl<'a> Ord for AnyOrd<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.0.as_any(), other.0.as_any());
+
+        a.type_id()
+            .cmp(&b.type_id())
+            .then_with(|| self.0.cmp_dyn(b))
+    }
+}
+
+impl<'a> PartialOrd for AnyOrd<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for AnyOrd<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for AnyOrd<'a> {}
+
+/// Returns key which orders by `rank` first, and then by `key`, whose type can differ between
+/// ranks
+///
+/// This is a recipe for enums whose variants carry payloads of different types, e.g.
+/// `Numeric(u64)` and `Text(String)`: every variant returns its rank and its payload from a
+/// `match`, and all arms produce the same key type `(R, AnyOrd)`. Values of different ranks are
+/// ordered by rank, and their payloads are never compared. Values of the same rank are ordered
+/// by the payload, which should have the same type for the same rank (otherwise they're ordered
+/// by an arbitrary but consistent order of types, see [`AnyOrd`]).
+///
+/// Unlike `enum_fields` mode of the macro, which orders variants as declared and compares all
+/// fields, ranks and payloads are arbitrary expressions: several variants can share a rank, and
+/// payload can be any part of the variant. Payloads are borrowed from the value (or are
+/// constants, like `&()` for variants without payload), and have to be of `'static` types, like
+/// `u64` or `String`.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|v| match v {
+///     Value::Numeric(n) => ord_by_key::tagged(0, n),
+///     Value::Text(s) => ord_by_key::tagged(1, s),
+///     Value::Missing => ord_by_key::tagged(2, &()),
+/// })]
+/// pub enum Value {
+///     Text(String),
+///     Numeric(u64),
+///     Missing,
+/// }
+///
+/// assert!(Value::Numeric(100) < Value::Text("a".to_string()));
+/// assert!(Value::Numeric(2) < Value::Numeric(10));
+/// assert!(Value::Text("a".to_string()) < Value::Text("b".to_string()));
+/// assert!(Value::Text("z".to_string()) < Value::Missing);
+/// ```
+pub fn tagged<'a, R: Ord, T: Ord + Any>(rank: R, key: &'a T) -> (R, AnyOrd<'a>) {
+    (rank, AnyOrd::new(key))
+}
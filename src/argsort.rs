@@ -0,0 +1,51 @@
+use std::vec::Vec;
+
+/// Returns indices which would sort `values` by [`Ord`], e.g. to reorder parallel arrays
+///
+/// `values[indices[0]]` is the smallest value, `values[indices[1]]` is the next one, and so on.
+/// Sorting is stable: indices of equal values stay in ascending order. `values` are not moved,
+/// so the same permutation can be applied to any number of slices of the same length. Requires
+/// `std` feature.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|s| s.points)]
+/// pub struct Score {
+///     pub points: u32,
+/// }
+///
+/// let scores = [Score { points: 30 }, Score { points: 10 }, Score { points: 20 }];
+/// let names = ["carol", "alice", "bob"];
+///
+/// let order = ord_by_key::argsort(&scores);
+/// assert_eq!(order, [1, 2, 0]);
+///
+/// let names: Vec<_> = order.iter().map(|&i| names[i]).collect();
+/// assert_eq!(names, ["alice", "bob", "carol"]);
+/// ```
+pub fn argsort<T: Ord>(values: &[T]) -> Vec<usize> {
+    let mut indices: Vec<_> = (0..values.len()).collect();
+    indices.sort_by(|&a, &b| values[a].cmp(&values[b]));
+    indices
+}
+
+/// Returns indices which would sort `values` by `key`, same as [`argsort`] for values which are
+/// not [`Ord`] themselves
+///
+/// Sorting is stable. The key is computed on every comparison, the same way as by
+/// `sort_by_key`.
+///
+/// ```
+/// let lengths = ord_by_key::argsort_by_key(&["ccc", "a", "bb", "d"], |s| s.len());
+/// assert_eq!(lengths, [1, 3, 2, 0]);
+/// ```
+pub fn argsort_by_key<T, K, F>(values: &[T], mut key: F) -> Vec<usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut indices: Vec<_> = (0..values.len()).collect();
+    indices.sort_by_key(|&i| key(&values[i]));
+    indices
+}
@@ -0,0 +1,60 @@
+use core::cmp::Ordering;
+
+/// Key returned by [`assume_total`]
+///
+/// Implements [`Ord`] by [`PartialOrd::partial_cmp`] of the underlying value, and panics if the
+/// values are incomparable.
+#[derive(Clone, Copy, Debug)]
+pub struct AssumeTotal<T>(T);
+
+/// Creates a key which orders a [`PartialOrd`] value as if its order was total
+///
+/// Useful for foreign types which implement only [`PartialOrd`], but are known to be totally
+/// ordered in the domain of the application, e.g. a measurement type which never holds NaN.
+///
+/// # Panics
+/// Comparison (including `==`) panics when [`PartialOrd::partial_cmp`] returns `None`. Since
+/// sorting and ordered collections compare values at arbitrary moments, a single incomparable
+/// value can panic far from the place where it was created, so only use this helper if
+/// incomparable values are guaranteed not to occur. For [`f64`], prefer `TotalF64` or
+/// `NumericF64`, which define an order for NaN instead of panicking.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Foreign type with derived `PartialOrd` only
+/// #[derive(Clone, Copy, PartialEq, PartialOrd)]
+/// pub struct Celsius(pub f32);
+///
+/// #[ord_eq_by_key_selector(|r| ord_by_key::assume_total(r.temperature))]
+/// pub struct Reading {
+///     pub temperature: Celsius,
+/// }
+///
+/// assert!(Reading { temperature: Celsius(-5.0) } < Reading { temperature: Celsius(20.0) });
+/// ```
+pub fn assume_total<T: PartialOrd>(value: T) -> AssumeTotal<T> {
+    AssumeTotal(value)
+}
+
+impl<T: PartialOrd> Ord for AssumeTotal<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .expect("incomparable values in `assume_total` key")
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for AssumeTotal<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> PartialEq for AssumeTotal<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for AssumeTotal<T> {}
@@ -0,0 +1,72 @@
+/// Unsigned integer which bit fields can be extracted from, implemented for all primitive
+/// unsigned integer types
+///
+/// Used by [`bit_field`], which makes extraction available in key expressions regardless of the
+/// integer type.
+pub trait BitFieldInt: Ord + Copy {
+    /// Number of bits in the type, same as `BITS` of the primitive type
+    const BITS: u32;
+
+    /// Returns bits `[lo, hi]` of `self` shifted down to bit `0`, callers check the bounds
+    fn extract(self, lo: u32, hi: u32) -> Self;
+}
+
+macro_rules! impl_bit_field_int {
+    ($($ty:ty),*) => {
+        $(
+            impl BitFieldInt for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                fn extract(self, lo: u32, hi: u32) -> Self {
+                    // Shifting by the full width overflows, so the mask is built by shifting
+                    // down from all ones instead of shifting up from `1`
+                    let mask = <$ty>::MAX >> (Self::BITS - 1 - (hi - lo));
+                    (self >> lo) & mask
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_field_int!(u8, u16, u32, u64, u128, usize);
+
+/// Returns bits `lo` through `hi` of `value`, both inclusive, shifted down to bit `0`, to be used
+/// as a key
+///
+/// Orders by a sub-field of flags packed into an integer, e.g. bits `4..=7` of a `u32`. The
+/// result has the same type as `value`, and compares the same way as the sub-field would if it
+/// was stored as a separate unsigned integer. Extracting bits `0` through `BITS - 1` returns
+/// `value` as is.
+///
+/// # Panics
+///
+/// Panics if `lo > hi` or if `hi` is not less than the number of bits of the type. Bounds are
+/// checked on every call, including in release builds.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Bits 0-3 are priority, bits 4-7 are category: ordered by category, then by priority
+/// #[ord_eq_by_key_selector(|f|
+///     ord_by_key::bit_field(f.packed, 4, 7),
+///     ord_by_key::bit_field(f.packed, 0, 3))]
+/// pub struct Flags {
+///     pub packed: u32,
+/// }
+///
+/// assert!(Flags { packed: 0x1f } < Flags { packed: 0x20 });
+/// assert!(Flags { packed: 0x21 } < Flags { packed: 0x22 });
+/// // Bits above the fields don't participate
+/// assert!(Flags { packed: 0x100 } == Flags { packed: 0x000 });
+/// ```
+pub fn bit_field<T: BitFieldInt>(value: T, lo: u32, hi: u32) -> T {
+    assert!(
+        lo <= hi && hi < T::BITS,
+        "bit field [{}, {}] is out of range of {}-bit integer",
+        lo,
+        hi,
+        T::BITS
+    );
+
+    value.extract(lo, hi)
+}
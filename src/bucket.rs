@@ -0,0 +1,36 @@
+/// Returns index of the band of `value`, to be used as a key which groups values into bands
+///
+/// `edges` are sorted lower bounds of bands after the first one: values less than `edges[0]`
+/// are in band `0`, values from `edges[i - 1]` (inclusive) to `edges[i]` (exclusive) are in
+/// band `i`, and values equal to or greater than the last edge are in band `edges.len()`. So
+/// edges `[100, 300, 1000]` make four bands: `..100`, `100..300`, `300..1000` and `1000..`.
+/// Empty `edges` put all values into band `0`.
+///
+/// The band is found with a binary search, so it costs `O(log n)` comparisons. `edges` have to
+/// be sorted ascending (equal edges make empty bands), which is checked only if
+/// `debug_assertions` are enabled. Edges can be of any [`PartialOrd`] type, e.g. floats, a value
+/// which can't be compared with edges (NaN) is in band `0`. Values in the same band are equal,
+/// add the value itself as the next key to order values within a band.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Metrics are grouped by latency band, then ordered by raw latency within a band
+/// #[ord_eq_by_key_selector(|m| ord_by_key::bucket(m.latency_ms, &[100, 300, 1000]), m.latency_ms)]
+/// pub struct Metric {
+///     pub latency_ms: u32,
+/// }
+///
+/// assert_eq!(ord_by_key::bucket(99, &[100, 300, 1000]), 0);
+/// assert_eq!(ord_by_key::bucket(100, &[100, 300, 1000]), 1);
+/// assert_eq!(ord_by_key::bucket(5000, &[100, 300, 1000]), 3);
+/// assert!(Metric { latency_ms: 250 } < Metric { latency_ms: 300 });
+/// ```
+pub fn bucket<T: PartialOrd>(value: T, edges: &[T]) -> usize {
+    debug_assert!(
+        edges.windows(2).all(|pair| pair[0] <= pair[1]),
+        "edges of buckets have to be sorted"
+    );
+
+    edges.partition_point(|edge| *edge <= value)
+}
@@ -0,0 +1,49 @@
+use core::cmp::Ordering;
+
+/// Key which orders byte strings lexicographically
+///
+/// Bytes are compared one by one, and if one byte string is a prefix of the other, the shorter
+/// one is less. This is the same order as of `[u8]`, `Vec<u8>` and `[u8; N]`, the wrapper gives
+/// one key type for all of them: arrays, vectors and slices are coerced to `&[u8]`, so values
+/// of different containers (or arrays of different lengths) can be compared with each other.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|id| ord_by_key::Bytes(&id.hash))]
+/// pub struct Id {
+///     pub hash: [u8; 32],
+/// }
+///
+/// assert!(Id { hash: [1; 32] } < Id { hash: [2; 32] });
+/// assert!(ord_by_key::Bytes(&[1, 2]) < ord_by_key::Bytes(&vec![1, 2, 0]));
+/// ```
+///
+/// Comparison is delegated to [`Ord`] of `[u8]`, which compares with `memcmp`, the same as
+/// using a `&[u8]` key directly.
+///
+/// Comparison stops at the first byte which differs, so it takes time which depends on the
+/// contents. It's meant for deterministic ordering of identifiers (e.g. hashes or public keys),
+/// not for comparing secrets, which requires constant-time equality.
+#[derive(Clone, Copy, Debug)]
+pub struct Bytes<'a>(pub &'a [u8]);
+
+impl<'a> Ord for Bytes<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(other.0)
+    }
+}
+
+impl<'a> PartialOrd for Bytes<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for Bytes<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Bytes<'a> {}
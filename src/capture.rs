@@ -0,0 +1,95 @@
+use crate::NumericStr;
+use core::cmp::Ordering;
+use regex::Regex;
+
+/// Comparator builder which orders strings by a capture group of a regular expression
+///
+/// Compiling a regular expression is much more expensive than matching it, so it can't be done
+/// in a key expression, which is evaluated on every comparison. `ByCapture` compiles the
+/// expression once and produces a comparator for `sort_by`, `binary_search_by` and similar
+/// methods. Captured text is compared either as a number ([`ByCapture::numeric`]) or as a string
+/// ([`ByCapture::lexical`]). Strings which don't match (or where the group didn't participate
+/// in the match) go before all others.
+///
+/// ```
+/// use ord_by_key::ByCapture;
+///
+/// let mut lines = vec!["id=10 stop", "id=9 start", "no id", "id=100 exit"];
+/// lines.sort_by(ByCapture::new(r"id=(\d+)", 1).numeric());
+///
+/// assert_eq!(lines, ["no id", "id=9 start", "id=10 stop", "id=100 exit"]);
+/// ```
+///
+/// Comparator still matches the expression against both strings on every comparison. For large
+/// inputs it may be faster to extract captures once with [`ByCapture::capture`] and
+/// `sort_by_cached_key`.
+///
+/// Requires `regex` feature.
+#[derive(Clone, Debug)]
+pub struct ByCapture {
+    regex: Regex,
+    group: usize,
+}
+
+impl ByCapture {
+    /// Compiles `pattern` and creates comparator builder using capture group with index `group`
+    /// (0 is the whole match)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regular expression or doesn't have group `group`. Use
+    /// [`ByCapture::from_regex`] for patterns which are not known in advance.
+    pub fn new(pattern: &str, group: usize) -> Self {
+        match Regex::new(pattern) {
+            Ok(regex) => Self::from_regex(regex, group),
+            Err(err) => panic!("invalid regular expression: {}", err),
+        }
+    }
+
+    /// Creates comparator builder using already compiled `regex` and capture group with index
+    /// `group` (0 is the whole match)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `regex` doesn't have group `group`.
+    pub fn from_regex(regex: Regex, group: usize) -> Self {
+        assert!(
+            group < regex.captures_len(),
+            "regular expression `{}` doesn't have capture group {}",
+            regex.as_str(),
+            group
+        );
+
+        ByCapture { regex, group }
+    }
+
+    /// Returns text of the capture group in `text`, or `None` if the expression doesn't match or
+    /// the group didn't participate in the match
+    pub fn capture<'t>(&self, text: &'t str) -> Option<&'t str> {
+        if self.group == 0 {
+            return self.regex.find(text).map(|m| m.as_str());
+        }
+
+        self.regex
+            .captures(text)?
+            .get(self.group)
+            .map(|m| m.as_str())
+    }
+
+    /// Creates comparator which compares captured text as [`NumericStr`], i.e. as non-negative
+    /// integers of any length, e.g. `9 < 10` and `007 == 7`. Captures which are not sequences of
+    /// ASCII digits go after all numbers, ordered as strings
+    pub fn numeric<T: AsRef<str> + ?Sized>(self) -> impl Fn(&T, &T) -> Ordering {
+        move |a, b| {
+            let a = self.capture(a.as_ref()).map(NumericStr);
+            let b = self.capture(b.as_ref()).map(NumericStr);
+
+            a.cmp(&b)
+        }
+    }
+
+    /// Creates comparator which compares captured text as strings, e.g. `10 < 9`
+    pub fn lexical<T: AsRef<str> + ?Sized>(self) -> impl Fn(&T, &T) -> Ordering {
+        move |a, b| self.capture(a.as_ref()).cmp(&self.capture(b.as_ref()))
+    }
+}
@@ -0,0 +1,57 @@
+use core::cmp::Ordering;
+
+/// Key which orders strings case-insensitively
+///
+/// Strings are compared by [`char::to_lowercase`] of their characters, lazily and without
+/// allocation, so `"apple" < "Banana"` and `"Hello" == "hELLO"`. This is lowercasing, not full
+/// Unicode case folding: e.g. `"Straße"` and `"STRASSE"` are different. Strings which differ
+/// only in case are equal, add the string itself as the next key if they have to be ordered
+/// deterministically.
+///
+/// The wrapper is generic over anything which is [`AsRef<str>`], so it nests into other key
+/// wrappers by value. To combine it with placement of `None`, map the inner value of the
+/// [`Option`] into the wrapper, so [`NoneFirst`](crate::NoneFirst) and
+/// [`NoneLast`](crate::NoneLast) order the wrappers:
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::{CaseInsensitive, NoneLast};
+///
+/// #[ord_eq_by_key_selector(|c| NoneLast(c.nickname.as_deref().map(CaseInsensitive)))]
+/// pub struct Contact {
+///     pub nickname: Option<String>,
+/// }
+///
+/// let contact = |nickname: Option<&str>| Contact {
+///     nickname: nickname.map(str::to_string),
+/// };
+///
+/// assert!(contact(Some("alice")) < contact(Some("Bob")));
+/// assert!(contact(Some("Zed")) < contact(None));
+/// assert!(contact(Some("BOB")) == contact(Some("bob")));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CaseInsensitive<S>(pub S);
+
+impl<S: AsRef<str>> Ord for CaseInsensitive<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let a = self.0.as_ref().chars().flat_map(char::to_lowercase);
+        let b = other.0.as_ref().chars().flat_map(char::to_lowercase);
+
+        a.cmp(b)
+    }
+}
+
+impl<S: AsRef<str>> PartialOrd for CaseInsensitive<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str>> PartialEq for CaseInsensitive<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: AsRef<str>> Eq for CaseInsensitive<S> {}
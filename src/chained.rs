@@ -0,0 +1,116 @@
+use core::cmp::Ordering;
+use core::iter;
+
+/// Key returned by [`chained`]
+///
+/// Compares logical concatenation of parts with a space between them, without allocating the
+/// concatenated string.
+#[derive(Clone, Copy, Debug)]
+pub struct Chained<A, B>(A, B);
+
+/// String-like part of [`chained`] key
+///
+/// Implemented for everything which implements [`AsRef<str>`] and for [`Chained`] itself, so
+/// more than two parts can be chained with nested calls.
+pub trait ChainPart {
+    /// Returns UTF-8 bytes of the part, comparing bytes gives the same result as comparing
+    /// [`char`]s
+    fn part_bytes(&self) -> impl Iterator<Item = u8> + '_;
+}
+
+/// Creates a key which orders values as if they were concatenated into a single string with a
+/// space between parts
+///
+/// For example `chained(&p.first, &p.last)` orders people the same way as
+/// `format!("{} {}", p.first, p.last)`, but the parts are compared lazily without allocation.
+/// The separator prevents the concatenation ambiguity: without it
+/// `"ab"` + `"c"` and `"a"` + `"bc"` would be equal. Note that it doesn't make parts containing
+/// the separator themselves unambiguous: `"Mary Ann"` + `"Lee"` is equal to `"Mary"` +
+/// `"Ann Lee"`, the same way as their concatenations are equal. Use a tuple of parts instead if
+/// parts should be compared independently.
+///
+/// More than two parts can be chained with nested calls, `chained(chained(a, b), c)`.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|p| ord_by_key::chained(&p.first, &p.last))]
+/// pub struct Person {
+///     pub first: String,
+///     pub last: String,
+/// }
+///
+/// let person = |first: &str, last: &str| Person {
+///     first: first.to_string(),
+///     last: last.to_string(),
+/// };
+///
+/// // Same as "Ann Lee" < "Anna Belle"
+/// assert!(person("Ann", "Lee") < person("Anna", "Belle"));
+/// ```
+pub fn chained<A, B>(a: A, b: B) -> Chained<A, B>
+where
+    A: ChainPart,
+    B: ChainPart,
+{
+    Chained(a, b)
+}
+
+impl<S> ChainPart for S
+where
+    S: AsRef<str>,
+{
+    fn part_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.as_ref().bytes()
+    }
+}
+
+impl<A, B> ChainPart for Chained<A, B>
+where
+    A: ChainPart,
+    B: ChainPart,
+{
+    fn part_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0
+            .part_bytes()
+            .chain(iter::once(b' '))
+            .chain(self.1.part_bytes())
+    }
+}
+
+impl<A, B> Ord for Chained<A, B>
+where
+    A: ChainPart,
+    B: ChainPart,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.part_bytes().cmp(other.part_bytes())
+    }
+}
+
+impl<A, B> PartialOrd for Chained<A, B>
+where
+    A: ChainPart,
+    B: ChainPart,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, B> PartialEq for Chained<A, B>
+where
+    A: ChainPart,
+    B: ChainPart,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<A, B> Eq for Chained<A, B>
+where
+    A: ChainPart,
+    B: ChainPart,
+{
+}
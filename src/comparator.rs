@@ -0,0 +1,191 @@
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+/// Direction of a key of [`Comparator`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Smaller keys go first
+    #[default]
+    Asc,
+    /// Greater keys go first
+    Desc,
+}
+
+impl Direction {
+    /// Returns `ordering` of keys in this direction
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Direction::Asc => ordering,
+            Direction::Desc => ordering.reverse(),
+        }
+    }
+}
+
+/// Comparison performed by [`Comparator`]
+///
+/// Implemented for `()`, which considers all values equal, for [`Step`], and for pairs of
+/// comparisons, which compare by the second one when the first one returns
+/// [`Ordering::Equal`].
+pub trait CompareBy<T: ?Sized> {
+    /// Compares `a` with `b`
+    fn compare_by(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T: ?Sized> CompareBy<T> for () {
+    fn compare_by(&self, _: &T, _: &T) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T: ?Sized, A: CompareBy<T>, B: CompareBy<T>> CompareBy<T> for (A, B) {
+    fn compare_by(&self, a: &T, b: &T) -> Ordering {
+        self.0
+            .compare_by(a, b)
+            .then_with(|| self.1.compare_by(a, b))
+    }
+}
+
+/// A single key of [`Comparator`] with its direction
+#[derive(Clone, Copy, Debug)]
+pub struct Step<F> {
+    compare: F,
+    direction: Direction,
+}
+
+impl<T: ?Sized, F: Fn(&T, &T) -> Ordering> CompareBy<T> for Step<F> {
+    fn compare_by(&self, a: &T, b: &T) -> Ordering {
+        self.direction.apply((self.compare)(a, b))
+    }
+}
+
+/// Comparator builder for orderings whose direction is chosen at runtime
+///
+/// `Ord` generated by `ord_eq_by_key_selector` is fixed when the code is built, so it can't
+/// follow e.g. a column of a table which the user sorts ascending or descending. `Comparator`
+/// chains keys the same way as key expressions of the macro, and the direction of every key is
+/// set with [`Comparator::direction`], so keys can be flipped independently. Later keys are
+/// only compared when all previous keys are equal, regardless of their direction.
+///
+/// Keys are added with [`Comparator::then_key`], which borrows the key from the value, with
+/// [`Comparator::then_key_owned`], which computes it, or with [`Comparator::then_ord`], which
+/// uses [`Ord`] of the value itself, e.g. the one generated by `ord_eq_by_key_selector`. Keys
+/// are ascending until [`Comparator::direction`] is called. The comparator is used with
+/// [`Comparator::compare`], or turned into a closure for `sort_by` and similar methods with
+/// [`Comparator::build`].
+///
+/// ```
+/// use ord_by_key::{ord_eq_by_key_selector, Comparator, Direction};
+///
+/// #[ord_eq_by_key_selector(|p| p.id)]
+/// pub struct Person {
+///     pub id: u32,
+///     pub name: &'static str,
+///     pub age: u32,
+/// }
+///
+/// let mut people = vec![
+///     Person { id: 1, name: "Bob", age: 30 },
+///     Person { id: 2, name: "Alice", age: 25 },
+///     Person { id: 3, name: "Alice", age: 40 },
+/// ];
+///
+/// let ascending = false;
+/// let comparator = Comparator::<Person>::new()
+///     .then_key(|p| &p.name)
+///     .direction(if ascending { Direction::Asc } else { Direction::Desc })
+///     .then_key(|p| &p.age);
+///
+/// people.sort_by(comparator.build());
+///
+/// let ids: Vec<_> = people.iter().map(|p| p.id).collect();
+/// assert_eq!(ids, [1, 2, 3]);
+/// ```
+pub struct Comparator<T: ?Sized, C = ()> {
+    steps: C,
+    value: PhantomData<fn(&T, &T)>,
+}
+
+/// Comparator with `C` followed by a key compared with `F`
+type Then<T, C, F> = Comparator<T, (C, Step<F>)>;
+
+impl<T: ?Sized> Comparator<T> {
+    /// Creates comparator without keys, which considers all values equal
+    pub fn new() -> Self {
+        Comparator {
+            steps: (),
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Default for Comparator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized, C: CompareBy<T>> Comparator<T, C> {
+    /// Adds a key borrowed from the value, ascending by default
+    pub fn then_key<F, K>(self, key: F) -> Then<T, C, impl Fn(&T, &T) -> Ordering>
+    where
+        F: Fn(&T) -> &K,
+        K: Ord + ?Sized,
+    {
+        self.then_cmp(move |a, b| key(a).cmp(key(b)))
+    }
+
+    /// Adds a key computed from the value, ascending by default
+    ///
+    /// The key is computed twice on every comparison, the same way as key expressions of
+    /// `ord_eq_by_key_selector`.
+    pub fn then_key_owned<F, K>(self, key: F) -> Then<T, C, impl Fn(&T, &T) -> Ordering>
+    where
+        F: Fn(&T) -> K,
+        K: Ord,
+    {
+        self.then_cmp(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Adds [`Ord`] of the value itself as a key, ascending by default
+    pub fn then_ord(self) -> Then<T, C, impl Fn(&T, &T) -> Ordering>
+    where
+        T: Ord,
+    {
+        self.then_cmp(T::cmp)
+    }
+
+    /// Adds a comparison of two values as a key, ascending by default
+    pub fn then_cmp<F>(self, compare: F) -> Then<T, C, F>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let step = Step {
+            compare,
+            direction: Direction::Asc,
+        };
+
+        Comparator {
+            steps: (self.steps, step),
+            value: PhantomData,
+        }
+    }
+
+    /// Compares `a` with `b` by the keys in the order they were added
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.steps.compare_by(a, b)
+    }
+
+    /// Returns comparator as a closure, to be used with `sort_by`, `binary_search_by` and
+    /// similar methods
+    pub fn build(self) -> impl Fn(&T, &T) -> Ordering {
+        move |a, b| self.compare(a, b)
+    }
+}
+
+impl<T: ?Sized, C, F> Then<T, C, F> {
+    /// Sets direction of the last added key
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.steps.1.direction = direction;
+        self
+    }
+}
@@ -0,0 +1,59 @@
+/// Compares two values by fields of primitive types in a `const` context
+///
+/// `const_cmp!(a, b, .x, .y)` expands to a chain of `<` and `>` comparisons of fields `x` and
+/// `y` of `a` and `b`, which returns [`Ordering`](core::cmp::Ordering) of the first field which
+/// differs, the same as keys `|v| v.x, v.y` of the macro. [`Ord::cmp`] can't be called in
+/// `const fn`, but comparison operators of primitive types can, so this can be used to sort
+/// arrays at compile time, e.g. to build lookup tables searched with `binary_search_by`.
+///
+/// Fields are written as accessors: named (`.x`), tuple (`.0`) or nested (`.inner.x`). They
+/// have to be primitive integers, [`bool`] or [`char`], since comparison operators of other
+/// types call [`PartialOrd`], which is not `const`. Without fields values are equal.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use ord_by_key::const_cmp;
+///
+/// #[derive(Clone, Copy)]
+/// pub struct Pair(u8, i32);
+///
+/// const fn sorted<const N: usize>(mut pairs: [Pair; N]) -> [Pair; N] {
+///     let mut i = 1;
+///     while i < N {
+///         let mut j = i;
+///         while j > 0 {
+///             if let Ordering::Greater = const_cmp!(pairs[j - 1], pairs[j], .0, .1) {
+///                 let swap = pairs[j - 1];
+///                 pairs[j - 1] = pairs[j];
+///                 pairs[j] = swap;
+///             }
+///             j -= 1;
+///         }
+///         i += 1;
+///     }
+///     pairs
+/// }
+///
+/// const PAIRS: [Pair; 3] = sorted([Pair(2, 0), Pair(1, 5), Pair(1, -5)]);
+///
+/// assert_eq!([PAIRS[0].1, PAIRS[1].1, PAIRS[2].1], [-5, 5, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_cmp {
+    ($a:expr, $b:expr $(, $(. $field:tt)+)* $(,)?) => {{
+        let (a, b) = (&$a, &$b);
+        $crate::const_cmp!(@fields a, b; $(($(. $field)+))*)
+    }};
+    (@fields $a:ident, $b:ident; ($($field:tt)+) $($rest:tt)*) => {
+        if $a $($field)+ < $b $($field)+ {
+            ::core::cmp::Ordering::Less
+        } else if $a $($field)+ > $b $($field)+ {
+            ::core::cmp::Ordering::Greater
+        } else {
+            $crate::const_cmp!(@fields $a, $b; $($rest)*)
+        }
+    };
+    (@fields $a:ident, $b:ident;) => {
+        ::core::cmp::Ordering::Equal
+    };
+}
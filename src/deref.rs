@@ -0,0 +1,83 @@
+use core::cmp::Ordering;
+use core::ops::Deref;
+
+/// Key which orders smart pointers and guards by the value they point to
+///
+/// Some pointer-like types don't implement [`Ord`] themselves, for example
+/// [`Ref`](core::cell::Ref) guard returned by [`RefCell::borrow`](core::cell::RefCell::borrow).
+/// `ByDeref` compares them by [`Deref::Target`], which allows to use such guards as keys without
+/// copying the value out.
+///
+/// This is useful to order by a value which is expensive to compute and is cached in a field of
+/// the struct. Comparison takes `&self`, so the cache needs interior mutability.
+/// [`OnceCell`](core::cell::OnceCell) is the simplest option, because
+/// [`OnceCell::get_or_init`](core::cell::OnceCell::get_or_init) returns a plain reference which
+/// is a key by itself. If the cache has to be reset, use [`RefCell`](core::cell::RefCell) and
+/// `ByDeref` over the guard:
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::cell::RefCell;
+///
+/// #[ord_eq_by_key_selector(|d| {
+///     if d.normalized.borrow().is_none() {
+///         *d.normalized.borrow_mut() = Some(d.name.to_lowercase());
+///     }
+///
+///     ord_by_key::ByDeref(d.normalized.borrow())
+/// })]
+/// pub struct Document {
+///     pub name: String,
+///     // Lazily computed on the first comparison
+///     pub normalized: RefCell<Option<String>>,
+/// }
+///
+/// let document = |name: &str| Document {
+///     name: name.to_string(),
+///     normalized: RefCell::new(None),
+/// };
+///
+/// assert!(document("apple") < document("Banana"));
+/// ```
+///
+/// Note that both keys are alive while they are compared, so key expression must not try to
+/// borrow the cache mutably once it's populated: comparing a value with itself would panic.
+#[derive(Clone, Copy, Debug)]
+pub struct ByDeref<D>(pub D);
+
+impl<D> Ord for ByDeref<D>
+where
+    D: Deref,
+    D::Target: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self.0).cmp(&*other.0)
+    }
+}
+
+impl<D> PartialOrd for ByDeref<D>
+where
+    D: Deref,
+    D::Target: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D> PartialEq for ByDeref<D>
+where
+    D: Deref,
+    D::Target: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<D> Eq for ByDeref<D>
+where
+    D: Deref,
+    D::Target: Ord,
+{
+}
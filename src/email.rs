@@ -0,0 +1,110 @@
+use core::cmp::Ordering;
+use core::iter;
+
+/// Key which orders email addresses by their canonical form
+///
+/// Address is split at the last `@` into the local part and the domain. Domain is always
+/// lowercased, since domain names are case-insensitive. Rules for the local part depend on the
+/// constructor:
+///
+/// * [`EmailCanonical::new`] keeps the local part as is, which is what the standard requires in
+///   general
+/// * [`EmailCanonical::gmail`] applies Gmail-style rules: the local part is lowercased, the
+///   part starting at the first `+` (the tag) is removed, and all `.` are removed, so
+///   `User.Name+tag@Gmail.com` is equal to `username@gmail.com`
+///
+/// Gmail-style rules are applied regardless of the domain. Many providers don't ignore dots or
+/// tags, so select the constructor by domain in the key expression if addresses of different
+/// providers are mixed. Address without `@` is treated as a local part without a domain.
+/// Lowercasing uses [`char::to_lowercase`], and the canonical form is compared lazily by
+/// [`char`]s without allocation.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::EmailCanonical;
+///
+/// #[ord_eq_by_key_selector(|u| EmailCanonical::gmail(&u.email))]
+/// pub struct User {
+///     pub email: String,
+/// }
+///
+/// let user = |email: &str| User { email: email.to_string() };
+///
+/// assert!(user("User.Name+tag@Gmail.com") == user("username@gmail.com"));
+/// assert!(user("alice@gmail.com") < user("Bob@gmail.com"));
+/// assert!(EmailCanonical::new("User@Example.com") != EmailCanonical::new("user@example.com"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct EmailCanonical<'a> {
+    email: &'a str,
+    gmail: bool,
+}
+
+impl<'a> EmailCanonical<'a> {
+    /// Creates key which lowercases only the domain of `email`
+    pub fn new(email: &'a str) -> Self {
+        EmailCanonical {
+            email,
+            gmail: false,
+        }
+    }
+
+    /// Creates key which lowercases `email`, and removes the tag and dots of its local part
+    pub fn gmail(email: &'a str) -> Self {
+        EmailCanonical { email, gmail: true }
+    }
+
+    /// Returns characters of the canonical form
+    fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        let (local, domain) = match self.email.rfind('@') {
+            Some(at) => (&self.email[..at], Some(&self.email[at + 1..])),
+            None => (self.email, None),
+        };
+
+        let gmail = self.gmail;
+        let local = match local.find('+') {
+            Some(plus) if gmail => &local[..plus],
+            _ => local,
+        };
+
+        let local = local
+            .chars()
+            .filter(move |&c| !gmail || c != '.')
+            .flat_map(move |c| {
+                // Either lowercase form of the character, or the character itself
+                let (lower, keep) = if gmail {
+                    (Some(c.to_lowercase()), None)
+                } else {
+                    (None, Some(c))
+                };
+
+                lower.into_iter().flatten().chain(keep)
+            });
+
+        let domain = domain
+            .into_iter()
+            .flat_map(|domain| iter::once('@').chain(domain.chars().flat_map(char::to_lowercase)));
+
+        local.chain(domain)
+    }
+}
+
+impl<'a> Ord for EmailCanonical<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.chars().cmp(other.chars())
+    }
+}
+
+impl<'a> PartialOrd for EmailCanonical<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for EmailCanonical<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for EmailCanonical<'a> {}
@@ -0,0 +1,222 @@
+use core::cmp::Ordering;
+
+/// Key which orders [`f64`] by [`f64::total_cmp`]
+///
+/// Every bit pattern is distinct: `-0.0` is less than `+0.0`, and NaNs are ordered by their sign
+/// and payload (negative NaNs go before all numbers, positive NaNs go after all numbers). [`Eq`]
+/// is consistent with [`Ord`], so `-0.0` and `+0.0` are not equal, while two NaNs with the same
+/// bit pattern are equal.
+///
+/// Use [`NumericF64`] if `-0.0` and `+0.0` should be treated as the same value, e.g. for
+/// deduplication.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|m| ord_by_key::StrictF64(m.value))]
+/// pub struct Measurement {
+///     pub value: f64,
+/// }
+///
+/// assert!(Measurement { value: -0.0 } < Measurement { value: 0.0 });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StrictF64(pub f64);
+
+/// Key which orders [`f64`] numerically, with all NaNs equal to each other
+///
+/// `-0.0` and `+0.0` are equal, and all NaNs (regardless of sign and payload) are equal to each
+/// other and greater than all numbers, including infinity. [`Eq`] is consistent with [`Ord`].
+///
+/// Use [`StrictF64`] if every bit pattern should be distinct.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::collections::BTreeSet;
+///
+/// #[ord_eq_by_key_selector(|m| ord_by_key::NumericF64(m.value))]
+/// pub struct Measurement {
+///     pub value: f64,
+/// }
+///
+/// let set: BTreeSet<_> = [-0.0, 0.0, f64::NAN, -f64::NAN]
+///     .iter()
+///     .map(|&value| Measurement { value })
+///     .collect();
+///
+/// assert_eq!(set.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NumericF64(pub f64);
+
+/// Key which orders [`f64`] with absolute precision `eps`, consistently with [`Eq`]
+///
+/// Values are split into buckets of width `eps` by rounding `value / eps` to the nearest integer
+/// (halfway cases are rounded away from zero), and buckets are compared as [`NumericF64`]. Values
+/// in the same bucket are equal, so e.g. with `eps = 0.1` both `1.02` and `0.97` are equal to
+/// `1.0`.
+///
+/// Comparing values as equal if `(a - b).abs() < eps` is not safe for [`Ord`], because such
+/// equality is not transitive: with `eps = 1.0`, `0.0 == 0.6` and `0.6 == 1.2`, but
+/// `0.0 != 1.2`, so sorting, binary search and ordered collections may produce inconsistent
+/// results. Bucketing is transitive, at the cost of values which are closer than `eps` to each
+/// other but in adjacent buckets being not equal (e.g. `0.04` and `0.06` with `eps = 0.1`).
+///
+/// `eps` should be positive and the same for all compared values, since buckets of different
+/// precisions are not comparable.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|m| ord_by_key::NumericF64Eps(m.value, 0.01))]
+/// pub struct Measurement {
+///     pub value: f64,
+/// }
+///
+/// assert!(Measurement { value: 1.001 } == Measurement { value: 0.998 });
+/// assert!(Measurement { value: 1.001 } < Measurement { value: 1.02 });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NumericF64Eps(pub f64, pub f64);
+
+impl NumericF64Eps {
+    fn bucket(&self) -> NumericF64 {
+        NumericF64(round(self.0 / self.1))
+    }
+}
+
+/// Rounds to the nearest integer, halfway cases away from zero, same as `f64::round` which is
+/// not available without `std`
+fn round(value: f64) -> f64 {
+    // Values starting from 2^52 are integers, as well as infinities, NaNs pass through as is
+    if !(-4503599627370496.0 < value && value < 4503599627370496.0) {
+        return value;
+    }
+
+    // Both conversion and subtraction are exact for values less than 2^52
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Creates a key which orders values by weighted sum of numbers
+///
+/// Computes `w1 * v1 + w2 * v2 + ...` and returns it as [`StrictF64`], so the score can be
+/// used as a key even though [`f64`] doesn't implement [`Ord`]. Values can be of any type
+/// convertible to [`f64`] without loss, e.g. `f32`, `u32` or `i32`. To keep ordering intuitive,
+/// zero score is always `+0.0`, and NaN score (e.g. when one of values is NaN or infinity is
+/// multiplied by zero weight) is always positive NaN, which goes after all numbers.
+///
+/// Scores of different values can be equal, add more keys after the score to break ties
+/// deterministically.
+///
+/// ```
+/// use core::cmp::Reverse;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Documents with higher score go first, documents with the same score are ordered by id
+/// #[ord_eq_by_key_selector(|d|
+///     Reverse(ord_by_key::weighted([(0.6, d.relevance), (0.4, d.recency)])),
+///     d.id)]
+/// pub struct Document {
+///     pub id: u32,
+///     pub relevance: f32,
+///     pub recency: f32,
+/// }
+/// ```
+pub fn weighted<I, V>(terms: I) -> StrictF64
+where
+    I: IntoIterator<Item = (f64, V)>,
+    V: Into<f64>,
+{
+    let score = terms
+        .into_iter()
+        .fold(0.0, |score, (weight, value)| score + weight * value.into());
+
+    if score.is_nan() {
+        StrictF64(f64::NAN)
+    } else {
+        // `-0.0 + 0.0` is `+0.0`
+        StrictF64(score + 0.0)
+    }
+}
+
+impl Ord for StrictF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for StrictF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for StrictF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for StrictF64 {}
+
+impl Ord for NumericF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                if self.0 < other.0 {
+                    Ordering::Less
+                } else if self.0 > other.0 {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for NumericF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for NumericF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NumericF64 {}
+
+impl Ord for NumericF64Eps {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bucket().cmp(&other.bucket())
+    }
+}
+
+impl PartialOrd for NumericF64Eps {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for NumericF64Eps {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NumericF64Eps {}
@@ -0,0 +1,98 @@
+use core::ops::Bound;
+use core::ops::RangeBounds;
+
+/// Value which is ordered by a key of type [`HasKey::Key`]
+///
+/// Makes the key of a type available to generic code, e.g. to merge sequences of different
+/// types which share a key type with [`merge_by_key`](crate::merge_by_key). Can be implemented
+/// by hand, or generated by `ord_eq_by_key_selector` with `gen_has_key = Type` option, in which
+/// case [`HasKey::key`] returns values of key expressions.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::HasKey;
+///
+/// #[ord_eq_by_key_selector(|e| e.timestamp; gen_has_key = u64)]
+/// pub struct Event {
+///     pub timestamp: u64,
+/// }
+///
+/// fn latest<T: HasKey>(values: &[T]) -> Option<T::Key> {
+///     values.iter().map(HasKey::key).max()
+/// }
+///
+/// assert_eq!(latest(&[Event { timestamp: 5 }, Event { timestamp: 7 }]), Some(7));
+/// ```
+pub trait HasKey {
+    /// Type of the key
+    type Key: Ord;
+
+    /// Returns the key of the value
+    fn key(&self) -> Self::Key;
+
+    /// Returns `true` if the key of the value is less than `key`
+    ///
+    /// Together with [`HasKey::le_key`], makes boundaries of a key range in a sorted slice
+    /// available to [`slice::partition_point`] without constructing a value to search for:
+    /// `v.partition_point(|x| x.lt_key(&lo))` is the index of the first value with key not less
+    /// than `lo`. See [`key_range`] for the whole range.
+    fn lt_key(&self, key: &Self::Key) -> bool {
+        self.key() < *key
+    }
+
+    /// Returns `true` if the key of the value is less than or equal to `key`
+    fn le_key(&self, key: &Self::Key) -> bool {
+        self.key() <= *key
+    }
+
+    /// Returns `true` if the key of the value is greater than `key`
+    fn gt_key(&self, key: &Self::Key) -> bool {
+        self.key() > *key
+    }
+
+    /// Returns `true` if the key of the value is greater than or equal to `key`
+    fn ge_key(&self, key: &Self::Key) -> bool {
+        self.key() >= *key
+    }
+}
+
+/// Returns the subslice of `sorted` with keys in `range`
+///
+/// `sorted` has to be sorted by [`HasKey::key`], e.g. by `Ord` generated with `gen_has_key`.
+/// Boundaries are found by binary search with [`slice::partition_point`], so keys are computed
+/// for `O(log n)` values. Works with any kind of range of keys, e.g. `lo..hi`, `lo..=hi` or
+/// `..hi`. Empty slice is returned if the range is empty.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|e| e.timestamp; gen_has_key = u64)]
+/// pub struct Event {
+///     pub timestamp: u64,
+/// }
+///
+/// let events: Vec<_> = (0..10).map(|i| Event { timestamp: i * 10 }).collect();
+///
+/// let range = ord_by_key::key_range(&events, 25..=50);
+/// let timestamps: Vec<_> = range.iter().map(|e| e.timestamp).collect();
+/// assert_eq!(timestamps, [30, 40, 50]);
+/// ```
+pub fn key_range<T, R>(sorted: &[T], range: R) -> &[T]
+where
+    T: HasKey,
+    R: RangeBounds<T::Key>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(lo) => sorted.partition_point(|x| x.lt_key(lo)),
+        Bound::Excluded(lo) => sorted.partition_point(|x| x.le_key(lo)),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(hi) => sorted.partition_point(|x| x.le_key(hi)),
+        Bound::Excluded(hi) => sorted.partition_point(|x| x.lt_key(hi)),
+        Bound::Unbounded => sorted.len(),
+    };
+
+    &sorted[start..end.max(start)]
+}
@@ -0,0 +1,132 @@
+use core::cmp::Ordering;
+use core::hash::Hash;
+use core::hash::Hasher;
+
+/// Key which orders values by a stable 64-bit hash of the value
+///
+/// Useful to bucket or shuffle items deterministically: the order looks random, but it's the
+/// same across runs, platforms and versions of this crate. The hash is 64-bit FNV-1a, which is
+/// part of the crate's stability guarantee and won't change. Integers are hashed as little-endian
+/// and `usize`/`isize` as 64-bit, so the result doesn't depend on the platform. Note that the
+/// hash is computed from what the value's [`Hash`] implementation writes, which for types from
+/// the standard library is not formally guaranteed to stay the same across Rust versions. Also
+/// the standard library hashes slices of integers wider than a byte (e.g. `[u32]`) as raw
+/// memory, so their hashes differ between little-endian and big-endian platforms.
+///
+/// Different values can have the same hash, and such values compare equal. Add the value itself
+/// as the next key to get a total order.
+///
+/// This is not a cryptographic hash and doesn't protect against crafted collisions.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Records are ordered by hash of the key, then by the key itself
+/// #[ord_eq_by_key_selector(|r| ord_by_key::StableHash(&r.key), &r.key)]
+/// pub struct Record {
+///     pub key: String,
+/// }
+///
+/// assert_eq!(ord_by_key::StableHash("hello").value(), 0xa9bd73cca220c59c);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StableHash<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T> StableHash<'a, T>
+where
+    T: Hash + ?Sized,
+{
+    /// Returns the hash of the value
+    pub fn value(&self) -> u64 {
+        let mut hasher = Fnv1a(FNV_OFFSET_BASIS);
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hasher with platform-independent integer encoding
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+impl<'a, T> Ord for StableHash<'a, T>
+where
+    T: Hash + ?Sized,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl<'a, T> PartialOrd for StableHash<'a, T>
+where
+    T: Hash + ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, T> PartialEq for StableHash<'a, T>
+where
+    T: Hash + ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl<'a, T> Eq for StableHash<'a, T> where T: Hash + ?Sized {}
@@ -0,0 +1,119 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+
+/// Key wrapper which stores up to `N` elements of a sequence inline and orders them
+/// lexicographically, without allocation
+///
+/// Any [`Ord`] sequence type (`Vec`, `SmallVec`, `ArrayVec`, ...) can be returned from a key
+/// expression as is, `InlineSeq` is for the common case of short keys built on the fly, which
+/// would otherwise be collected into a `Vec`. Elements are compared in order like slices: the first
+/// differing element decides, and if one sequence is a prefix of the other, the shorter one is
+/// less.
+///
+/// Sequences longer than `N` are truncated: only the first `N` elements are stored and compared,
+/// the rest are ignored, so sequences which differ only after `N` elements are equal. Use
+/// [`InlineSeq::is_truncated`] to check whether elements were dropped.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::InlineSeq;
+///
+/// // Paths are ordered by their first 4 segments
+/// #[ord_eq_by_key_selector(|p| p.path.split('/').collect::<InlineSeq<&str, 4>>())]
+/// pub struct Route {
+///     pub path: String,
+/// }
+///
+/// let route = |path: &str| Route { path: path.to_string() };
+/// assert!(route("api/users") < route("api/users/list"));
+/// assert!(route("api/users/list") < route("api/v2"));
+/// // Segments after the 4th are ignored
+/// assert!(route("a/b/c/d/e") == route("a/b/c/d/f"));
+/// ```
+pub struct InlineSeq<T, const N: usize> {
+    items: [Option<T>; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<T, const N: usize> InlineSeq<T, N> {
+    /// Creates the key from the first `N` elements of `values`, remaining elements are dropped
+    pub fn new<I: IntoIterator<Item = T>>(values: I) -> Self {
+        let mut values = values.into_iter().fuse();
+        let items = [(); N].map(|_| values.next());
+        let len = items.iter().take_while(|item| item.is_some()).count();
+        let truncated = len == N && values.next().is_some();
+
+        InlineSeq {
+            items,
+            len,
+            truncated,
+        }
+    }
+
+    /// Returns number of stored elements, at most `N`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no elements are stored
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the sequence had more than `N` elements, and the rest were dropped
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns stored elements in order
+    pub fn iter(&self) -> impl Iterator<Item = &T> + Clone {
+        self.items[..self.len].iter().flatten()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for InlineSeq<T, N> {
+    /// Same as [`InlineSeq::new`], elements after the first `N` are dropped
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        InlineSeq::new(values)
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for InlineSeq<T, N> {
+    fn clone(&self) -> Self {
+        InlineSeq {
+            items: self.items.clone(),
+            len: self.len,
+            truncated: self.truncated,
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Copy for InlineSeq<T, N> {}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for InlineSeq<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for InlineSeq<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Ord, const N: usize> PartialOrd for InlineSeq<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord, const N: usize> PartialEq for InlineSeq<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord, const N: usize> Eq for InlineSeq<T, N> {}
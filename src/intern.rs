@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::vec;
+use std::vec::Vec;
+
+/// Sorts `values` by a borrowed key, comparing keys only while building a table of distinct keys
+///
+/// Distinct keys are collected into a sorted intern table first, and every value gets the rank
+/// of its key in the table. Values are then sorted by ranks with a counting sort, which doesn't
+/// compare keys at all. The result is the same as of `values.sort_by(|a, b| key(a).cmp(key(b)))`,
+/// including stability: values with equal keys keep their relative order.
+///
+/// Looking up a key in a table of `m` distinct keys takes `O(log m)` comparisons, which is
+/// fewer than a regular sort needs per value when `m` is small, so this is faster when many
+/// values share a handful of keys which are expensive to compare, e.g. long category names or
+/// paths with common prefixes. The standard sort handles repeated keys well too, so the gain is
+/// moderate, and it disappears as the number of distinct keys grows: when most keys are distinct,
+/// lookups take as many comparisons as a regular sort, and this is slower because of the table.
+/// Requires `std` feature.
+///
+/// ```
+/// pub struct Record {
+///     pub category: String,
+///     pub id: u32,
+/// }
+///
+/// let record = |category: &str, id| Record { category: category.to_string(), id };
+/// let mut records = [
+///     record("vegetables", 1),
+///     record("fruit", 2),
+///     record("vegetables", 3),
+///     record("dairy", 4),
+///     record("fruit", 5),
+/// ];
+///
+/// ord_by_key::intern_sort(&mut records, |r| &r.category);
+///
+/// let ids: Vec<_> = records.iter().map(|r| r.id).collect();
+/// assert_eq!(ids, [4, 2, 5, 1, 3]);
+/// ```
+pub fn intern_sort<T, K, F>(values: &mut [T], mut key: F)
+where
+    K: Ord + ?Sized,
+    F: FnMut(&T) -> &K,
+{
+    // Keys get ids in order of first occurrence, which are then mapped to ranks of keys in the
+    // sorted table, so each value's key is compared only while looking it up in the table
+    let mut table: BTreeMap<&K, usize> = BTreeMap::new();
+    let ids: Vec<usize> = values
+        .iter()
+        .map(|value| {
+            let id = table.len();
+            *table.entry(key(value)).or_insert(id)
+        })
+        .collect();
+
+    let mut ranks = vec![0; table.len()];
+    for (rank, &id) in table.values().enumerate() {
+        ranks[id] = rank;
+    }
+
+    let table_len = table.len();
+    let ranks: Vec<usize> = ids.iter().map(|&id| ranks[id]).collect();
+
+    // Counting sort by ranks, which is stable. `order[i]` is the index of the value which goes to
+    // position `i`
+    let mut starts = vec![0; table_len + 1];
+    for &rank in &ranks {
+        starts[rank + 1] += 1;
+    }
+    for rank in 1..starts.len() {
+        starts[rank] += starts[rank - 1];
+    }
+
+    let mut order = vec![0; values.len()];
+    for (index, &rank) in ranks.iter().enumerate() {
+        order[starts[rank]] = index;
+        starts[rank] += 1;
+    }
+
+    // Moves values along each cycle of the permutation, marking placed positions in `order`
+    for start in 0..order.len() {
+        let mut current = start;
+        while order[current] != start {
+            let next = order[current];
+            order[current] = current;
+            values.swap(current, next);
+            current = next;
+        }
+        order[current] = current;
+    }
+}
@@ -0,0 +1,123 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Reference to a value ordered by a key function, e.g. to put large values into a heap without
+/// moving or cloning them
+///
+/// Holds `&T` and the key function, and compares values by keys returned by the function. The
+/// key function of the left operand is used for both values, so all compared references should
+/// be created with the same function (closures without captured state, or with the same captured
+/// state), otherwise the order is inconsistent.
+///
+/// ```
+/// use ord_by_key::ByKeyRef;
+/// use std::collections::BinaryHeap;
+///
+/// pub struct Report {
+///     pub severity: u8,
+///     pub body: [u8; 4096],
+/// }
+///
+/// let reports: Vec<_> = [2, 9, 5]
+///     .iter()
+///     .map(|&severity| Report { severity, body: [0; 4096] })
+///     .collect();
+///
+/// let by_severity = |r: &Report| r.severity;
+/// let mut heap: BinaryHeap<_> = reports.iter().map(|r| ByKeyRef::new(r, by_severity)).collect();
+///
+/// assert_eq!(heap.pop().unwrap().get().severity, 9);
+/// assert_eq!(heap.pop().unwrap().get().severity, 5);
+/// ```
+///
+/// The references borrow the source, so a collection of `ByKeyRef` can't outlive it (e.g. the
+/// heap can't be returned from a function which owns the source vector), and the source can't be
+/// modified while the collection exists. Key function takes a reference to the value, so it's
+/// not possible to borrow from the value itself with `Fn(&T) -> &K`, return keys by value (e.g.
+/// copy or clone them).
+pub struct ByKeyRef<'a, T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    value: &'a T,
+    key: F,
+    _key: PhantomData<fn() -> K>,
+}
+
+impl<'a, T, K, F> ByKeyRef<'a, T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    /// Creates reference to `value` ordered by `key`
+    pub fn new(value: &'a T, key: F) -> Self {
+        ByKeyRef {
+            value,
+            key,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns the reference to the value
+    pub fn get(&self) -> &'a T {
+        self.value
+    }
+}
+
+impl<'a, T, K, F> Clone for ByKeyRef<'a, T, K, F>
+where
+    F: Fn(&T) -> K + Clone,
+{
+    fn clone(&self) -> Self {
+        ByKeyRef::new(self.value, self.key.clone())
+    }
+}
+
+impl<'a, T, K, F> Copy for ByKeyRef<'a, T, K, F> where F: Fn(&T) -> K + Copy {}
+
+impl<'a, T, K, F> fmt::Debug for ByKeyRef<'a, T, K, F>
+where
+    T: fmt::Debug,
+    F: Fn(&T) -> K,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ByKeyRef").field(&self.value).finish()
+    }
+}
+
+impl<'a, T, K, F> Ord for ByKeyRef<'a, T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.key)(self.value).cmp(&(self.key)(other.value))
+    }
+}
+
+impl<'a, T, K, F> PartialOrd for ByKeyRef<'a, T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, T, K, F> PartialEq for ByKeyRef<'a, T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, T, K, F> Eq for ByKeyRef<'a, T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+}
@@ -0,0 +1,33 @@
+/// Returns element of lookup `table` at `index`, clamping out-of-range indices to the last
+/// element, to be used as a key which maps small codes to ranks
+///
+/// Indexing a table in a key expression directly (`RANKS[x.code as usize]`) panics on a code
+/// out of range, so a single malformed value makes sorting panic. `lut` instead treats any index
+/// past the end as the last index: codes `table.len()` and above have the rank of the last
+/// element. Leave a sentinel there (e.g. `u8::MAX`) to sort unknown codes after the known ones.
+/// Note that converting a negative code with `as usize` wraps it to a large index, so negative
+/// codes get the last rank as well.
+///
+/// Panics if `table` is empty, since no element can be returned.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Codes 0..3 are known, any other code is unknown and sorts last
+/// const RANKS: [u8; 4] = [2, 0, 1, u8::MAX];
+///
+/// #[ord_eq_by_key_selector(|s| ord_by_key::lut(&RANKS, s.code as usize))]
+/// pub struct Status {
+///     pub code: u32,
+/// }
+///
+/// assert!(Status { code: 1 } < Status { code: 2 });
+/// assert!(Status { code: 0 } < Status { code: 1000 });
+/// assert!(Status { code: 1000 } == Status { code: 3 });
+/// ```
+pub fn lut<T: Copy>(table: &[T], index: usize) -> T {
+    match table.get(index) {
+        Some(value) => *value,
+        None => *table.last().expect("lookup table has to be non-empty"),
+    }
+}
@@ -0,0 +1,115 @@
+use crate::HasKey;
+
+/// Item of a merge of two sequences of different types, see [`keyed_merge`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Merged<A, B> {
+    /// Item of the first sequence
+    Left(A),
+    /// Item of the second sequence
+    Right(B),
+}
+
+/// Merges two sequences of different types sorted by keys of the same type
+///
+/// Both sequences have to be sorted by their keys (`key_a` and `key_b` respectively), then items
+/// are yielded in order of keys, wrapped into [`Merged`] to tell which sequence they come from.
+/// Merge is stable: items with equal keys are yielded in order of their sequence, and items of
+/// the first sequence go before items of the second one. Keys are evaluated once per comparison.
+///
+/// ```
+/// use ord_by_key::Merged;
+///
+/// let local = [(1, "started"), (5, "stopped")];
+/// let remote = [10, 3];
+///
+/// let merged: Vec<_> = ord_by_key::keyed_merge(
+///     local.iter(),
+///     remote.iter().rev(),
+///     |(timestamp, _)| *timestamp,
+///     |timestamp| **timestamp,
+/// )
+/// .collect();
+///
+/// assert_eq!(
+///     merged,
+///     [
+///         Merged::Left(&(1, "started")),
+///         Merged::Right(&3),
+///         Merged::Left(&(5, "stopped")),
+///         Merged::Right(&10),
+///     ]
+/// );
+/// ```
+pub fn keyed_merge<IA, IB, K, FA, FB>(
+    a: IA,
+    b: IB,
+    key_a: FA,
+    key_b: FB,
+) -> impl Iterator<Item = Merged<IA::Item, IB::Item>>
+where
+    IA: IntoIterator,
+    IB: IntoIterator,
+    K: Ord,
+    FA: Fn(&IA::Item) -> K,
+    FB: Fn(&IB::Item) -> K,
+{
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+
+    core::iter::from_fn(move || {
+        let left = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => key_a(x) <= key_b(y),
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return None,
+        };
+
+        if left {
+            a.next().map(Merged::Left)
+        } else {
+            b.next().map(Merged::Right)
+        }
+    })
+}
+
+/// Merges two sequences of different types sorted by keys of the same type, using [`HasKey`]
+///
+/// Same as [`keyed_merge`] with [`HasKey::key`] as key functions.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::Merged;
+///
+/// #[ord_eq_by_key_selector(|e| e.timestamp; gen_has_key = u64)]
+/// pub struct LocalEvent {
+///     pub timestamp: u64,
+/// }
+///
+/// #[ord_eq_by_key_selector(|e| e.received_at; gen_has_key = u64)]
+/// pub struct RemoteEvent {
+///     pub received_at: u64,
+///     pub host: &'static str,
+/// }
+///
+/// let local = vec![LocalEvent { timestamp: 1 }, LocalEvent { timestamp: 4 }];
+/// let remote = vec![RemoteEvent { received_at: 2, host: "a" }];
+///
+/// let timestamps: Vec<_> = ord_by_key::merge_by_key(local, remote)
+///     .map(|event| match event {
+///         Merged::Left(local) => local.timestamp,
+///         Merged::Right(remote) => remote.received_at,
+///     })
+///     .collect();
+///
+/// assert_eq!(timestamps, [1, 2, 4]);
+/// ```
+pub fn merge_by_key<IA, IB, K>(a: IA, b: IB) -> impl Iterator<Item = Merged<IA::Item, IB::Item>>
+where
+    IA: IntoIterator,
+    IB: IntoIterator,
+    IA::Item: HasKey<Key = K>,
+    IB::Item: HasKey<Key = K>,
+    K: Ord,
+{
+    keyed_merge(a, b, HasKey::key, HasKey::key)
+}
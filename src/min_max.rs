@@ -0,0 +1,86 @@
+/// Returns the greater of two values by [`Ord`], to be used as a key
+///
+/// Same as [`core::cmp::max`]. Works with borrowed values, so keys which are not [`Copy`] don't
+/// have to be cloned: `max_of(&p.first, &p.second)` returns a reference to the greater field.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Rectangles are bucketed by the longer side, then by the shorter side
+/// #[ord_eq_by_key_selector(|r|
+///     ord_by_key::max_of(r.width, r.height),
+///     ord_by_key::min_of(r.width, r.height))]
+/// pub struct Rectangle {
+///     pub width: u32,
+///     pub height: u32,
+/// }
+///
+/// assert!(Rectangle { width: 3, height: 1 } == Rectangle { width: 1, height: 3 });
+/// assert!(Rectangle { width: 3, height: 1 } < Rectangle { width: 2, height: 4 });
+/// ```
+pub fn max_of<T: Ord>(a: T, b: T) -> T {
+    core::cmp::max(a, b)
+}
+
+/// Returns the lesser of two values by [`Ord`], to be used as a key
+///
+/// Same as [`core::cmp::min`]. Works with borrowed values the same way as [`max_of`].
+pub fn min_of<T: Ord>(a: T, b: T) -> T {
+    core::cmp::min(a, b)
+}
+
+/// Returns the greatest of several values by [`Ord`], to be used as a key
+///
+/// Generalizes [`max_of`] to any number of values, e.g. to order records by the most recent of
+/// their timestamps. Works with borrowed values the same way as [`max_of`]. If several values are
+/// equally great, the last one is returned, same as [`Iterator::max`]. The array must not be
+/// empty: an empty array fails the build (but not `cargo check`, which doesn't evaluate the
+/// check).
+///
+/// ```
+/// use core::cmp::Reverse;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Recently touched records go first
+/// #[ord_eq_by_key_selector(|r| Reverse(ord_by_key::latest_of([r.created, r.updated, r.accessed])))]
+/// pub struct Record {
+///     pub created: u64,
+///     pub updated: u64,
+///     pub accessed: u64,
+/// }
+///
+/// let record = |created, updated, accessed| Record { created, updated, accessed };
+/// assert!(record(1, 2, 9) < record(5, 5, 5));
+/// assert!(record(1, 9, 2) == record(9, 1, 1));
+/// ```
+pub fn latest_of<T: Ord, const N: usize>(values: [T; N]) -> T {
+    #[allow(clippy::let_unit_value)]
+    let () = NonEmpty::<N>::CHECK;
+
+    IntoIterator::into_iter(values)
+        .max()
+        .expect("checked when the code is built")
+}
+
+/// Returns the least of several values by [`Ord`], to be used as a key
+///
+/// Generalizes [`min_of`] the same way as [`latest_of`] generalizes [`max_of`]. If several values
+/// are equally least, the first one is returned, same as [`Iterator::min`].
+pub fn earliest_of<T: Ord, const N: usize>(values: [T; N]) -> T {
+    #[allow(clippy::let_unit_value)]
+    let () = NonEmpty::<N>::CHECK;
+
+    IntoIterator::into_iter(values)
+        .min()
+        .expect("checked when the code is built")
+}
+
+/// Fails compilation of `latest_of` and `earliest_of` with an empty array
+struct NonEmpty<const N: usize>;
+
+impl<const N: usize> NonEmpty<N> {
+    const CHECK: () = assert!(
+        N > 0,
+        "`latest_of` and `earliest_of` require at least one value"
+    );
+}
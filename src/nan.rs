@@ -0,0 +1,32 @@
+use core::cmp::Ordering;
+
+/// Compares values by [`PartialOrd`], treating values which are not comparable even to
+/// themselves (e.g. NaN) as equal to each other and greater than any other value
+///
+/// This is the comparison of keys used by `partial_only(nan = equal)` mode of the macro. Unlike
+/// [`PartialOrd::partial_cmp`] of floats, it orders NaN consistently, but it still returns
+/// `None` for values which are incomparable for other reasons, e.g. sets ordered by inclusion.
+/// Values are checked for NaN only when they are incomparable, so comparison of ordinary values
+/// costs the same as `partial_cmp`. The check is done for the value as a whole: a tuple holding
+/// NaN is equal to any other tuple holding NaN, use separate keys to compare the other elements.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use ord_by_key::nan_equal_cmp;
+///
+/// assert_eq!(nan_equal_cmp(&f64::NAN, &f64::NAN), Some(Ordering::Equal));
+/// assert_eq!(nan_equal_cmp(&f64::NAN, &f64::INFINITY), Some(Ordering::Greater));
+/// assert_eq!(nan_equal_cmp(&1.0, &f64::NAN), Some(Ordering::Less));
+/// assert_eq!(nan_equal_cmp(&1.0, &2.0), Some(Ordering::Less));
+/// ```
+pub fn nan_equal_cmp<T: PartialOrd + ?Sized>(a: &T, b: &T) -> Option<Ordering> {
+    match a.partial_cmp(b) {
+        None => match (a.partial_cmp(a).is_none(), b.partial_cmp(b).is_none()) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
+            (false, false) => None,
+        },
+        result => result,
+    }
+}
@@ -0,0 +1,65 @@
+use core::cmp::Ordering;
+
+/// Key which orders strings of decimal digits as integers, e.g. zero-padded identifiers
+///
+/// Strings consisting only of ASCII digits are compared as non-negative integers of any length:
+/// leading zeros are ignored, so `"007" == "7"` and `"010" > "9"`. Numbers which don't fit into
+/// any integer type are supported, since trimmed digits are compared by length and then
+/// lexicographically, without parsing.
+///
+/// All other strings (including empty strings, signs, spaces and non-ASCII digits) are not
+/// numbers: they go after all numbers and are compared with each other as strings. Trim or
+/// validate input in the key expression if it may contain such characters.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|o| ord_by_key::NumericStr(&o.id))]
+/// pub struct Order {
+///     pub id: String,
+/// }
+///
+/// let order = |id: &str| Order { id: id.to_string() };
+///
+/// assert!(order("007") == order("7"));
+/// assert!(order("9") < order("010"));
+/// assert!(order("123") < order("n/a"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NumericStr<'a>(pub &'a str);
+
+impl<'a> NumericStr<'a> {
+    /// Digits without leading zeros, or `None` if the string is not a number
+    fn digits(&self) -> Option<&'a str> {
+        if !self.0.is_empty() && self.0.bytes().all(|b| b.is_ascii_digit()) {
+            Some(self.0.trim_start_matches('0'))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Ord for NumericStr<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.digits(), other.digits()) {
+            (Some(a), Some(b)) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.0.cmp(other.0),
+        }
+    }
+}
+
+impl<'a> PartialOrd for NumericStr<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for NumericStr<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for NumericStr<'a> {}
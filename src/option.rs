@@ -0,0 +1,120 @@
+use core::cmp::Ordering;
+
+/// Key which orders [`Option`] with `None` before any value
+///
+/// This is the same as the order of [`Option`] itself, the wrapper makes placement of `None`
+/// explicit next to [`NoneLast`].
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|t| ord_by_key::NoneFirst(t.due))]
+/// pub struct Task {
+///     pub due: Option<u32>,
+/// }
+///
+/// assert!(Task { due: None } < Task { due: Some(0) });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NoneFirst<T>(pub Option<T>);
+
+/// Key which orders [`Option`] with `None` after any value
+///
+/// Inner value can be a key wrapper itself, e.g. `NoneLast(name.map(CaseInsensitive))` orders
+/// names case-insensitively with missing names last, see
+/// [`CaseInsensitive`](crate::CaseInsensitive).
+///
+/// Combined with `match`, this orders an enum where only some variants carry an orderable
+/// payload: such variants are ordered by payload, and the rest form a single bucket after them.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|e| ord_by_key::NoneLast(match e {
+///     Event::Scheduled(at) => Some(*at),
+///     _ => None,
+/// }))]
+/// pub enum Event {
+///     Scheduled(u64),
+///     Unscheduled,
+///     Cancelled,
+/// }
+///
+/// assert!(Event::Scheduled(2) < Event::Unscheduled);
+/// assert!(Event::Scheduled(1) < Event::Scheduled(2));
+/// assert!(Event::Unscheduled == Event::Cancelled);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NoneLast<T>(pub Option<T>);
+
+// Conversions from borrowed options are used by `nulls = first` and `nulls = last` key modifiers
+// of the macro, so both `p.field` and `&p.field` can be wrapped without moving the value
+
+impl<T> From<Option<T>> for NoneFirst<T> {
+    fn from(value: Option<T>) -> Self {
+        NoneFirst(value)
+    }
+}
+
+impl<'a, T> From<&'a Option<T>> for NoneFirst<&'a T> {
+    fn from(value: &'a Option<T>) -> Self {
+        NoneFirst(value.as_ref())
+    }
+}
+
+impl<T> From<Option<T>> for NoneLast<T> {
+    fn from(value: Option<T>) -> Self {
+        NoneLast(value)
+    }
+}
+
+impl<'a, T> From<&'a Option<T>> for NoneLast<&'a T> {
+    fn from(value: &'a Option<T>) -> Self {
+        NoneLast(value.as_ref())
+    }
+}
+
+impl<T: Ord> Ord for NoneFirst<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Ord> PartialOrd for NoneFirst<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for NoneFirst<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for NoneFirst<T> {}
+
+impl<T: Ord> Ord for NoneLast<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Some(value), Some(other)) => value.cmp(other),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+        }
+    }
+}
+
+impl<T: Ord> PartialOrd for NoneLast<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for NoneLast<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for NoneLast<T> {}
@@ -0,0 +1,63 @@
+/// Value which may be missing, implemented for [`Option`] and [`Result`]
+///
+/// Used by [`or_default`], which makes the fallback available in key expressions regardless of
+/// the wrapper type.
+pub trait OrDefault {
+    /// Type of the value when it's present
+    type Value: Default;
+
+    /// Returns the value, or [`Default::default`] when it's missing
+    fn or_default(self) -> Self::Value;
+}
+
+impl<T: Default> OrDefault for Option<T> {
+    type Value = T;
+
+    fn or_default(self) -> T {
+        self.unwrap_or_default()
+    }
+}
+
+impl<T: Default, E> OrDefault for Result<T, E> {
+    type Value = T;
+
+    fn or_default(self) -> T {
+        self.unwrap_or_default()
+    }
+}
+
+/// Returns the value of `Some`/`Ok`, or [`Default::default`] for `None`/`Err`, to be used as a
+/// key
+///
+/// Fallible getters can't panic during comparison, so all values which fail to produce a key
+/// compare equal to the default, e.g. zero for numbers and empty string for strings, and are
+/// placed accordingly. The error itself doesn't participate in comparison. Wrap the key into
+/// [`NoneFirst`](crate::NoneFirst) or [`NoneLast`](crate::NoneLast) instead, if missing values
+/// should be placed separately from the default.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// pub struct Config {
+///     pub port: &'static str,
+/// }
+///
+/// impl Config {
+///     pub fn port(&self) -> Result<u16, core::num::ParseIntError> {
+///         self.port.parse()
+///     }
+/// }
+///
+/// // Configs with invalid port are ordered as port `0`
+/// #[ord_eq_by_key_selector(|s| ord_by_key::or_default(s.config.port()))]
+/// pub struct Server {
+///     pub config: Config,
+/// }
+///
+/// let server = |port| Server { config: Config { port } };
+/// assert!(server("invalid") < server("80"));
+/// assert!(server("invalid") == server("0"));
+/// ```
+pub fn or_default<T: OrDefault>(value: T) -> T::Value {
+    value.or_default()
+}
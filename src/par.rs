@@ -0,0 +1,27 @@
+use rayon::slice::ParallelSliceMut;
+
+/// Sorts `values` in parallel with `rayon`'s `par_sort_unstable`
+///
+/// Comparison generated by the macro is a pure function of the two values, so it can be called
+/// from multiple threads at once, and types using it are [`Send`] and [`Sync`] whenever their
+/// fields are. The sort is unstable: values which are equal may be reordered. This is the
+/// function called by `par_sort` method of `gen_par_sort` option.
+///
+/// Requires `rayon` feature.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|e| e.score)]
+/// pub struct Entry {
+///     pub score: u64,
+/// }
+///
+/// let mut entries: Vec<_> = (0..10_000).rev().map(|score| Entry { score }).collect();
+/// ord_by_key::par_sort(&mut entries);
+///
+/// assert!(entries.windows(2).all(|pair| pair[0] <= pair[1]));
+/// ```
+pub fn par_sort<T: Ord + Send>(values: &mut [T]) {
+    values.par_sort_unstable();
+}
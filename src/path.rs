@@ -0,0 +1,104 @@
+use core::cmp::Ordering;
+
+/// Key which orders paths component by component
+///
+/// Path is split on the separator and components are compared in order as strings. Parent
+/// path is a prefix of its children, so it's less than any of them. This is different from
+/// plain string order, where e.g. `a/b` goes after `a.txt` because `.` is less than `/`.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|e| ord_by_key::PathOrd(&e.path, '/'))]
+/// pub struct Entry {
+///     pub path: String,
+/// }
+///
+/// let entry = |path: &str| Entry { path: path.to_string() };
+///
+/// assert!(entry("a") < entry("a/b"));
+/// assert!(entry("a/b") < entry("a/c"));
+/// assert!(entry("a/b") < entry("a.txt"));
+/// ```
+///
+/// Empty components are kept, so `a//b` and `a/b` are different paths, and trailing separator
+/// makes path a child of the same path without it.
+#[derive(Clone, Copy, Debug)]
+pub struct PathOrd<'a>(pub &'a str, pub char);
+
+impl<'a> Ord for PathOrd<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.split(self.1).cmp(other.0.split(other.1))
+    }
+}
+
+impl<'a> PartialOrd for PathOrd<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for PathOrd<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for PathOrd<'a> {}
+
+/// Key which orders strings by their last segment, ignoring namespace prefix
+///
+/// Only the part after the last occurrence of the separator is compared, or the whole string if
+/// there is no separator (or the separator is empty). Trailing separator makes the last segment
+/// empty, so e.g. `a::` goes before any string with non-empty last segment. Strings which
+/// differ only in prefix are equal, add the whole string as the next key to break ties.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|s| ord_by_key::LastSegment(&s.id, "::"), &s.id)]
+/// pub struct Symbol {
+///     pub id: String,
+/// }
+///
+/// let symbol = |id: &str| Symbol { id: id.to_string() };
+///
+/// assert!(symbol("b::alpha") < symbol("a::beta"));
+/// assert!(symbol("a::alpha") < symbol("b::alpha"));
+/// assert!(symbol("a::alpha") < symbol("alpha"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LastSegment<'a, 'b>(pub &'a str, pub &'b str);
+
+impl<'a, 'b> LastSegment<'a, 'b> {
+    fn segment(&self) -> &'a str {
+        if self.1.is_empty() {
+            return self.0;
+        }
+
+        match self.0.rfind(self.1) {
+            Some(index) => &self.0[index + self.1.len()..],
+            None => self.0,
+        }
+    }
+}
+
+impl<'a, 'b> Ord for LastSegment<'a, 'b> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.segment().cmp(other.segment())
+    }
+}
+
+impl<'a, 'b> PartialOrd for LastSegment<'a, 'b> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, 'b> PartialEq for LastSegment<'a, 'b> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, 'b> Eq for LastSegment<'a, 'b> {}
@@ -0,0 +1,73 @@
+use bytemuck::Pod;
+use core::cmp::Ordering;
+
+/// Key which orders plain-old-data values by their raw bytes
+///
+/// Compares memory representations of values (as [`bytemuck::bytes_of`]) lexicographically, in
+/// one `memcmp`-like call instead of field-by-field comparison. Intended for fixed-layout
+/// records, e.g. `#[repr(C)]` structs read from files or network packets, which are compared
+/// often and where every byte is significant.
+///
+/// **Byte order is not numeric order.** Raw bytes are compared from the lowest address, so the
+/// result matches the "natural" order of the fields only if:
+///
+/// * the type is `#[repr(C)]` (or `#[repr(transparent)]`), so fields are laid out in
+///   declaration order - Rust layout may reorder fields
+/// * every multi-byte number is stored big-endian, e.g. as `[u8; 4]` produced by
+///   [`u32::to_be_bytes`]. Native integers on little-endian targets (x86, ARM) are compared by
+///   their least significant byte first, so `256u32` is less than `1u32`, and the order is
+///   different on big-endian targets
+/// * numbers are unsigned. Negative two's complement integers and floats have the sign bit set,
+///   so they go after positive ones, and negative floats are ordered in reverse
+///
+/// [`Pod`] guarantees there are no padding bytes, so all compared bytes are initialized, but it
+/// doesn't make the order meaningful. [`Eq`] is consistent with [`Ord`], values are equal when
+/// they are equal bit for bit (so e.g. `-0.0` and `+0.0` floats are different, and equal NaNs
+/// are equal). If the layout doesn't meet the requirements above, compare fields instead.
+///
+/// ```
+/// use bytemuck::Pod;
+/// use bytemuck::Zeroable;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Fields are declared in order of significance, numbers are stored big-endian
+/// #[ord_eq_by_key_selector(|r| ord_by_key::ByBytes(*r))]
+/// #[derive(Clone, Copy, Pod, Zeroable)]
+/// #[repr(C)]
+/// pub struct Record {
+///     pub partition: [u8; 2],
+///     pub offset: [u8; 8],
+/// }
+///
+/// let record = |partition: u16, offset: u64| Record {
+///     partition: partition.to_be_bytes(),
+///     offset: offset.to_be_bytes(),
+/// };
+///
+/// assert!(record(1, 256) < record(2, 1));
+/// assert!(record(1, 1) < record(1, 256));
+/// ```
+///
+/// Requires `bytemuck` feature.
+#[derive(Clone, Copy, Debug)]
+pub struct ByBytes<T: Pod>(pub T);
+
+impl<T: Pod> Ord for ByBytes<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        bytemuck::bytes_of(&self.0).cmp(bytemuck::bytes_of(&other.0))
+    }
+}
+
+impl<T: Pod> PartialOrd for ByBytes<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Pod> PartialEq for ByBytes<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Pod> Eq for ByBytes<T> {}
@@ -0,0 +1,65 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// Returns comparator which orders values by position of their key in `order`, e.g. to reorder
+/// a result set to match a precomputed ranking
+///
+/// `key` returns the identifier of a value, e.g. `|item| item.id`. The identifier is returned by
+/// value (anything which [`Borrow`]s the type of `order` elements), so identifiers which are not
+/// [`Copy`] have to be cloned, or `order` has to hold cheap copies of them, e.g. `&[&str]` for
+/// names which are `&'static str`. Values whose identifiers are not in `order` go after all
+/// that are, and compare equal with each other, so the stable `sort_by` keeps their original
+/// order. If an identifier occurs in `order` more than once, its first position is used, same as
+/// with [`Iterator::position`].
+///
+/// Looking up the position with [`Iterator::position`] on every comparison would make sorting
+/// `O(n * m * log n)` for `n` values and `m` identifiers. Instead positions are precomputed into
+/// a [`HashMap`] once, when the comparator is built, which takes `O(m)` time and memory, so
+/// every comparison is two hash lookups. Build the comparator once and reuse it for all sorts
+/// with the same `order`. Requires `std` feature.
+///
+/// ```
+/// use ord_by_key::by_position_in;
+///
+/// pub struct Item {
+///     pub id: u32,
+///     pub title: &'static str,
+/// }
+///
+/// let ranking = [30, 10, 20];
+/// let mut results = vec![
+///     Item { id: 10, title: "b" },
+///     Item { id: 99, title: "unranked" },
+///     Item { id: 20, title: "c" },
+///     Item { id: 30, title: "a" },
+/// ];
+///
+/// results.sort_by(by_position_in(&ranking, |item: &Item| item.id));
+///
+/// let titles: Vec<_> = results.iter().map(|item| item.title).collect();
+/// assert_eq!(titles, ["a", "b", "c", "unranked"]);
+/// ```
+pub fn by_position_in<'a, Id, T, K, F>(order: &'a [Id], key: F) -> impl Fn(&T, &T) -> Ordering + 'a
+where
+    Id: Hash + Eq,
+    K: Borrow<Id>,
+    F: Fn(&T) -> K + 'a,
+{
+    let mut positions = HashMap::with_capacity(order.len());
+
+    for (position, id) in order.iter().enumerate() {
+        positions.entry(id).or_insert(position);
+    }
+
+    // Identifiers which are not in `order` get the position after the last one
+    let position = move |value: &T| {
+        positions
+            .get(key(value).borrow())
+            .copied()
+            .unwrap_or(order.len())
+    };
+
+    move |a, b| position(a).cmp(&position(b))
+}
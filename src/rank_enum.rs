@@ -0,0 +1,46 @@
+/// Returns rank of a fieldless enum value by position of its variant in the list, to be used
+/// as a key
+///
+/// `rank_enum!(value => A, B, C)` expands to an exhaustive `match`, so adding a new variant to
+/// the enum fails to compile until it's placed in the ranking, and listing a variant twice is
+/// an error as well. Unlike `value as u8`, the order doesn't depend on declaration order of the
+/// variants. Ranks start at `0` and have type `usize`.
+///
+/// Variants are paths, either qualified (`Tier::Free`) or imported into the scope
+/// (`use Tier::*`). Name which doesn't resolve to a variant is reported as an error, instead of
+/// being treated as a catch-all binding.
+///
+/// ```
+/// use ord_by_key::{ord_eq_by_key_selector, rank_enum};
+///
+/// pub enum Tier {
+///     Enterprise,
+///     Free,
+///     Pro,
+/// }
+///
+/// #[ord_eq_by_key_selector(|u| rank_enum!(u.tier => Tier::Free, Tier::Pro, Tier::Enterprise))]
+/// pub struct User {
+///     pub tier: Tier,
+/// }
+///
+/// assert!(User { tier: Tier::Free } < User { tier: Tier::Pro });
+/// assert!(User { tier: Tier::Enterprise } > User { tier: Tier::Pro });
+/// ```
+#[macro_export]
+macro_rules! rank_enum {
+    ($value:expr => $($variant:path),+ $(,)?) => {
+        $crate::rank_enum!(@arms $value; (); 0usize; $($variant),+)
+    };
+    (@arms $value:expr; ($($arms:tt)*); $rank:expr; $variant:path $(, $rest:path)*) => {
+        $crate::rank_enum!(@arms $value; ($($arms)* $variant => $rank,); $rank + 1; $($rest),*)
+    };
+    (@arms $value:expr; ($($arms:tt)*); $rank:expr;) => {{
+        #[deny(unreachable_patterns)]
+        let rank: usize = match $value {
+            $($arms)*
+        };
+
+        rank
+    }};
+}
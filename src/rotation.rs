@@ -0,0 +1,118 @@
+use core::cmp::Ordering;
+
+/// Key wrapper which orders cyclic sequences by their lexicographically minimal rotation
+///
+/// Sequences which are rotations of each other (e.g. `[3, 1, 2]` and `[1, 2, 3]`) represent
+/// the same cyclic sequence, such as contents of a ring buffer or a circular DNA molecule, so
+/// they are equal. Other sequences are compared by their canonical form: the rotation which is
+/// the smallest in lexicographic order, i.e. `[3, 1, 2]` compares as `[1, 2, 3]`. Sequences of
+/// different lengths are compared by canonical forms too, so a shorter sequence is less only if
+/// its canonical form is a prefix of the other's.
+///
+/// The minimal rotation is found in linear time and without allocation, but it's done on every
+/// comparison, so each comparison costs `O(n)` even if sequences differ in the first element.
+/// For sorting many long sequences it can be faster to store the rotation with
+/// [`CanonicalRotation::start`] in a field (or use `cache` option of the macro).
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::CanonicalRotation;
+///
+/// #[ord_eq_by_key_selector(|r| CanonicalRotation(&r.bases))]
+/// pub struct Ring {
+///     pub bases: Vec<u8>,
+/// }
+///
+/// let ring = |bases: &[u8]| Ring { bases: bases.to_vec() };
+///
+/// // Both are `ATCG` in canonical form
+/// assert!(ring(b"GATC") == ring(b"TCGA"));
+/// // `CTAG` is `AGCT` in canonical form
+/// assert!(ring(b"CTAG") < ring(b"GATC"));
+/// ```
+#[derive(Debug)]
+pub struct CanonicalRotation<'a, T>(pub &'a [T]);
+
+impl<'a, T: Ord> CanonicalRotation<'a, T> {
+    /// Returns index of the element the lexicographically minimal rotation starts with. If the
+    /// sequence is periodic, i.e. has several minimal rotations, the smallest index is returned
+    ///
+    /// ```
+    /// use ord_by_key::CanonicalRotation;
+    ///
+    /// assert_eq!(CanonicalRotation(&[3, 1, 2]).start(), 1);
+    /// assert_eq!(CanonicalRotation(&[2, 1, 2, 1]).start(), 1);
+    /// ```
+    pub fn start(&self) -> usize {
+        let values = self.0;
+        let len = values.len();
+
+        if len == 0 {
+            return 0;
+        }
+
+        let at = |i: usize| &values[i % len];
+
+        // Two candidate starts `i` and `j` are compared element by element, `k` elements are
+        // known to be equal. When candidates differ at offset `k`, neither the larger candidate
+        // nor any start up to `k` elements after it can be minimal, since the rotation from such
+        // a start is greater than the matching rotation from the other candidate
+        let (mut i, mut j, mut k) = (0, 1, 0);
+
+        while i < len && j < len && k < len {
+            match at(i + k).cmp(at(j + k)) {
+                Ordering::Equal => k += 1,
+                Ordering::Greater => {
+                    i += k + 1;
+                    if i == j {
+                        i += 1;
+                    }
+                    k = 0;
+                }
+                Ordering::Less => {
+                    j += k + 1;
+                    if i == j {
+                        j += 1;
+                    }
+                    k = 0;
+                }
+            }
+        }
+
+        i.min(j)
+    }
+
+    /// Returns elements of the minimal rotation
+    fn rotation(&self) -> impl Iterator<Item = &'a T> {
+        let (head, tail) = self.0.split_at(self.start());
+        tail.iter().chain(head)
+    }
+}
+
+impl<T> Clone for CanonicalRotation<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CanonicalRotation<'_, T> {}
+
+impl<T: Ord> Ord for CanonicalRotation<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rotation().cmp(other.rotation())
+    }
+}
+
+impl<T: Ord> PartialOrd for CanonicalRotation<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for CanonicalRotation<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for CanonicalRotation<'_, T> {}
@@ -0,0 +1,75 @@
+/// Integer which supports saturating arithmetic, implemented for all primitive integer types
+///
+/// Used by [`sat_add`] and [`sat_sub`], which make the arithmetic available in key expressions
+/// regardless of the integer type.
+pub trait SaturatingInt: Ord + Copy {
+    /// Same as `saturating_add` of the primitive type
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Same as `saturating_sub` of the primitive type
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_saturating_int {
+    ($($ty:ty),*) => {
+        $(
+            impl SaturatingInt for $ty {
+                fn saturating_add(self, other: Self) -> Self {
+                    <$ty>::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$ty>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Returns `a + b`, clamped to the bounds of the type instead of overflowing, to be used as a key
+///
+/// Key expressions are evaluated on every comparison, so an overflow in a key makes comparison
+/// panic in debug builds and wrap around in release builds, which silently breaks the order.
+/// Saturation keeps the order monotonic: all sums above the maximum compare equal to the
+/// maximum.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|b| ord_by_key::sat_add(b.reserved, b.used))]
+/// pub struct Budget {
+///     pub reserved: u8,
+///     pub used: u8,
+/// }
+///
+/// assert!(Budget { reserved: 200, used: 100 } == Budget { reserved: 255, used: 1 });
+/// assert!(Budget { reserved: 1, used: 1 } < Budget { reserved: 200, used: 100 });
+/// ```
+pub fn sat_add<T: SaturatingInt>(a: T, b: T) -> T {
+    a.saturating_add(b)
+}
+
+/// Returns `a - b`, clamped to the bounds of the type instead of overflowing, to be used as a key
+///
+/// Useful for unsigned scores with penalties, where the penalty can exceed the base: all such
+/// values compare equal to zero. See [`sat_add`] for the reasons to avoid overflow in keys.
+///
+/// ```
+/// use core::cmp::Reverse;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// // Players with higher score go first
+/// #[ord_eq_by_key_selector(|p| Reverse(ord_by_key::sat_sub(p.base, p.penalty)))]
+/// pub struct Player {
+///     pub base: u32,
+///     pub penalty: u32,
+/// }
+///
+/// assert!(Player { base: 10, penalty: 3 } < Player { base: 5, penalty: 1 });
+/// assert!(Player { base: 1, penalty: 5 } == Player { base: 0, penalty: 0 });
+/// ```
+pub fn sat_sub<T: SaturatingInt>(a: T, b: T) -> T {
+    a.saturating_sub(b)
+}
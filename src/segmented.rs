@@ -0,0 +1,211 @@
+use crate::NumericStr;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter;
+
+/// Key which orders structured identifiers, such as invoice or order numbers, segment by segment
+/// according to a pattern
+///
+/// `Segmented(value, pattern)` splits `value` into segments as described by a [`SegmentPattern`],
+/// and compares segments in order: the first differing segment decides. All keys which are
+/// compared with each other should use the same pattern. Comparison doesn't allocate and doesn't
+/// panic, since patterns are validated when they are created.
+///
+/// # Pattern
+/// Pattern is a list of segment types separated by single separator characters, e.g.
+/// `"skip-num-num"` or `"lit/num.num"`. Value is split at the same separator characters in the
+/// same order: each segment ends at the first occurrence of its separator, and the last segment
+/// takes the rest of the value. Segments missing from the value (when it has fewer separators
+/// than the pattern) are empty. Segment types are:
+///
+/// * `num` - compared as a non-negative integer of any length, like [`NumericStr`]: leading zeros
+///   are ignored, and segments which are not numbers go after all numbers
+/// * `lit` - compared as a string
+/// * `skip` - ignored, e.g. for a constant prefix
+///
+/// Any character which is not an ASCII letter is a separator. Dates in `YYYY-MM-DD` format can be
+/// described as `num-num-num`.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::SegmentPattern;
+/// use ord_by_key::Segmented;
+///
+/// const INVOICE_NUMBER: SegmentPattern = SegmentPattern::new("skip-num-num");
+///
+/// // Ordered by year, then by sequence number, the prefix is ignored
+/// #[ord_eq_by_key_selector(|i| Segmented(&i.number, INVOICE_NUMBER))]
+/// pub struct Invoice {
+///     pub number: String,
+/// }
+///
+/// let invoice = |number: &str| Invoice { number: number.to_string() };
+///
+/// assert!(invoice("INV-2023-00950") < invoice("INV-2024-00042"));
+/// assert!(invoice("INV-2024-00042") < invoice("INV-2024-00100"));
+/// assert!(invoice("INV-2024-00042") == invoice("CRN-2024-42"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Segmented<'a>(pub &'a str, pub SegmentPattern<'a>);
+
+/// Validated pattern of [`Segmented`] key, see [`Segmented`] for the pattern syntax
+///
+/// Pattern is checked once, when it is created, so comparison of keys can't fail on it. Declare a
+/// constant with [`SegmentPattern::new`] to have an unknown segment type reported at compile
+/// time, or use [`SegmentPattern::parse`] for patterns known only at run time.
+///
+/// ```compile_fail,E0080
+/// use ord_by_key::SegmentPattern;
+///
+/// // `nmu` is not a segment type, so the constant fails to evaluate
+/// const PATTERN: SegmentPattern = SegmentPattern::new("lit-nmu");
+/// # let _ = PATTERN;
+/// ```
+///
+/// ```
+/// use ord_by_key::SegmentPattern;
+/// use ord_by_key::UnknownSegmentType;
+///
+/// assert!(SegmentPattern::parse("lit-num").is_ok());
+/// assert!(SegmentPattern::parse("lit-nmu").unwrap_err() == UnknownSegmentType("nmu"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SegmentPattern<'p>(&'p str);
+
+impl<'p> SegmentPattern<'p> {
+    /// Creates a pattern, panicking if it has an unknown segment type, which is a compile error
+    /// when the pattern is a constant
+    pub const fn new(pattern: &'p str) -> Self {
+        if unknown_segment_type(pattern.as_bytes()).is_some() {
+            panic!("unknown segment type in pattern");
+        }
+        SegmentPattern(pattern)
+    }
+
+    /// Creates a pattern, returning the first unknown segment type as an error
+    pub fn parse(pattern: &'p str) -> Result<Self, UnknownSegmentType<'p>> {
+        match unknown_segment_type(pattern.as_bytes()) {
+            Some((start, end)) => Err(UnknownSegmentType(&pattern[start..end])),
+            None => Ok(SegmentPattern(pattern)),
+        }
+    }
+}
+
+/// Error of [`SegmentPattern::parse`] with a segment type which is not `num`, `lit` or `skip`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownSegmentType<'p>(pub &'p str);
+
+impl<'p> fmt::Display for UnknownSegmentType<'p> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown segment type `{}` in pattern", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'p> std::error::Error for UnknownSegmentType<'p> {}
+
+/// Byte range of the first segment type in `pattern` which is not `num`, `lit` or `skip`
+const fn unknown_segment_type(pattern: &[u8]) -> Option<(usize, usize)> {
+    let mut start = 0;
+    while start < pattern.len() {
+        let mut end = start;
+        while end < pattern.len() && pattern[end].is_ascii_alphabetic() {
+            end += 1;
+        }
+
+        if !is_name(pattern, start, end, b"num")
+            && !is_name(pattern, start, end, b"lit")
+            && !is_name(pattern, start, end, b"skip")
+        {
+            return Some((start, end));
+        }
+
+        // Skips the separator, including continuation bytes of a non-ASCII character
+        start = end + 1;
+        while start < pattern.len() && pattern[start] & 0xc0 == 0x80 {
+            start += 1;
+        }
+    }
+
+    None
+}
+
+/// Whether `pattern[start..end]` is `name`, slicing is not available in `const fn`
+const fn is_name(pattern: &[u8], start: usize, end: usize, name: &[u8]) -> bool {
+    if end - start != name.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < name.len() {
+        if pattern[start + i] != name[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Compared segment of the value
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Segment<'a> {
+    Num(NumericStr<'a>),
+    Lit(&'a str),
+}
+
+impl<'a> Segmented<'a> {
+    /// Compared segments of the value, `skip` segments are omitted
+    fn segments(&self) -> impl Iterator<Item = Segment<'a>> {
+        let Segmented(mut value, SegmentPattern(mut pattern)) = *self;
+
+        iter::from_fn(move || loop {
+            if pattern.is_empty() {
+                return None;
+            }
+
+            let name_end = pattern
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(pattern.len());
+            let (name, rest) = pattern.split_at(name_end);
+            let separator = rest.chars().next();
+            pattern = separator.map_or("", |c| &rest[c.len_utf8()..]);
+
+            let segment = match separator.and_then(|c| value.find(c).map(|i| (c, i))) {
+                Some((c, i)) => {
+                    let segment = &value[..i];
+                    value = &value[i + c.len_utf8()..];
+                    segment
+                }
+                None => core::mem::take(&mut value),
+            };
+
+            match name {
+                "num" => return Some(Segment::Num(NumericStr(segment))),
+                "lit" => return Some(Segment::Lit(segment)),
+                // `skip`, other segment types are rejected when the pattern is created
+                _ => continue,
+            }
+        })
+    }
+}
+
+impl<'a> Ord for Segmented<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.segments().cmp(other.segments())
+    }
+}
+
+impl<'a> PartialOrd for Segmented<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for Segmented<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Segmented<'a> {}
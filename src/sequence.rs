@@ -0,0 +1,153 @@
+use core::cmp::Ordering;
+
+/// Key returned by [`by_len_then`]
+///
+/// Compares underlying collections by number of elements first (shorter collection is less),
+/// then element-wise in iteration order.
+#[derive(Clone, Copy, Debug)]
+pub struct ByLenThen<C>(C);
+
+/// Creates a key which orders collections by number of elements, then by elements
+///
+/// Works with anything which can be cheaply cloned into an [`ExactSizeIterator`] of [`Ord`]
+/// items, which includes references to all standard collections (`&Vec<T>`, `&[T]`,
+/// `&BTreeMap<K, V>`, `&BTreeSet<T>`, ...). Strings can be compared by their bytes with
+/// `by_len_then(s.as_bytes())`, which orders by [`str::len`] first and then by regular string
+/// order.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use std::collections::BTreeMap;
+///
+/// // Tags with fewer entries go first, maps of the same size are compared by entries
+/// #[ord_eq_by_key_selector(|t| ord_by_key::by_len_then(&t.tags))]
+/// pub struct Tagged {
+///     pub tags: BTreeMap<String, u32>,
+/// }
+/// ```
+pub fn by_len_then<C>(collection: C) -> ByLenThen<C>
+where
+    C: IntoIterator + Clone,
+    C::IntoIter: ExactSizeIterator,
+    C::Item: Ord,
+{
+    ByLenThen(collection)
+}
+
+impl<C> Ord for ByLenThen<C>
+where
+    C: IntoIterator + Clone,
+    C::IntoIter: ExactSizeIterator,
+    C::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let iter_self = self.0.clone().into_iter();
+        let iter_other = other.0.clone().into_iter();
+
+        iter_self
+            .len()
+            .cmp(&iter_other.len())
+            .then_with(|| iter_self.cmp(iter_other))
+    }
+}
+
+impl<C> PartialOrd for ByLenThen<C>
+where
+    C: IntoIterator + Clone,
+    C::IntoIter: ExactSizeIterator,
+    C::Item: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> PartialEq for ByLenThen<C>
+where
+    C: IntoIterator + Clone,
+    C::IntoIter: ExactSizeIterator,
+    C::Item: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C> Eq for ByLenThen<C>
+where
+    C: IntoIterator + Clone,
+    C::IntoIter: ExactSizeIterator,
+    C::Item: Ord,
+{
+}
+
+/// Key which orders iterators lexicographically by their elements, without collecting them
+///
+/// Compares two clones of the iterators in lockstep, like [`Iterator::cmp`]: the first differing
+/// element decides, and if one iterator is a prefix of the other, the shorter one is less.
+/// Comparison stops at the first difference, so for long sequences it's cheaper than collecting
+/// both into a `Vec`. The iterator is cloned on every comparison, so it should be cheap to clone
+/// (e.g. an adaptor over a borrowed slice or string) and must produce the same elements each
+/// time.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// pub struct Sentence {
+///     pub text: String,
+/// }
+///
+/// impl Sentence {
+///     pub fn words(&self) -> impl Iterator<Item = String> + Clone + '_ {
+///         self.text.split_whitespace().map(|w| w.to_lowercase())
+///     }
+/// }
+///
+/// // Sentences are ordered word by word, ignoring case and spacing
+/// #[ord_eq_by_key_selector(|(s)| ord_by_key::Seq(s.words()))]
+/// pub struct ByWords(Sentence);
+///
+/// let sentence = |text: &str| ByWords(Sentence { text: text.to_string() });
+/// assert!(sentence("The  cat") == sentence("the cat"));
+/// assert!(sentence("the cat") < sentence("the cat sat"));
+/// assert!(sentence("the cat sat") < sentence("the dog"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Seq<I>(pub I);
+
+impl<I> Ord for Seq<I>
+where
+    I: Iterator + Clone,
+    I::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.clone().cmp(other.0.clone())
+    }
+}
+
+impl<I> PartialOrd for Seq<I>
+where
+    I: Iterator + Clone,
+    I::Item: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I> PartialEq for Seq<I>
+where
+    I: Iterator + Clone,
+    I::Item: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<I> Eq for Seq<I>
+where
+    I: Iterator + Clone,
+    I::Item: Ord,
+{
+}
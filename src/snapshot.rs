@@ -0,0 +1,44 @@
+use core::cmp::Ordering;
+
+/// Creates a comparator which orders values by a key computed relative to a fixed reference
+///
+/// Keys which depend on the current time, e.g. priority decaying with age, can't be computed by
+/// reading the clock in a key expression: the clock moves during sorting, so the same pair of
+/// values may compare differently, which violates requirements of [`Ord`] and may make sorting
+/// produce inconsistent results or panic. Capture the reference (usually `Instant::now()`) once
+/// and pass it to the key function instead, so all comparisons of one sort see the same time.
+/// Reference can be of any type.
+///
+/// ```
+/// use ord_by_key::StrictF64;
+/// use std::cmp::Reverse;
+/// use std::time::Duration;
+/// use std::time::Instant;
+///
+/// pub struct Item {
+///     pub priority: f64,
+///     pub created: Instant,
+/// }
+///
+/// let freshness = |item: &Item, now: &Instant| {
+///     let age = now.duration_since(item.created).as_secs_f64();
+///     Reverse(StrictF64(item.priority - age * 0.5))
+/// };
+///
+/// let start = Instant::now();
+/// let mut items = vec![
+///     Item { priority: 10.0, created: start },
+///     Item { priority: 9.0, created: start + Duration::from_secs(4) },
+/// ];
+///
+/// // Freshest item goes first
+/// items.sort_by(ord_by_key::at(start + Duration::from_secs(10), freshness));
+/// assert_eq!(items[0].priority, 9.0);
+/// ```
+pub fn at<R, T, K, F>(reference: R, key: F) -> impl Fn(&T, &T) -> Ordering
+where
+    F: Fn(&T, &R) -> K,
+    K: Ord,
+{
+    move |a, b| key(a, &reference).cmp(&key(b, &reference))
+}
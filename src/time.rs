@@ -0,0 +1,51 @@
+use chrono::DateTime;
+use chrono::TimeZone;
+use core::cmp::Ordering;
+
+/// Key which orders [`DateTime`] by its instant in UTC
+///
+/// Events recorded in different time zones have to be ordered by the moment they happened, not
+/// by the local clock: `10:00+02:00` happened before `09:00+00:00`. Ordering by local time
+/// (e.g. by [`DateTime::naive_local`] or by formatted timestamps) is a classic bug, `AsUtc`
+/// makes the intended order explicit in the key expression.
+///
+/// ```
+/// use chrono::DateTime;
+/// use chrono::FixedOffset;
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|e| ord_by_key::AsUtc(e.timestamp))]
+/// pub struct Event {
+///     pub timestamp: DateTime<FixedOffset>,
+/// }
+///
+/// let event = |s: &str| Event { timestamp: DateTime::parse_from_rfc3339(s).unwrap() };
+///
+/// assert!(event("2024-01-01T10:00:00+02:00") < event("2024-01-01T09:00:00+00:00"));
+/// ```
+///
+/// Requires `chrono` feature.
+#[derive(Clone, Debug)]
+pub struct AsUtc<Tz: TimeZone>(pub DateTime<Tz>);
+
+impl<Tz: TimeZone> Copy for AsUtc<Tz> where DateTime<Tz>: Copy {}
+
+impl<Tz: TimeZone> Ord for AsUtc<Tz> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.naive_utc().cmp(&other.0.naive_utc())
+    }
+}
+
+impl<Tz: TimeZone> PartialOrd for AsUtc<Tz> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Tz: TimeZone> PartialEq for AsUtc<Tz> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Tz: TimeZone> Eq for AsUtc<Tz> {}
@@ -0,0 +1,114 @@
+use core::cmp::Ordering;
+use core::time::Duration;
+
+/// Timestamp which can be bucketed by [`TimeBucket`]
+///
+/// Implemented for [`Duration`] (time since an epoch chosen by the caller),
+/// [`SystemTime`](std::time::SystemTime) (with `std` feature) and `chrono::DateTime` (with
+/// `chrono` feature), whose epoch is the Unix epoch, and for references to them.
+pub trait EpochNanos {
+    /// Returns number of nanoseconds since the epoch, negative for timestamps before it
+    fn epoch_nanos(&self) -> i128;
+}
+
+impl EpochNanos for Duration {
+    fn epoch_nanos(&self) -> i128 {
+        self.as_nanos() as i128
+    }
+}
+
+#[cfg(feature = "std")]
+impl EpochNanos for std::time::SystemTime {
+    fn epoch_nanos(&self) -> i128 {
+        match self.duration_since(std::time::UNIX_EPOCH) {
+            Ok(after) => after.epoch_nanos(),
+            Err(before) => -before.duration().epoch_nanos(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> EpochNanos for chrono::DateTime<Tz> {
+    fn epoch_nanos(&self) -> i128 {
+        // Seconds can be negative, sub-second nanoseconds are always counted forward from them
+        self.timestamp() as i128 * 1_000_000_000 + self.timestamp_subsec_nanos() as i128
+    }
+}
+
+impl<T: EpochNanos + ?Sized> EpochNanos for &T {
+    fn epoch_nanos(&self) -> i128 {
+        (**self).epoch_nanos()
+    }
+}
+
+/// Key which orders timestamps by buckets of `window` duration, so timestamps within the same
+/// bucket are equal
+///
+/// This is the same idea as [`NumericF64Eps`](crate::NumericF64Eps) applied to time, e.g. to
+/// deduplicate near-simultaneous events. Timestamps are not compared with each other within a
+/// tolerance (which wouldn't be transitive: `a ~ b` and `b ~ c` don't imply `a ~ c`), but
+/// divided into buckets: bucket is the number of nanoseconds since the epoch divided by the
+/// number of nanoseconds in `window`, rounded down (also for timestamps before the epoch). So
+/// [`Eq`] and [`Ord`] are transitive, but timestamps which are closer than `window` to each
+/// other can still fall into neighbouring buckets, e.g. `0.999s` and `1.001s` with a window of
+/// `1s`.
+///
+/// Bucketing is epoch-relative: bucket boundaries are multiples of `window` since the epoch of
+/// the timestamp, which is the Unix epoch for [`SystemTime`](std::time::SystemTime) and
+/// `chrono::DateTime`, so 1-second buckets start at whole seconds of UTC, and 1-day buckets start
+/// at UTC midnight. [`Instant`](std::time::Instant) has no epoch, wrap
+/// `instant.duration_since(start)` instead, whose buckets start at `start`. Window shorter than
+/// a nanosecond is treated as a nanosecond. All keys which are compared with each other should
+/// use the same window.
+///
+/// ```
+/// use core::time::Duration;
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::TimeBucket;
+///
+/// // Events of the same source within the same second are duplicates
+/// #[ord_eq_by_key_selector(|e| e.source, TimeBucket(e.at, Duration::from_secs(1)))]
+/// pub struct Event {
+///     pub source: &'static str,
+///     pub at: Duration,
+/// }
+///
+/// let event = |source, millis| Event { source, at: Duration::from_millis(millis) };
+/// let mut events = vec![event("a", 1_100), event("b", 1_200), event("a", 1_900), event("a", 2_000)];
+///
+/// events.sort();
+/// events.dedup();
+///
+/// let events: Vec<_> = events.iter().map(|e| (e.source, e.at.as_millis())).collect();
+/// assert_eq!(events, [("a", 1_100), ("a", 2_000), ("b", 1_200)]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TimeBucket<T>(pub T, pub Duration);
+
+impl<T: EpochNanos> TimeBucket<T> {
+    /// Number of the bucket of the timestamp since the epoch
+    fn bucket(&self) -> i128 {
+        let window = self.1.epoch_nanos().max(1);
+        self.0.epoch_nanos().div_euclid(window)
+    }
+}
+
+impl<T: EpochNanos> Ord for TimeBucket<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bucket().cmp(&other.bucket())
+    }
+}
+
+impl<T: EpochNanos> PartialOrd for TimeBucket<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: EpochNanos> PartialEq for TimeBucket<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: EpochNanos> Eq for TimeBucket<T> {}
@@ -0,0 +1,57 @@
+use core::cmp::Ordering;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Key which orders strings by extended grapheme clusters
+///
+/// Requires `unicode` feature.
+///
+/// Strings are split into grapheme clusters (user-perceived characters, such as emoji ZWJ
+/// sequences, flags or letters with combining accents) and compared cluster by cluster, so each
+/// cluster is treated as a single unit. Cluster which is a prefix of another cluster is less,
+/// e.g. `e` followed by a character which doesn't combine with it goes before `e\u{301}` (`é`
+/// with a combining accent), while comparison by `char` would let the character after `e` decide.
+/// Clusters with the same base and different combining marks are compared by the marks, e.g.
+/// `e\u{302}` (`ê`) goes after `e\u{301}`.
+///
+/// Note that clusters themselves are still compared by their bytes lexically, which is not a
+/// locale-aware collation: `é` precomposed (`\u{e9}`) and decomposed (`e\u{301}`) are different
+/// clusters, and uppercase letters go before lowercase ones.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|m| ord_by_key::ByGrapheme(&m.text))]
+/// pub struct Message {
+///     pub text: String,
+/// }
+///
+/// let message = |text: &str| Message { text: text.to_string() };
+///
+/// // `e` with combining accent is a single cluster which goes after `e`
+/// assert!(message("e\u{1F600}") < message("e\u{301}"));
+///
+/// // A different combining accent makes a different cluster, compared by the accent
+/// assert!(message("e\u{302}") > message("e\u{301}"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ByGrapheme<'a>(pub &'a str);
+
+impl<'a> Ord for ByGrapheme<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.graphemes(true).cmp(other.0.graphemes(true))
+    }
+}
+
+impl<'a> PartialOrd for ByGrapheme<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for ByGrapheme<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<'a> Eq for ByGrapheme<'a> {}
@@ -0,0 +1,46 @@
+use core::cmp::Ordering;
+
+/// Key which orders strings by their UTF-16 code units, same as JavaScript
+///
+/// [`str`] is ordered by Unicode scalar values (which is the same as order of UTF-8 bytes), while
+/// JavaScript compares strings by UTF-16 code units. Orders are the same for characters of the
+/// Basic Multilingual Plane, but differ for characters above it (e.g. emoji), which are encoded
+/// as surrogate pairs starting with `0xD800..=0xDBFF` and go before characters `U+E000..=U+FFFF`.
+/// Code units are produced lazily by [`str::encode_utf16`], so no allocation is made.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+///
+/// #[ord_eq_by_key_selector(|l| ord_by_key::Utf16Order(&l.text))]
+/// pub struct Label {
+///     pub text: String,
+/// }
+///
+/// let label = |text: &str| Label { text: text.to_string() };
+///
+/// // U+1F600 is encoded as 0xD83D 0xDE00, which is less than 0xFF61
+/// assert!(label("\u{1F600}") < label("\u{FF61}"));
+/// assert!("\u{1F600}" > "\u{FF61}");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Utf16Order<'a>(pub &'a str);
+
+impl<'a> Ord for Utf16Order<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.encode_utf16().cmp(other.0.encode_utf16())
+    }
+}
+
+impl<'a> PartialOrd for Utf16Order<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for Utf16Order<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Utf16Order<'a> {}
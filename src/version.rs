@@ -0,0 +1,86 @@
+use core::cmp::Ordering;
+
+/// Key which orders loosely formatted version and build identifiers by their numeric groups
+///
+/// Every maximal run of ASCII digits is a numeric group, regardless of the characters around it,
+/// so `1.2.3-4_5`, `1-2-3.4.5` and `v1.2.3+4.5` all have groups `1, 2, 3, 4, 5`. Groups are
+/// compared as non-negative integers of any length (leading zeros are ignored), and missing
+/// trailing groups are treated as zero, so `1.2 == 1.2.0` and `1.10 > 1.9`.
+///
+/// When all numeric groups are equal, runs of other letters (alphanumeric characters except
+/// ASCII digits) are compared as a sequence of strings, e.g. `1.0a < 1.0b`. All other characters
+/// are separators and are ignored. This is more permissive than semantic versioning and doesn't
+/// follow its precedence rules: numeric groups always decide first, so `1.0-rc1 < 1.0-beta2`, and
+/// a version without letters goes before the same version with them, so `1.0 < 1.0-rc`.
+/// Comparison doesn't allocate.
+///
+/// ```
+/// use ord_by_key::ord_eq_by_key_selector;
+/// use ord_by_key::VersionGroups;
+///
+/// #[ord_eq_by_key_selector(|b| VersionGroups(&b.id))]
+/// pub struct Build {
+///     pub id: String,
+/// }
+///
+/// let build = |id: &str| Build { id: id.to_string() };
+///
+/// assert!(build("1.2.3-4_5") == build("1-2-3.4.5"));
+/// assert!(build("1.2") == build("1.2.0"));
+/// assert!(build("1.9") < build("1.10"));
+/// assert!(build("2.0a") < build("2.0b"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct VersionGroups<'a>(pub &'a str);
+
+impl<'a> VersionGroups<'a> {
+    /// Numeric groups without leading zeros
+    fn numbers(&self) -> impl Iterator<Item = &'a str> {
+        self.0
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|run| !run.is_empty())
+            .map(|run| run.trim_start_matches('0'))
+    }
+
+    /// Runs of letters between numeric groups and separators
+    fn letters(&self) -> impl Iterator<Item = &'a str> {
+        self.0
+            .split(|c: char| !c.is_alphanumeric() || c.is_ascii_digit())
+            .filter(|run| !run.is_empty())
+    }
+}
+
+impl<'a> Ord for VersionGroups<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numbers(), other.numbers());
+
+        loop {
+            // Zero without leading zeros is empty, and stands for a missing group
+            let (x, y) = match (a.next(), b.next()) {
+                (None, None) => break,
+                (x, y) => (x.unwrap_or(""), y.unwrap_or("")),
+            };
+
+            let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        self.letters().cmp(other.letters())
+    }
+}
+
+impl<'a> PartialOrd for VersionGroups<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for VersionGroups<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for VersionGroups<'a> {}
@@ -0,0 +1,33 @@
+//! Derive macro consuming `Ord` of the type, used by tests of interaction of
+//! `ord_eq_by_key_selector` with other macros
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+/// Generates `fn sort_all(values: &mut [Self])` which sorts values with [`slice::sort`], and
+/// `fn sorted_set(values) -> BTreeSet<Self>`, so the type has to implement [`Ord`]
+#[proc_macro_derive(Sorted)]
+pub fn derive_sorted(item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let result = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Sorts values in place
+            pub fn sort_all(values: &mut [Self]) {
+                values.sort();
+            }
+
+            /// Collects values into an ordered set
+            pub fn sorted_set<I: IntoIterator<Item = Self>>(
+                values: I,
+            ) -> ::std::collections::BTreeSet<Self> {
+                values.into_iter().collect()
+            }
+        }
+    };
+
+    result.into()
+}
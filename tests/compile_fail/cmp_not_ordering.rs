@@ -0,0 +1,9 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|a, b| cmp: a.score < b.score, a.id)]
+pub struct Player {
+    pub id: u32,
+    pub score: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(
+    ord = |p| p.id;
+    eq = |p| p.weight;
+    unsafe_inconsistent)]
+pub struct Parcel {
+    pub id: u32,
+    pub weight: f32,
+}
+
+fn main() {}
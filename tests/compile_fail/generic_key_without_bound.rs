@@ -0,0 +1,6 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|(s, t)| s, t)]
+pub struct Entry<'a, T>(&'a str, T);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ord_by_key::ord_by_key_advanced;
+
+#[ord_by_key_advanced(eq = |m| m.id; hash = |m| m.id, m.weight)]
+pub struct Measurement {
+    pub id: u32,
+    pub weight: f32,
+}
+
+fn main() {}
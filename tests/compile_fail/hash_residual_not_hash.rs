@@ -0,0 +1,10 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|m| m.id; hash_residual = [unit, weight])]
+pub struct Measurement {
+    pub id: u32,
+    pub unit: &'static str,
+    pub weight: f64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|p| &p.name, p.score)]
+pub struct Player {
+    pub name: String,
+    pub score: f64,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use ord_by_key::{ord_eq_by_key_selector, rank_enum};
+
+pub enum Tier {
+    Free,
+    Pro,
+    Enterprise,
+}
+
+#[ord_eq_by_key_selector(|u| rank_enum!(u.tier => Tier::Free, Tier::Pro))]
+pub struct User {
+    pub tier: Tier,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use ord_by_key::ord_eq_by_key_selector;
+
+#[ord_eq_by_key_selector(|p|)]
+pub struct Point {
+    pub x: i32,
+}
+
+#[ord_eq_by_key_selector(|s|)]
+pub enum Shape {
+    Empty,
+    Circle(u32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ord_by_key::ord_eq_by_key_selector;
+use std::rc::Rc;
+
+#[ord_eq_by_key_selector(|e| *e.score; gen_par_sort)]
+pub struct Entry {
+    pub score: Rc<u8>,
+}
+
+fn main() {}
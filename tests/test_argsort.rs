@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::argsort;
+    use ord_by_key::argsort_by_key;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|t| t.priority)]
+    #[derive(Debug)]
+    pub struct Task {
+        pub priority: u32,
+        pub id: usize,
+    }
+
+    fn tasks(priorities: &[u32]) -> Vec<Task> {
+        priorities
+            .iter()
+            .enumerate()
+            .map(|(id, &priority)| Task { priority, id })
+            .collect()
+    }
+
+    fn is_permutation(indices: &[usize], len: usize) -> bool {
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+        sorted == (0..len).collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn test_sorted_order() {
+        let values = tasks(&[5, 3, 9, 1, 3, 7, 5]);
+        let order = argsort(&values);
+
+        assert!(is_permutation(&order, values.len()));
+        assert!(order.windows(2).all(|w| values[w[0]] <= values[w[1]]));
+        assert!(order == [3, 1, 4, 0, 6, 5, 2]);
+    }
+
+    #[test]
+    fn test_stable() {
+        let values = tasks(&[2, 1, 2, 1, 2, 1]);
+        let order = argsort(&values);
+
+        // Indices of equal values keep ascending order
+        let ids: Vec<_> = order.iter().map(|&i| values[i].id).collect();
+        assert!(ids == [1, 3, 5, 0, 2, 4]);
+
+        // Same as stable sorting of the values themselves
+        let mut sorted = tasks(&[2, 1, 2, 1, 2, 1]);
+        sorted.sort();
+        assert!(sorted.iter().map(|t| t.id).collect::<Vec<_>>() == ids);
+    }
+
+    #[test]
+    fn test_parallel_arrays() {
+        let keys = [30, 10, 20, 10];
+        let names = ["d", "a", "c", "b"];
+        let weights = [0.3, 0.1, 0.2, 0.15];
+
+        let order = argsort(&keys);
+        let names: Vec<_> = order.iter().map(|&i| names[i]).collect();
+        let weights: Vec<_> = order.iter().map(|&i| weights[i]).collect();
+
+        assert!(names == ["a", "b", "c", "d"]);
+        assert!(weights == [0.1, 0.15, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn test_by_key() {
+        // Floats are not `Ord`, order by a key of their bits
+        let values = [2.5f64, -1.0, 0.5, 2.5, 0.0];
+        let order = argsort_by_key(&values, |v| ord_by_key::TotalF64(*v));
+
+        assert!(is_permutation(&order, values.len()));
+        assert!(order == [1, 4, 2, 0, 3]);
+
+        let words = ["pear", "fig", "apple", "kiwi"];
+        assert!(argsort_by_key(&words, |w| w.len()) == [1, 0, 3, 2]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(argsort::<u32>(&[]).is_empty());
+        assert!(argsort_by_key(&[] as &[f64], |v| ord_by_key::TotalF64(*v)).is_empty());
+        assert!(argsort(&[1]) == [0]);
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::cell::Cell as Counter;
+
+    thread_local! {
+        static COMPARISONS: Counter<usize> = const { Counter::new(0) };
+    }
+
+    fn comparisons() -> usize {
+        COMPARISONS.with(|c| c.get())
+    }
+
+    /// Cell of the board which counts comparisons of cells
+    #[derive(Clone, Copy, Debug, Eq)]
+    pub struct Cell {
+        pub piece: u8,
+    }
+
+    impl PartialEq for Cell {
+        fn eq(&self, other: &Self) -> bool {
+            COMPARISONS.with(|c| c.set(c.get() + 1));
+            self.piece == other.piece
+        }
+    }
+
+    impl Ord for Cell {
+        fn cmp(&self, other: &Self) -> Ordering {
+            COMPARISONS.with(|c| c.set(c.get() + 1));
+            self.piece.cmp(&other.piece)
+        }
+    }
+
+    impl PartialOrd for Cell {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[ord_eq_by_key_selector(|b| &b.cells)]
+    pub struct Board {
+        pub cells: [Cell; 64],
+    }
+
+    #[ord_eq_by_key_selector(|(cells)| &cells[..])]
+    pub struct Large([u8; 1000]);
+
+    fn board(changes: &[(usize, u8)]) -> Board {
+        let mut cells = [Cell { piece: 0 }; 64];
+
+        for &(index, piece) in changes {
+            cells[index].piece = piece;
+        }
+
+        Board { cells }
+    }
+
+    #[test]
+    fn test_element_wise() {
+        assert!(board(&[]).cmp(&board(&[])) == Ordering::Equal);
+        assert!(board(&[(63, 1)]).cmp(&board(&[])) == Ordering::Greater);
+        assert!(board(&[(0, 1)]).cmp(&board(&[(1, 2)])) == Ordering::Greater);
+        assert!(board(&[(5, 1)]) == board(&[(5, 1)]));
+        assert!(board(&[(5, 1)]) != board(&[(5, 2)]));
+    }
+
+    #[test]
+    fn test_short_circuit() {
+        let a = board(&[(0, 1)]);
+        let b = board(&[(0, 2)]);
+
+        let before = comparisons();
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert_eq!(comparisons() - before, 1);
+
+        let before = comparisons();
+        assert!(a != b);
+        assert_eq!(comparisons() - before, 1);
+
+        let before = comparisons();
+        assert!(a == board(&[(0, 1)]));
+        assert_eq!(comparisons() - before, 64);
+    }
+
+    #[test]
+    fn test_large_array() {
+        let mut a = Large([0; 1000]);
+        let b = Large([0; 1000]);
+
+        assert!(a == b);
+        a.0[999] = 1;
+        assert!(a.cmp(&b) == Ordering::Greater);
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "chrono")]
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use chrono::DateTime;
+    use chrono::FixedOffset;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::AsUtc;
+
+    #[ord_eq_by_key_selector(|e| AsUtc(e.timestamp))]
+    #[derive(Debug)]
+    pub struct Event {
+        pub name: &'static str,
+        pub timestamp: DateTime<FixedOffset>,
+    }
+
+    fn event(name: &'static str, timestamp: &str) -> Event {
+        Event {
+            name,
+            timestamp: DateTime::parse_from_rfc3339(timestamp).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_different_offsets() {
+        let berlin = event("berlin", "2024-03-01T10:00:00+02:00");
+        let london = event("london", "2024-03-01T09:00:00+00:00");
+
+        // Naive local time says London happened first
+        assert!(london.timestamp.naive_local() < berlin.timestamp.naive_local());
+
+        // but Berlin event was at 08:00 UTC
+        assert!(berlin.cmp(&london) == Ordering::Less);
+
+        let mut events = [
+            london,
+            event("tokyo", "2024-03-01T16:30:00+09:00"),
+            berlin,
+            event("new york", "2024-03-01T02:00:00-05:00"),
+        ];
+        events.sort();
+
+        let names: Vec<_> = events.iter().map(|e| e.name).collect();
+        assert_eq!(names, ["new york", "tokyo", "berlin", "london"]);
+    }
+
+    #[test]
+    fn test_same_instant() {
+        let a = event("a", "2024-03-01T10:00:00+02:00");
+        let b = event("b", "2024-03-01T08:00:00Z");
+
+        assert!(a == b);
+        assert!(a.timestamp.offset() != b.timestamp.offset());
+    }
+
+    #[test]
+    fn test_utc() {
+        let early = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let late = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 1).unwrap();
+
+        assert!(AsUtc(early).cmp(&AsUtc(late)) == Ordering::Less);
+        assert!(AsUtc(late) == AsUtc(late));
+    }
+}
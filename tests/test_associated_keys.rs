@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    pub trait Keyed {
+        type Key: Ord;
+
+        fn key(&self) -> Self::Key;
+    }
+
+    /// Associated type without `Ord` bound in the trait
+    pub trait Ranked {
+        type Rank;
+
+        fn rank(&self) -> Self::Rank;
+    }
+
+    #[ord_eq_by_key_selector(|(w)| w.key())]
+    pub struct Wrapper<T: Keyed>(T);
+
+    #[ord_eq_by_key_selector(|r| r.item.rank(), &r.name)]
+    pub struct ByRank<T>
+    where
+        T: Ranked,
+        T::Rank: Ord,
+    {
+        pub item: T,
+        pub name: &'static str,
+    }
+
+    /// Key borrowing from the value through a generic associated type
+    pub trait Named {
+        type Name<'a>: Ord
+        where
+            Self: 'a;
+
+        fn name(&self) -> Self::Name<'_>;
+    }
+
+    #[ord_eq_by_key_selector(|(n)| n.name())]
+    pub struct ByName<T: Named>(T);
+
+    pub struct User {
+        pub id: u32,
+        pub login: String,
+    }
+
+    impl Keyed for User {
+        type Key = Reverse<u32>;
+
+        fn key(&self) -> Reverse<u32> {
+            Reverse(self.id)
+        }
+    }
+
+    impl Ranked for User {
+        type Rank = usize;
+
+        fn rank(&self) -> usize {
+            self.login.len()
+        }
+    }
+
+    impl Named for User {
+        type Name<'a> = &'a str;
+
+        fn name(&self) -> &str {
+            &self.login
+        }
+    }
+
+    fn user(id: u32, login: &str) -> User {
+        User {
+            id,
+            login: login.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_associated_key() {
+        assert!(Wrapper(user(1, "a")).cmp(&Wrapper(user(2, "a"))) == Ordering::Greater);
+        assert!(Wrapper(user(1, "a")) == Wrapper(user(1, "b")));
+
+        let mut users = [
+            Wrapper(user(1, "a")),
+            Wrapper(user(3, "b")),
+            Wrapper(user(2, "c")),
+        ];
+        users.sort();
+
+        let ids: Vec<_> = users.iter().map(|w| w.0.id).collect();
+        assert_eq!(ids, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_bound_in_where_clause() {
+        let by_rank = |login, name| ByRank {
+            item: user(0, login),
+            name,
+        };
+
+        assert!(by_rank("ab", "z").cmp(&by_rank("abc", "a")) == Ordering::Less);
+        assert!(by_rank("ab", "a").cmp(&by_rank("cd", "b")) == Ordering::Less);
+        assert!(by_rank("ab", "a") == by_rank("cd", "a"));
+    }
+
+    #[test]
+    fn test_generic_associated_type() {
+        assert!(ByName(user(2, "alice")).cmp(&ByName(user(1, "bob"))) == Ordering::Less);
+        assert!(ByName(user(2, "bob")) == ByName(user(1, "bob")));
+    }
+}
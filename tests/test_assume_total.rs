@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::assume_total;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    /// Foreign type which is only `PartialOrd`
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    pub struct Celsius(pub f32);
+
+    #[ord_eq_by_key_selector(|r| assume_total(r.temperature), r.id)]
+    #[derive(Debug)]
+    pub struct Reading {
+        pub temperature: Celsius,
+        pub id: u32,
+    }
+
+    fn reading(temperature: f32, id: u32) -> Reading {
+        Reading {
+            temperature: Celsius(temperature),
+            id,
+        }
+    }
+
+    #[test]
+    fn test_order() {
+        assert!(reading(-5.0, 1).cmp(&reading(20.0, 0)) == Ordering::Less);
+        assert!(reading(1.0, 1).cmp(&reading(1.0, 0)) == Ordering::Greater);
+        assert!(reading(0.0, 1) == reading(-0.0, 1));
+        assert!(assume_total("a").cmp(&assume_total("b")) == Ordering::Less);
+
+        let mut readings = [reading(3.0, 0), reading(-1.0, 1), reading(2.5, 2)];
+        readings.sort();
+
+        let ids: Vec<_> = readings.iter().map(|r| r.id).collect();
+        assert_eq!(ids, [1, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "incomparable values in `assume_total` key")]
+    fn test_incomparable_panics() {
+        let _ = reading(f32::NAN, 0).cmp(&reading(1.0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "incomparable values in `assume_total` key")]
+    fn test_incomparable_eq_panics() {
+        let _ = reading(f32::NAN, 0) == reading(f32::NAN, 0);
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::bit_field;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    /// Bits 0-3 are priority, bits 4-7 are category
+    #[ord_eq_by_key_selector(|f|
+        ord_by_key::bit_field(f.packed, 4, 7),
+        ord_by_key::bit_field(f.packed, 0, 3))]
+    #[derive(Debug)]
+    pub struct Flags {
+        pub packed: u32,
+    }
+
+    fn flags(category: u32, priority: u32) -> Flags {
+        Flags {
+            packed: category << 4 | priority,
+        }
+    }
+
+    #[test]
+    fn test_key_expression() {
+        assert!(flags(1, 15).cmp(&flags(2, 0)) == Ordering::Less);
+        assert!(flags(2, 1).cmp(&flags(2, 2)) == Ordering::Less);
+        assert!(flags(3, 3).cmp(&flags(3, 3)) == Ordering::Equal);
+
+        // Bits outside of both fields are ignored
+        assert!(
+            Flags {
+                packed: 0xff00_0012
+            } == flags(1, 2)
+        );
+
+        let mut all = [flags(2, 0), flags(0, 9), flags(1, 15), flags(0, 1)];
+        all.sort();
+
+        let packed: Vec<_> = all.iter().map(|f| f.packed).collect();
+        assert_eq!(packed, [0x01, 0x09, 0x1f, 0x20]);
+    }
+
+    #[test]
+    fn test_field_boundaries() {
+        let value: u32 = 0b1011_0110;
+
+        assert_eq!(bit_field(value, 0, 0), 0);
+        assert_eq!(bit_field(value, 1, 1), 1);
+        assert_eq!(bit_field(value, 0, 3), 0b0110);
+        assert_eq!(bit_field(value, 4, 7), 0b1011);
+        // Field spanning the boundary of the nibbles
+        assert_eq!(bit_field(value, 2, 5), 0b1101);
+        assert_eq!(bit_field(value, 8, 31), 0);
+
+        assert_eq!(bit_field(0x8000_0000u32, 31, 31), 1);
+        assert_eq!(bit_field(u64::MAX, 60, 63), 0xf);
+        assert_eq!(bit_field(u128::MAX, 127, 127), 1);
+    }
+
+    #[test]
+    fn test_full_width() {
+        assert_eq!(bit_field(0xa5u8, 0, 7), 0xa5);
+        assert_eq!(bit_field(u16::MAX, 0, 15), u16::MAX);
+        assert_eq!(bit_field(0xdead_beefu32, 0, 31), 0xdead_beef);
+        assert_eq!(bit_field(u64::MAX - 1, 0, 63), u64::MAX - 1);
+        assert_eq!(bit_field(u128::MAX, 0, 127), u128::MAX);
+        assert_eq!(bit_field(usize::MAX, 0, usize::BITS - 1), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit field [3, 2] is out of range of 32-bit integer")]
+    fn test_reversed_bounds() {
+        bit_field(0u32, 3, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit field [4, 8] is out of range of 8-bit integer")]
+    fn test_bounds_past_width() {
+        bit_field(0u8, 4, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit field [64, 64] is out of range of 64-bit integer")]
+    fn test_lo_past_width() {
+        bit_field(0u64, 64, 64);
+    }
+}
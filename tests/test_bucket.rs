@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::bucket;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    const EDGES: [u32; 3] = [100, 300, 1000];
+
+    #[ord_eq_by_key_selector(|m| bucket(m.latency_ms, &EDGES), m.latency_ms)]
+    #[derive(Debug)]
+    pub struct Metric {
+        pub latency_ms: u32,
+    }
+
+    #[ord_eq_by_key_selector(|m| bucket(m.latency_ms, &EDGES))]
+    pub struct Band {
+        pub latency_ms: u32,
+    }
+
+    #[test]
+    fn test_boundaries() {
+        assert!(bucket(0, &EDGES) == 0);
+        assert!(bucket(99, &EDGES) == 0);
+        assert!(bucket(100, &EDGES) == 1);
+        assert!(bucket(299, &EDGES) == 1);
+        assert!(bucket(300, &EDGES) == 2);
+        assert!(bucket(999, &EDGES) == 2);
+        assert!(bucket(1000, &EDGES) == 3);
+        assert!(bucket(u32::MAX, &EDGES) == 3);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert!(bucket(5, &[]) == 0);
+        assert!(bucket(5, &[5]) == 1);
+        assert!(bucket(4, &[5]) == 0);
+
+        // Equal edges make an empty band
+        assert!(bucket(10, &[10, 10, 20]) == 2);
+        assert!(bucket(9, &[10, 10, 20]) == 0);
+    }
+
+    #[test]
+    fn test_floats() {
+        let edges = [0.25, 0.5, 0.75];
+
+        assert!(bucket(-1.0, &edges) == 0);
+        assert!(bucket(0.25, &edges) == 1);
+        assert!(bucket(0.7, &edges) == 2);
+        assert!(bucket(f64::INFINITY, &edges) == 3);
+        assert!(bucket(f64::NAN, &edges) == 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "edges of buckets have to be sorted")]
+    fn test_unsorted_edges() {
+        let _ = bucket(1, &[300, 100]);
+    }
+
+    #[test]
+    fn test_key() {
+        let metric = |latency_ms| Metric { latency_ms };
+
+        assert!(metric(250).cmp(&metric(100)) == Ordering::Greater);
+        assert!(metric(99).cmp(&metric(100)) == Ordering::Less);
+
+        let mut metrics: Vec<_> = [1200, 50, 300, 120, 999, 0]
+            .iter()
+            .map(|&l| metric(l))
+            .collect();
+        metrics.sort();
+
+        let latencies: Vec<_> = metrics.iter().map(|m| m.latency_ms).collect();
+        assert!(latencies == [0, 50, 120, 300, 999, 1200]);
+
+        // Only the band is compared
+        assert!(Band { latency_ms: 300 } == Band { latency_ms: 999 });
+        assert!(Band { latency_ms: 299 } < Band { latency_ms: 300 });
+    }
+}
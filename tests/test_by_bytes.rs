@@ -0,0 +1,77 @@
+#![cfg(feature = "bytemuck")]
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use bytemuck::Pod;
+    use bytemuck::Zeroable;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::ByBytes;
+
+    /// Layout where byte order matches field order: `repr(C)`, big-endian numbers
+    #[ord_eq_by_key_selector(|r| ByBytes(*r))]
+    #[derive(Clone, Copy, Debug, Pod, Zeroable)]
+    #[repr(C)]
+    pub struct Record {
+        pub partition: [u8; 2],
+        pub offset: [u8; 8],
+    }
+
+    /// Same record with field-by-field comparison
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Fields {
+        pub partition: u16,
+        pub offset: u64,
+    }
+
+    fn record(partition: u16, offset: u64) -> Record {
+        Record {
+            partition: partition.to_be_bytes(),
+            offset: offset.to_be_bytes(),
+        }
+    }
+
+    #[test]
+    fn test_big_endian_layout_matches_fields() {
+        let values = [(0, 0), (0, 255), (0, 256), (1, 0), (1, 1 << 40), (256, 1)];
+
+        for &(ap, ao) in &values {
+            for &(bp, bo) in &values {
+                let fields = |partition, offset| Fields { partition, offset };
+
+                assert_eq!(
+                    record(ap, ao).cmp(&record(bp, bo)),
+                    fields(ap, ao).cmp(&fields(bp, bo))
+                );
+                assert_eq!(
+                    record(ap, ao) == record(bp, bo),
+                    fields(ap, ao) == fields(bp, bo)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_native_integers() {
+        // Bytes of native integers are compared in memory order, which depends on the target
+        if cfg!(target_endian = "little") {
+            assert!(ByBytes(256u32).cmp(&ByBytes(1u32)) == Ordering::Less);
+        } else {
+            assert!(ByBytes(256u32).cmp(&ByBytes(1u32)) == Ordering::Greater);
+        }
+
+        // Single bytes are always in numeric order
+        assert!(ByBytes(1u8).cmp(&ByBytes(2u8)) == Ordering::Less);
+        assert!(ByBytes([1u8, 2]).cmp(&ByBytes([1u8, 3])) == Ordering::Less);
+
+        // Sign bit makes negative numbers greater than positive ones
+        assert!(ByBytes(-1i8).cmp(&ByBytes(1i8)) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_bitwise_equality() {
+        assert!(ByBytes(-0.0f64) != ByBytes(0.0f64));
+        assert!(ByBytes(f64::NAN) == ByBytes(f64::NAN));
+        assert!(ByBytes(1.5f32) == ByBytes(1.5f32));
+    }
+}
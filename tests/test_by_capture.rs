@@ -0,0 +1,104 @@
+#![cfg(feature = "regex")]
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ByCapture;
+    use regex::Regex;
+
+    #[test]
+    fn test_numeric() {
+        let cmp = ByCapture::new(r"id=(\w+)", 1).numeric();
+
+        assert!(cmp("id=9", "id=10") == Ordering::Less);
+        assert!(cmp("id=007", "id=7") == Ordering::Equal);
+        assert!(cmp("id=0", "id=000") == Ordering::Equal);
+
+        // Longer than any integer type
+        assert!(
+            cmp(
+                "id=100000000000000000000000000000000",
+                "id=99999999999999999999999999999999"
+            ) == Ordering::Greater
+        );
+
+        // Non-numeric captures go after numbers, missing captures go first
+        assert!(cmp("id=abc", "id=999") == Ordering::Greater);
+        assert!(cmp("id=abc", "id=abd") == Ordering::Less);
+        assert!(cmp("none", "id=0") == Ordering::Less);
+        assert!(cmp("none", "other") == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_lexical() {
+        let cmp = ByCapture::new(r"id=(\d+)", 1).lexical();
+
+        assert!(cmp("id=9", "id=10") == Ordering::Greater);
+        assert!(cmp("id=007", "id=7") == Ordering::Less);
+        assert!(cmp("none", "id=0") == Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut lines: Vec<String> = ["b id=20", "a id=3", "c", "d id=100"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        lines.sort_by(ByCapture::new(r"id=(\d+)", 1).numeric());
+        assert_eq!(lines, ["c", "a id=3", "b id=20", "d id=100"]);
+
+        lines.sort_by(ByCapture::new(r"id=(\d+)", 1).lexical());
+        assert_eq!(lines, ["c", "d id=100", "b id=20", "a id=3"]);
+    }
+
+    #[test]
+    fn test_groups() {
+        // Optional group which didn't participate in the match
+        let by_minor = ByCapture::new(r"v(\d+)(?:\.(\d+))?", 2);
+        assert_eq!(by_minor.capture("v1.5"), Some("5"));
+        assert_eq!(by_minor.capture("v1"), None);
+
+        let cmp = by_minor.numeric();
+        assert!(cmp("v1", "v1.0") == Ordering::Less);
+
+        // Whole match
+        let whole = ByCapture::from_regex(Regex::new(r"\d+").unwrap(), 0);
+        assert_eq!(whole.capture("abc 42 7"), Some("42"));
+        assert!(whole.numeric()("x 8", "y 12") == Ordering::Less);
+    }
+
+    #[test]
+    fn test_struct_field() {
+        #[derive(Debug)]
+        struct Event {
+            message: &'static str,
+        }
+
+        let mut events = [
+            Event {
+                message: "job=12 done",
+            },
+            Event {
+                message: "job=2 done",
+            },
+        ];
+
+        let cmp = ByCapture::new(r"job=(\d+)", 1).numeric();
+        events.sort_by(|a, b| cmp(a.message, b.message));
+
+        assert_eq!(events[0].message, "job=2 done");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regular expression")]
+    fn test_invalid_pattern() {
+        ByCapture::new(r"id=(\d+", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't have capture group 2")]
+    fn test_missing_group() {
+        ByCapture::new(r"id=(\d+)", 2);
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "unicode")]
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::ByGrapheme;
+
+    #[ord_eq_by_key_selector(|m| ord_by_key::ByGrapheme(&m.text))]
+    pub struct Message {
+        pub text: String,
+    }
+
+    fn message(text: &str) -> Message {
+        Message {
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_zwj_sequence() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let man_grinning = "\u{1F468}\u{1F600}";
+
+        // By `char`, zero width joiner is less than the emoji following the man
+        assert!(family < man_grinning);
+
+        // By grapheme, the family is a single cluster which starts with the man
+        assert!(message(man_grinning).cmp(&message(family)) == Ordering::Less);
+        assert!(message(family).cmp(&message(family)) == Ordering::Equal);
+        assert!(message(family).cmp(&message("\u{1F468}")) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_flags() {
+        let usa = "\u{1F1FA}\u{1F1F8}";
+        let ukraine = "\u{1F1FA}\u{1F1E6}";
+
+        assert!(ByGrapheme(ukraine) < ByGrapheme(usa));
+        assert!(ByGrapheme(usa) == ByGrapheme(usa));
+
+        // Two flags are two clusters, the second one is compared only when the first is equal
+        assert!(ByGrapheme(&[usa, ukraine].concat()) < ByGrapheme(&[usa, usa].concat()));
+        assert!(ByGrapheme(usa) < ByGrapheme(&[usa, ukraine].concat()));
+    }
+
+    #[test]
+    fn test_combining_accents() {
+        let mut messages: Vec<_> = ["e\u{301}", "ez", "e\u{1F600}", "e", "f"]
+            .iter()
+            .map(|t| message(t))
+            .collect();
+
+        messages.sort();
+
+        let texts: Vec<_> = messages.iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(texts, ["e", "ez", "e\u{1F600}", "e\u{301}", "f"]);
+
+        // By `char`, accented `e` would go between `ez` and `e` followed by the emoji
+        assert!("ez" < "e\u{301}" && "e\u{301}" < "e\u{1F600}");
+
+        // Precomposed and decomposed forms are different clusters
+        assert!(ByGrapheme("\u{e9}") != ByGrapheme("e\u{301}"));
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(by_index = [2, 0, 1])]
+    #[derive(Debug)]
+    pub struct Rgb(u8, u8, u8);
+
+    #[allow(dead_code)]
+    #[ord_eq_by_key_selector(by_index = [1 desc, 0,])]
+    #[derive(Debug)]
+    pub struct Score(String, u32, Vec<f64>);
+
+    #[ord_eq_by_key_selector(by_index = [1]; warn_missing_fields(skip(0)))]
+    pub struct Tagged<T>(T, u32);
+
+    #[test]
+    fn test_order() {
+        assert!(Rgb(0, 0, 1).cmp(&Rgb(255, 255, 0)) == Ordering::Greater);
+        assert!(Rgb(1, 0, 5).cmp(&Rgb(0, 9, 5)) == Ordering::Greater);
+        assert!(Rgb(1, 0, 5).cmp(&Rgb(1, 9, 5)) == Ordering::Less);
+        assert!(Rgb(1, 2, 3) == Rgb(1, 2, 3));
+
+        let mut colors = [Rgb(3, 0, 0), Rgb(0, 0, 2), Rgb(1, 1, 0), Rgb(1, 0, 0)];
+        colors.sort();
+
+        let colors: Vec<_> = colors.iter().map(|c| (c.0, c.1, c.2)).collect();
+        assert_eq!(colors, [(1, 0, 0), (1, 1, 0), (3, 0, 0), (0, 0, 2)]);
+    }
+
+    #[test]
+    fn test_desc() {
+        let score = |name: &str, points| Score(name.to_string(), points, vec![f64::NAN]);
+
+        assert!(score("a", 2).cmp(&score("b", 1)) == Ordering::Less);
+        assert!(score("b", 1).cmp(&score("a", 1)) == Ordering::Greater);
+        // Unused field doesn't affect equality
+        assert!(score("a", 1) == score("a", 1));
+    }
+
+    #[test]
+    fn test_generic() {
+        assert!(Tagged("z", 1).cmp(&Tagged("a", 2)) == Ordering::Less);
+        assert!(Tagged(0.5, 1) == Tagged(1.5, 1));
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use core::cmp::Reverse;
+    use ord_by_key::ByKeyRef;
+    use std::collections::BinaryHeap;
+
+    #[derive(Debug)]
+    pub struct BigStruct {
+        pub id: u32,
+        pub priority: i64,
+        pub payload: [u64; 512],
+    }
+
+    fn big(id: u32, priority: i64) -> BigStruct {
+        BigStruct {
+            id,
+            priority,
+            payload: [id as u64; 512],
+        }
+    }
+
+    #[test]
+    fn test_heap_of_references() {
+        let values = vec![big(1, 10), big(2, -5), big(3, 30), big(4, 0)];
+        let by_priority = |b: &BigStruct| b.priority;
+
+        let mut heap = BinaryHeap::new();
+        for value in &values {
+            heap.push(ByKeyRef::new(value, by_priority));
+        }
+
+        let mut ids = vec![];
+        while let Some(top) = heap.pop() {
+            // Heap holds references into `values`, nothing is copied
+            assert!(values.iter().any(|v| core::ptr::eq(v, top.get())));
+            assert_eq!(top.get().payload[511], top.get().id as u64);
+            ids.push(top.get().id);
+        }
+
+        assert_eq!(ids, [3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_min_heap() {
+        let values = [big(1, 10), big(2, -5), big(3, 30)];
+
+        let mut heap: BinaryHeap<_> = values
+            .iter()
+            .map(|v| Reverse(ByKeyRef::new(v, |b: &BigStruct| b.priority)))
+            .collect();
+
+        assert_eq!(heap.pop().map(|Reverse(r)| r.get().id), Some(2));
+        assert_eq!(heap.peek().map(|Reverse(r)| r.get().id), Some(1));
+    }
+
+    #[test]
+    fn test_compare_by_key() {
+        let key = |b: &BigStruct| b.priority / 10;
+        let (a, b, c) = (big(1, 11), big(2, 19), big(3, 25));
+
+        assert!(ByKeyRef::new(&a, key).cmp(&ByKeyRef::new(&b, key)) == Ordering::Equal);
+        assert!(ByKeyRef::new(&a, key) == ByKeyRef::new(&b, key));
+        assert!(ByKeyRef::new(&c, key).cmp(&ByKeyRef::new(&b, key)) == Ordering::Greater);
+
+        let mut refs = [ByKeyRef::new(&c, key), ByKeyRef::new(&a, key)];
+        refs.sort();
+        assert_eq!(refs[0].get().id, 1);
+
+        // Copy of closure without captures
+        let copy = refs[0];
+        assert_eq!(copy.get().id, refs[0].get().id);
+        assert_eq!(
+            format!("{:?}", ByKeyRef::new(&1, |v: &i32| *v)),
+            "ByKeyRef(1)"
+        );
+    }
+}
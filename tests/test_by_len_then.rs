@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeMap;
+
+    #[ord_eq_by_key_selector(|(v)| ord_by_key::by_len_then(v))]
+    pub struct VecByLen(Vec<i32>);
+
+    #[ord_eq_by_key_selector(|(m)| ord_by_key::by_len_then(m))]
+    pub struct MapByLen(BTreeMap<&'static str, i32>);
+
+    #[ord_eq_by_key_selector(|(s)| ord_by_key::by_len_then(s.as_bytes()))]
+    pub struct StringByLen(String);
+
+    fn map(entries: &[(&'static str, i32)]) -> MapByLen {
+        MapByLen(entries.iter().cloned().collect())
+    }
+
+    #[test]
+    fn test_vec() {
+        assert!(VecByLen(vec![]).cmp(&VecByLen(vec![0])) == Ordering::Less);
+        assert!(VecByLen(vec![9]).cmp(&VecByLen(vec![1, 2])) == Ordering::Less);
+        assert!(VecByLen(vec![1, 2]).cmp(&VecByLen(vec![1, 3])) == Ordering::Less);
+        assert!(VecByLen(vec![1, 3]).cmp(&VecByLen(vec![1, 2])) == Ordering::Greater);
+        assert!(VecByLen(vec![1, 2]).eq(&VecByLen(vec![1, 2])));
+
+        // Plain `Vec` order would put [1, 2] before [9]
+        assert!(vec![1, 2] < vec![9]);
+    }
+
+    #[test]
+    fn test_btree_map() {
+        assert!(map(&[("z", 0)]).cmp(&map(&[("a", 0), ("b", 0)])) == Ordering::Less);
+        assert!(map(&[("a", 1)]).cmp(&map(&[("a", 2)])) == Ordering::Less);
+        assert!(map(&[("a", 1)]).cmp(&map(&[("b", 0)])) == Ordering::Less);
+        assert!(map(&[("a", 1), ("b", 2)]).eq(&map(&[("b", 2), ("a", 1)])));
+    }
+
+    #[test]
+    fn test_string() {
+        let mut names: Vec<_> = ["Michael", "Bob", "Alex", "John", "Al"]
+            .iter()
+            .map(|s| StringByLen(s.to_string()))
+            .collect();
+
+        names.sort();
+
+        let names: Vec<_> = names.iter().map(|s| s.0.as_str()).collect();
+        assert_eq!(names, ["Al", "Bob", "Alex", "John", "Michael"]);
+    }
+}
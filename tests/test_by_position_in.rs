@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::by_position_in;
+
+    #[derive(Debug)]
+    pub struct Item {
+        pub id: u32,
+        pub name: String,
+    }
+
+    fn item(id: u32, name: &str) -> Item {
+        Item {
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    fn ids(items: &[Item]) -> Vec<u32> {
+        items.iter().map(|i| i.id).collect()
+    }
+
+    #[test]
+    fn test_reorder_by_ranking() {
+        let ranking = vec![3, 1, 4, 2];
+        let mut items = vec![item(1, "a"), item(2, "b"), item(3, "c"), item(4, "d")];
+
+        items.sort_by(by_position_in(&ranking, |i: &Item| i.id));
+        assert!(ids(&items) == [3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_missing_last() {
+        let ranking = [2, 1];
+        let mut items = vec![item(5, "x"), item(1, "a"), item(7, "y"), item(2, "b")];
+
+        let compare = by_position_in(&ranking, |i: &Item| i.id);
+        assert!(compare(&item(1, ""), &item(5, "")) == Ordering::Less);
+        assert!(compare(&item(5, ""), &item(7, "")) == Ordering::Equal);
+
+        // Unranked items keep their original order
+        items.sort_by(compare);
+        assert!(ids(&items) == [2, 1, 5, 7]);
+    }
+
+    #[test]
+    fn test_duplicates_and_unused() {
+        let ranking = [1, 2, 1, 9];
+        let mut items = vec![item(2, "b"), item(1, "a")];
+
+        items.sort_by(by_position_in(&ranking, |i: &Item| i.id));
+        assert!(ids(&items) == [1, 2]);
+
+        let compare = by_position_in(&[] as &[u32], |i: &Item| i.id);
+        assert!(compare(&item(1, ""), &item(2, "")) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_owned_ids() {
+        let ranking: Vec<String> = ["c", "a"].iter().map(|s| s.to_string()).collect();
+        let mut items = vec![item(1, "a"), item(2, "b"), item(3, "c")];
+
+        let compare = by_position_in(&ranking, |i: &Item| i.name.clone());
+        items.sort_by(&compare);
+        assert!(ids(&items) == [3, 1, 2]);
+
+        // The comparator is reused for another sort with the same ranking
+        let mut reversed: Vec<_> = items.into_iter().rev().collect();
+        reversed.sort_by(compare);
+        assert!(ids(&reversed) == [3, 1, 2]);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::Bytes;
+
+    #[ord_eq_by_key_selector(|id| Bytes(&id.hash))]
+    pub struct Hash {
+        pub hash: [u8; 32],
+    }
+
+    #[ord_eq_by_key_selector(|(b)| Bytes(b))]
+    pub struct Owned(Vec<u8>);
+
+    #[ord_eq_by_key_selector(|(b)| Bytes(b))]
+    pub struct Borrowed<'a>(&'a [u8]);
+
+    fn hash(first: u8, last: u8) -> Hash {
+        let mut hash = [0; 32];
+        hash[0] = first;
+        hash[31] = last;
+        Hash { hash }
+    }
+
+    #[test]
+    fn test_array() {
+        assert!(hash(1, 0).cmp(&hash(0, 9)) == Ordering::Greater);
+        assert!(hash(1, 1).cmp(&hash(1, 2)) == Ordering::Less);
+        assert!(hash(1, 1) == hash(1, 1));
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert!(Owned(vec![1, 2]).cmp(&Owned(vec![1, 2, 0])) == Ordering::Less);
+        assert!(Owned(vec![]).cmp(&Owned(vec![0])) == Ordering::Less);
+        assert!(Owned(vec![2]).cmp(&Owned(vec![1, 9, 9])) == Ordering::Greater);
+        assert!(Borrowed(b"ab").cmp(&Borrowed(b"abc")) == Ordering::Less);
+        assert!(Borrowed(b"b").cmp(&Borrowed(b"abc")) == Ordering::Greater);
+        assert!(Borrowed(b"") == Borrowed(&[]));
+    }
+
+    #[test]
+    fn test_containers() {
+        let array = [1u8, 2, 3];
+        let vec = vec![1u8, 2, 3];
+        let slice: &[u8] = &[1, 2, 3];
+        let longer = [1u8, 2, 3, 0];
+
+        assert!(Bytes(&array) == Bytes(&vec));
+        assert!(Bytes(&vec) == Bytes(slice));
+        assert!(Bytes(&array[..2]).cmp(&Bytes(&vec)) == Ordering::Less);
+        assert!(Bytes(&array).cmp(&Bytes(&longer)) == Ordering::Less);
+        assert!(Bytes(b"\xff").cmp(&Bytes(&longer)) == Ordering::Greater);
+
+        for (a, b) in [
+            (&b"a"[..], &b"b"[..]),
+            (b"ab", b"a"),
+            (b"", b""),
+            (b"\x00", b""),
+        ] {
+            assert_eq!(Bytes(a).cmp(&Bytes(b)), a.to_vec().cmp(&b.to_vec()));
+        }
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|d| d.title.to_lowercase(), d.year; cache = sort_key)]
+    #[derive(Debug)]
+    pub struct Document {
+        pub title: String,
+        pub year: u32,
+        sort_key: (String, u32),
+    }
+
+    impl Document {
+        fn new(title: &str, year: u32) -> Self {
+            let mut document = Document {
+                title: title.to_string(),
+                year,
+                sort_key: Default::default(),
+            };
+            document.refresh_sort_key();
+            document
+        }
+    }
+
+    #[test]
+    fn test_ordered_by_cached_key() {
+        let a = Document::new("apple", 2001);
+        let b = Document::new("Banana", 1999);
+        let c = Document::new("APPLE", 2000);
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(c.cmp(&a) == Ordering::Less);
+        assert!(a == Document::new("Apple", 2001));
+        assert_eq!(a.compute_sort_key(), ("apple".to_string(), 2001));
+    }
+
+    #[test]
+    fn test_refresh_after_mutation() {
+        let mut a = Document::new("apple", 2001);
+        let b = Document::new("banana", 2001);
+
+        a.title = "cherry".to_string();
+        a.refresh_sort_key();
+
+        assert!(a.cmp(&b) == Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "cached key `sort_key` of `Document` is stale, call \
+                                 `refresh_sort_key` after modifying fields which the key \
+                                 depends on")
+    )]
+    fn test_stale_key() {
+        let mut a = Document::new("apple", 2001);
+        let b = Document::new("banana", 2001);
+
+        a.title = "cherry".to_string();
+
+        // Without `debug_assertions` stale key is used as is
+        assert!(a.cmp(&b) == Ordering::Less);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "is stale"))]
+    fn test_stale_key_other() {
+        let a = Document::new("apple", 2001);
+        let mut b = Document::new("banana", 2001);
+
+        b.year = 0;
+        let _ = a == b;
+    }
+
+    #[ord_eq_by_key_selector(methods = [score]; cache = cached_score, warn_missing_fields)]
+    pub struct Player {
+        pub hits: u32,
+        pub misses: u32,
+        cached_score: i64,
+    }
+
+    impl Player {
+        fn score(&self) -> i64 {
+            self.hits as i64 * 2 - self.misses as i64
+        }
+    }
+
+    #[test]
+    fn test_methods() {
+        let mut player = Player {
+            hits: 3,
+            misses: 1,
+            cached_score: 0,
+        };
+        player.refresh_cached_score();
+
+        let mut other = Player {
+            hits: 1,
+            misses: 0,
+            cached_score: 0,
+        };
+        other.refresh_cached_score();
+
+        assert!(player.cmp(&other) == Ordering::Greater);
+        assert_eq!(player.compute_cached_score(), 5);
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::CanonicalRotation;
+
+    #[ord_eq_by_key_selector(|r| CanonicalRotation(&r.bases), r.name)]
+    #[derive(Debug)]
+    pub struct Ring {
+        pub bases: Vec<u8>,
+        pub name: &'static str,
+    }
+
+    /// Canonical form computed by trying all rotations
+    fn naive_canonical<T: Ord + Clone>(values: &[T]) -> Vec<T> {
+        (0..values.len().max(1))
+            .map(|start| {
+                let start = start.min(values.len());
+                let mut rotation = values[start..].to_vec();
+                rotation.extend_from_slice(&values[..start]);
+                rotation
+            })
+            .min()
+            .unwrap()
+    }
+
+    /// All sequences over `0..base` of lengths up to `max_len`
+    fn sequences(base: u8, max_len: usize) -> Vec<Vec<u8>> {
+        let mut all = vec![vec![]];
+        let mut last = vec![vec![]];
+
+        for _ in 0..max_len {
+            last = last
+                .iter()
+                .flat_map(|s: &Vec<u8>| {
+                    (0..base).map(move |value| {
+                        let mut s = s.clone();
+                        s.push(value);
+                        s
+                    })
+                })
+                .collect();
+            all.extend(last.iter().cloned());
+        }
+
+        all
+    }
+
+    #[test]
+    fn test_start() {
+        assert!(CanonicalRotation::<u8>(&[]).start() == 0);
+        assert!(CanonicalRotation(&[7]).start() == 0);
+        assert!(CanonicalRotation(&[1, 2, 3]).start() == 0);
+        assert!(CanonicalRotation(&[3, 1, 2]).start() == 1);
+        assert!(CanonicalRotation(&[2, 3, 1]).start() == 2);
+        assert!(CanonicalRotation(&[1, 1, 1]).start() == 0);
+        assert!(CanonicalRotation(&[2, 1, 2, 1]).start() == 1);
+        assert!(CanonicalRotation(&[1, 0, 0, 1, 0]).start() == 1);
+        assert!(CanonicalRotation(b"bbaab").start() == 2);
+    }
+
+    #[test]
+    fn test_start_matches_naive() {
+        for s in sequences(3, 7) {
+            let start = CanonicalRotation(&s).start();
+            let mut rotation = s[start..].to_vec();
+            rotation.extend_from_slice(&s[..start]);
+
+            assert!(rotation == naive_canonical(&s), "{:?}", s);
+            // Periodic sequences have several minimal rotations, the first one is returned
+            assert!(
+                (0..start).all(|earlier| {
+                    let mut other = s[earlier..].to_vec();
+                    other.extend_from_slice(&s[..earlier]);
+                    other != rotation
+                }),
+                "{:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotations_equal() {
+        let values = [4, 1, 3, 1, 2];
+
+        for start in 0..values.len() {
+            let mut rotation = values[start..].to_vec();
+            rotation.extend_from_slice(&values[..start]);
+
+            assert!(CanonicalRotation(&rotation[..]) == CanonicalRotation(&values[..]));
+            assert!(
+                CanonicalRotation(&rotation[..]).cmp(&CanonicalRotation(&values[..]))
+                    == Ordering::Equal
+            );
+        }
+
+        assert!(CanonicalRotation(&[1, 2, 3]) != CanonicalRotation(&[1, 3, 2]));
+    }
+
+    #[test]
+    fn test_order_matches_canonical() {
+        let all = sequences(2, 5);
+
+        for a in &all {
+            for b in &all {
+                let expected = naive_canonical(a).cmp(&naive_canonical(b));
+
+                assert!(
+                    CanonicalRotation(&a[..]).cmp(&CanonicalRotation(&b[..])) == expected,
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_different_lengths() {
+        assert!(CanonicalRotation::<u8>(&[]) < CanonicalRotation(&[0]));
+        // `[1, 2]` is a prefix of `[1, 2, 3]`
+        assert!(CanonicalRotation(&[2, 1]) < CanonicalRotation(&[3, 1, 2]));
+        // `[0, 1, 2]` is less than `[1, 2]`, even though the sequence is longer
+        assert!(CanonicalRotation(&[1, 2, 0]) < CanonicalRotation(&[2, 1]));
+        assert!(CanonicalRotation(&[1, 1]) < CanonicalRotation(&[1, 2]));
+    }
+
+    #[test]
+    fn test_key() {
+        let ring = |bases: &[u8], name| Ring {
+            bases: bases.to_vec(),
+            name,
+        };
+
+        assert!(ring(b"GATC", "a") < ring(b"TCGA", "b"));
+        assert!(ring(b"GATC", "a") == ring(b"ATCG", "a"));
+        // `AGCT` is the canonical form of `CTAG`, `ATCG` of `GATC`
+        assert!(ring(b"CTAG", "b") < ring(b"GATC", "a"));
+
+        let mut rings = [ring(b"TTA", "c"), ring(b"CAT", "b"), ring(b"ATC", "a")];
+        rings.sort();
+
+        let names: Vec<_> = rings.iter().map(|r| r.name).collect();
+        assert!(names == ["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::CaseInsensitive;
+    use ord_by_key::NoneFirst;
+    use ord_by_key::NoneLast;
+
+    #[ord_eq_by_key_selector(|c| NoneLast(c.nickname.map(CaseInsensitive)), c.id)]
+    #[derive(Debug)]
+    pub struct Contact {
+        pub id: u32,
+        pub nickname: Option<&'static str>,
+    }
+
+    #[ord_eq_by_key_selector(|t| NoneFirst(t.label.as_deref().map(CaseInsensitive)))]
+    pub struct Tag {
+        pub label: Option<String>,
+    }
+
+    fn nicknames(contacts: &[Contact]) -> Vec<Option<&'static str>> {
+        contacts.iter().map(|c| c.nickname).collect()
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert!(CaseInsensitive("apple").cmp(&CaseInsensitive("Banana")) == Ordering::Less);
+        assert!(CaseInsensitive("Hello").cmp(&CaseInsensitive("hELLO")) == Ordering::Equal);
+        assert!(CaseInsensitive("abc").cmp(&CaseInsensitive("AB")) == Ordering::Greater);
+        assert!(CaseInsensitive("").cmp(&CaseInsensitive("a")) == Ordering::Less);
+        assert!(CaseInsensitive("ÄRGER").cmp(&CaseInsensitive("ärger")) == Ordering::Equal);
+        assert!(CaseInsensitive("Straße").cmp(&CaseInsensitive("STRASSE")) != Ordering::Equal);
+
+        // Owned strings and references to them
+        let owned = String::from("Zebra");
+        assert!(CaseInsensitive(&owned) > CaseInsensitive(&String::from("apple")));
+        assert!(CaseInsensitive(owned.clone()) == CaseInsensitive(String::from("ZEBRA")));
+    }
+
+    #[test]
+    fn test_none_last() {
+        let contact = |id, nickname| Contact { id, nickname };
+        let mut contacts = vec![
+            contact(0, None),
+            contact(1, Some("bob")),
+            contact(2, Some("Alice")),
+            contact(3, Some("BOB")),
+            contact(4, None),
+            contact(5, Some("carol")),
+        ];
+        contacts.sort();
+
+        assert!(
+            nicknames(&contacts)
+                == [
+                    Some("Alice"),
+                    Some("bob"),
+                    Some("BOB"),
+                    Some("carol"),
+                    None,
+                    None
+                ]
+        );
+        assert!(contact(0, Some("Bob")).cmp(&contact(0, Some("bOB"))) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_none_first() {
+        let tag = |label: Option<&str>| Tag {
+            label: label.map(str::to_string),
+        };
+
+        assert!(tag(None) < tag(Some("a")));
+        assert!(tag(Some("a")) < tag(Some("B")));
+        assert!(tag(Some("Rust")) == tag(Some("rust")));
+        assert!(tag(None) == tag(None));
+    }
+
+    #[test]
+    fn test_nesting() {
+        // Wrappers nest by value in any order
+        let a = Reverse(NoneLast(Some(CaseInsensitive("a"))));
+        let b = Reverse(NoneLast(Some(CaseInsensitive("B"))));
+        assert!(a > b);
+
+        let none = NoneLast(None::<CaseInsensitive<&str>>);
+        assert!(NoneLast(Some(CaseInsensitive("z"))) < none);
+    }
+}
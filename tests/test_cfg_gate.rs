@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use ::core::marker::PhantomData;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    // `std` feature of this crate is enabled by default, `regex` is disabled by default
+    #[ord_eq_by_key_selector(|r| r.id; cfg = "std", gen_extremes = larger/smaller)]
+    #[derive(Debug)]
+    pub struct Enabled {
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(|r| r.id; cfg = "regex", gen_option_ord = none_last)]
+    #[derive(Debug)]
+    pub struct Gated {
+        pub id: u32,
+    }
+
+    // Method resolution on `&&Probe<T>` picks the impl for `&Probe<T>` only if `T: Ord`, and
+    // falls back to the impl for `Probe<T>` after auto-deref otherwise
+    struct Probe<T>(PhantomData<T>);
+
+    macro_rules! is_ord {
+        ($ty:ty) => {
+            (&&Probe::<$ty>(PhantomData)).is_ord()
+        };
+    }
+
+    trait ViaOrd {
+        fn is_ord(&self) -> bool {
+            true
+        }
+    }
+
+    impl<T: Ord> ViaOrd for &Probe<T> {}
+
+    trait ViaAny {
+        fn is_ord(&self) -> bool {
+            false
+        }
+    }
+
+    impl<T> ViaAny for Probe<T> {}
+
+    #[test]
+    fn test_enabled_feature() {
+        assert!(is_ord!(Enabled));
+
+        #[cfg(feature = "std")]
+        {
+            assert!(Enabled { id: 1 } < Enabled { id: 2 });
+            assert!(Enabled { id: 1 }.larger(Enabled { id: 2 }).id == 2);
+        }
+    }
+
+    #[test]
+    fn test_disabled_feature() {
+        // Probe of a type which certainly isn't `Ord` to check the probe itself
+        assert!(!is_ord!(f64));
+
+        assert!(is_ord!(Gated) == cfg!(feature = "regex"));
+
+        // The struct exists regardless of the feature
+        let gated = Gated { id: 1 };
+        assert!(gated.id == 1);
+
+        #[cfg(feature = "regex")]
+        {
+            assert!(Gated { id: 1 } < Gated { id: 2 });
+            assert!(OptGated(Some(Gated { id: 9 })) < OptGated(None));
+        }
+    }
+}
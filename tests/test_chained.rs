@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::chained;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|p| ord_by_key::chained(&p.first, &p.last))]
+    pub struct Person {
+        pub first: String,
+        pub last: String,
+    }
+
+    #[ord_eq_by_key_selector(|p| chained(&p.first, &p.last))]
+    pub struct PersonRef<'a> {
+        pub first: &'a str,
+        pub last: &'a str,
+    }
+
+    fn person(first: &str, last: &str) -> Person {
+        Person {
+            first: first.to_string(),
+            last: last.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_separator() {
+        // Without the separator both would be "abc"
+        assert!(person("ab", "c") != person("a", "bc"));
+        assert!(person("ab", "c").cmp(&person("a", "bc")) == Ordering::Greater);
+        assert!(chained("ab", "c") > chained("a", "bc"));
+        assert!(chained("", "a") < chained("a", ""));
+    }
+
+    #[test]
+    fn test_same_as_concatenation() {
+        let names = [
+            ("Ann", "Lee"),
+            ("Anna", "Belle"),
+            ("Ann", "Marie Lee"),
+            ("Ann-Marie", "Lee"),
+            ("Ann", ""),
+            ("", "Ann"),
+            ("Бо", "Ян"),
+        ];
+
+        for a in &names {
+            for b in &names {
+                let concatenated = format!("{} {}", a.0, a.1).cmp(&format!("{} {}", b.0, b.1));
+
+                assert_eq!(person(a.0, a.1).cmp(&person(b.0, b.1)), concatenated);
+                assert_eq!(
+                    PersonRef {
+                        first: a.0,
+                        last: a.1
+                    } == PersonRef {
+                        first: b.0,
+                        last: b.1
+                    },
+                    concatenated == Ordering::Equal
+                );
+            }
+        }
+
+        // Spaces inside of parts compare the same as the separator
+        assert!(person("Mary Ann", "Lee") == person("Mary", "Ann Lee"));
+    }
+
+    #[test]
+    fn test_nested() {
+        assert!(chained("a", chained("b", "c")) < chained("a", chained("b", "d")));
+        assert!(chained("a", chained("b c", "d")) == chained("a", chained("b", "c d")));
+        assert!(chained(chained("a", "b"), "c") < chained(chained("a", "b"), "d"));
+        assert!(chained(chained("ab", "c"), "d") > chained(chained("a", "bc"), "d"));
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    /// Pinned rank overrides natural order only when both records are pinned. Note that it's not
+    /// transitive when pinned and unpinned records with different titles are mixed, the test
+    /// only checks pairs
+    #[ord_eq_by_key_selector(|a, b|
+        cmp: match (a.pin, b.pin) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            _ => Ordering::Equal,
+        },
+        &a.title,
+        a.id,
+    )]
+    #[derive(Debug)]
+    pub struct Record {
+        pub pin: Option<u32>,
+        pub title: &'static str,
+        pub id: u32,
+    }
+
+    /// `cmp:` expression after a key, and a path starting with `cmp` as a regular key
+    #[ord_eq_by_key_selector(|a, b| a.group, cmp: b.score.cmp(&a.score), core::cmp::Reverse(a.id))]
+    #[derive(Debug)]
+    pub struct Entry {
+        pub group: u8,
+        pub score: u32,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(|a, b| cmp: a.0.len().cmp(&b.0.len()), &a.0; partial_only)]
+    #[derive(Debug)]
+    pub struct Word(&'static str);
+
+    fn record(pin: Option<u32>, title: &'static str, id: u32) -> Record {
+        Record { pin, title, id }
+    }
+
+    #[test]
+    fn test_pinned_override() {
+        // Both pinned, pin decides
+        assert!(record(Some(1), "z", 0).cmp(&record(Some(2), "a", 0)) == Ordering::Less);
+
+        // Equal pins and missing pins fall through to natural keys
+        assert!(record(Some(1), "b", 0).cmp(&record(Some(1), "a", 0)) == Ordering::Greater);
+        assert!(record(None, "a", 0).cmp(&record(Some(9), "b", 0)) == Ordering::Less);
+        assert!(record(Some(0), "b", 0).cmp(&record(None, "a", 0)) == Ordering::Greater);
+        assert!(record(None, "a", 1).cmp(&record(None, "a", 2)) == Ordering::Less);
+
+        assert!(record(Some(1), "a", 0) == record(Some(1), "a", 0));
+        assert!(record(None, "a", 0) == record(Some(1), "a", 0));
+        assert!(record(Some(2), "a", 0) != record(Some(1), "a", 0));
+    }
+
+    #[test]
+    fn test_comparator_after_key() {
+        let entry = |group, score, id| Entry { group, score, id };
+
+        assert!(entry(0, 0, 0).cmp(&entry(1, 9, 0)) == Ordering::Less);
+        assert!(entry(1, 9, 0).cmp(&entry(1, 5, 0)) == Ordering::Less);
+        assert!(entry(1, 5, 1).cmp(&entry(1, 5, 2)) == Ordering::Greater);
+        assert!(entry(1, 5, 1) == entry(1, 5, 1));
+    }
+
+    #[test]
+    fn test_partial_only() {
+        assert_eq!(Word("bb").partial_cmp(&Word("a")), Some(Ordering::Greater));
+        assert_eq!(Word("ab").partial_cmp(&Word("ba")), Some(Ordering::Less));
+        assert!(Word("ab") == Word("ab"));
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|e| e.bucket, Reverse(e.offset); gen_cmp_mono)]
+    #[derive(Clone, Copy, Debug)]
+    pub struct Event {
+        pub bucket: u32,
+        pub offset: u64,
+    }
+
+    #[ord_eq_by_key_selector(|j| j.priority; gen_cmp_mono, fifo_tiebreak)]
+    #[derive(Debug)]
+    pub struct Job {
+        pub priority: u8,
+    }
+
+    fn event(bucket: u32, offset: u64) -> Event {
+        Event { bucket, offset }
+    }
+
+    #[test]
+    fn test_same_as_cmp() {
+        let events = [
+            event(1, 0),
+            event(0, 5),
+            event(1, 3),
+            event(0, 5),
+            event(2, 1),
+        ];
+
+        for a in &events {
+            for b in &events {
+                assert!(a.cmp_mono(b) == a.cmp(b));
+            }
+        }
+
+        assert!(Event::cmp_mono(&event(0, 9), &event(0, 1)) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let mut by_mono = [event(1, 0), event(0, 5), event(1, 3), event(2, 1)];
+        let mut by_ord = by_mono;
+
+        by_mono.sort_by(Event::cmp_mono);
+        by_ord.sort();
+
+        assert_eq!(
+            by_mono
+                .iter()
+                .map(|e| (e.bucket, e.offset))
+                .collect::<Vec<_>>(),
+            by_ord
+                .iter()
+                .map(|e| (e.bucket, e.offset))
+                .collect::<Vec<_>>()
+        );
+        assert!(by_mono.binary_search_by(|e| e.cmp_mono(&event(1, 0))) == Ok(2));
+    }
+
+    #[test]
+    fn test_with_tiebreak() {
+        let first = Job::new(1);
+        let second = Job::new(1);
+
+        assert!(first.cmp_mono(&second) == Ordering::Less);
+        assert!(Job::new(0).cmp_mono(&first) == Ordering::Less);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(ord = |x| x.category, x.score, x.id; eq = |x| x.category; unsafe_inconsistent)]
+    pub struct Item {
+        pub category: u32,
+        pub score: u32,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(unsafe_inconsistent; eq = |(c, s)| c; ord = |(c, s)| c, s,)]
+    pub struct Pair(u32, u32);
+
+    fn item(category: u32, score: u32, id: u32) -> Item {
+        Item {
+            category,
+            score,
+            id,
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        assert!(item(1, 2, 3) == item(1, 2, 3));
+        assert!(item(1, 2, 3) == item(1, 5, 6));
+        assert!(item(1, 2, 3) != item(2, 2, 3));
+
+        assert!(Pair(1, 2) == Pair(1, 3));
+        assert!(Pair(1, 2) != Pair(2, 2));
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(item(1, 2, 3).cmp(&item(1, 2, 3)) == Ordering::Equal);
+        assert!(item(1, 2, 3).cmp(&item(1, 2, 4)) == Ordering::Less);
+        assert!(item(1, 2, 3).cmp(&item(1, 1, 4)) == Ordering::Greater);
+        assert!(item(1, 2, 3).cmp(&item(0, 5, 5)) == Ordering::Greater);
+
+        // Values which are equal can still be ordered
+        assert!(item(1, 2, 3) == item(1, 5, 6));
+        assert!(item(1, 2, 3) < item(1, 5, 6));
+
+        assert!(Pair(1, 2).cmp(&Pair(1, 3)) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_dedup_by_category() {
+        let mut items = vec![
+            item(1, 3, 0),
+            item(0, 2, 1),
+            item(1, 1, 2),
+            item(0, 1, 3),
+            item(2, 0, 4),
+        ];
+
+        items.sort();
+        items.dedup();
+
+        let ids: Vec<_> = items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, [3, 2, 4]);
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::Comparator;
+    use ord_by_key::Direction;
+    use ord_by_key::Direction::Asc;
+    use ord_by_key::Direction::Desc;
+
+    #[ord_eq_by_key_selector(|p| p.id)]
+    #[derive(Debug)]
+    pub struct Person {
+        pub id: u32,
+        pub name: &'static str,
+        pub age: u32,
+    }
+
+    fn people() -> Vec<Person> {
+        [
+            (1, "Bob", 30),
+            (2, "Alice", 40),
+            (3, "Carol", 30),
+            (4, "Alice", 25),
+            (5, "Bob", 30),
+        ]
+        .iter()
+        .map(|&(id, name, age)| Person { id, name, age })
+        .collect()
+    }
+
+    /// Sorts by name, then by age, then by id, with directions of name and age chosen at runtime
+    fn sorted(name: Direction, age: Direction) -> Vec<u32> {
+        let comparator = Comparator::<Person>::new()
+            .then_key(|p| p.name)
+            .direction(name)
+            .then_key(|p| &p.age)
+            .direction(age)
+            .then_ord();
+
+        let mut people = people();
+        people.sort_by(comparator.build());
+        people.iter().map(|p| p.id).collect()
+    }
+
+    #[test]
+    fn test_directions_toggle_independently() {
+        assert_eq!(sorted(Asc, Asc), [4, 2, 1, 5, 3]);
+        assert_eq!(sorted(Asc, Desc), [2, 4, 1, 5, 3]);
+        assert_eq!(sorted(Desc, Asc), [3, 1, 5, 4, 2]);
+        assert_eq!(sorted(Desc, Desc), [3, 1, 5, 2, 4]);
+    }
+
+    #[test]
+    fn test_tiebreak_only_on_equal_keys() {
+        let comparator = Comparator::<Person>::new()
+            .then_key_owned(|p| p.age / 10)
+            .direction(Desc)
+            .then_key(|p| p.name);
+
+        let mut people = people();
+        people.sort_by(comparator.build());
+
+        // Ages 30, 30, 30 form one group ordered by name, stable for equal names
+        let ids: Vec<_> = people.iter().map(|p| p.id).collect();
+        assert_eq!(ids, [2, 1, 5, 3, 4]);
+    }
+
+    #[test]
+    fn test_generated_ord_reversed() {
+        let comparator = Comparator::<Person>::new().then_ord().direction(Desc);
+        let (a, b) = (&people()[0], &people()[1]);
+
+        assert!(a.cmp(b) == Ordering::Less);
+        assert!(comparator.compare(a, b) == Ordering::Greater);
+        assert!(comparator.compare(a, a) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_then_cmp_and_empty() {
+        let people = people();
+
+        assert!(Comparator::<Person>::new().compare(&people[0], &people[1]) == Ordering::Equal);
+
+        let comparator = Comparator::<Person>::new()
+            .then_cmp(|a, b| a.name.len().cmp(&b.name.len()))
+            .direction(Desc);
+        assert!(comparator.compare(&people[1], &people[0]) == Ordering::Less);
+        assert!(comparator.compare(&people[0], &people[2]) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_unsized_values() {
+        let comparator = Comparator::<str>::new()
+            .then_key_owned(str::len)
+            .then_key(|s| s)
+            .direction(Desc);
+
+        let mut words = vec!["bb", "a", "cc", "b"];
+        words.sort_by(|a, b| comparator.compare(a, b));
+        assert_eq!(words, ["b", "a", "cc", "bb"]);
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_compile_fail() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/compile_fail/*.rs");
+        #[cfg(feature = "rayon")]
+        t.compile_fail("tests/compile_fail_rayon/*.rs");
+    }
+}
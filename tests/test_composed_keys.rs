@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::WithAlphabet;
+    use std::cell::Cell;
+
+    thread_local! {
+        static NAME_KEYS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Returns number of evaluations of the secondary key since the previous call
+    fn name_keys() -> usize {
+        NAME_KEYS.with(|c| c.replace(0))
+    }
+
+    /// Alphabet of the address book, letters with diacritics go next to base letters
+    static ALPHABET: [char; 10] = ['a', 'á', 'b', 'c', 'č', 'd', 'e', 'é', 'z', 'ž'];
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum Category {
+        Family,
+        Work,
+        Other,
+    }
+
+    #[ord_eq_by_key_selector(|c|
+        c.category as u8,
+        {
+            NAME_KEYS.with(|k| k.set(k.get() + 1));
+            WithAlphabet(c.name, &ALPHABET)
+        })]
+    #[derive(Debug)]
+    pub struct Contact {
+        pub category: Category,
+        pub name: &'static str,
+    }
+
+    fn contact(category: Category, name: &'static str) -> Contact {
+        Contact { category, name }
+    }
+
+    #[test]
+    fn test_secondary_key_is_lazy() {
+        name_keys();
+
+        // Categories differ, names are not looked at
+        assert!(
+            contact(Category::Family, "žž").cmp(&contact(Category::Work, "a")) == Ordering::Less
+        );
+        assert!(contact(Category::Other, "a") != contact(Category::Work, "a"));
+        assert_eq!(name_keys(), 0);
+
+        // Categories tie, names decide
+        assert!(
+            contact(Category::Work, "čd").cmp(&contact(Category::Work, "cd")) == Ordering::Greater
+        );
+        assert_eq!(name_keys(), 2);
+
+        assert!(contact(Category::Work, "éa") == contact(Category::Work, "éa"));
+        assert_eq!(name_keys(), 2);
+    }
+
+    #[test]
+    fn test_sort_address_book() {
+        let mut book = [
+            contact(Category::Other, "ada"),
+            contact(Category::Work, "žad"),
+            contact(Category::Family, "čeda"),
+            contact(Category::Work, "zed"),
+            contact(Category::Family, "céd"),
+            contact(Category::Work, "ábe"),
+        ];
+        book.sort();
+
+        let names: Vec<_> = book.iter().map(|c| c.name).collect();
+        assert_eq!(names, ["céd", "čeda", "ábe", "zed", "žad", "ada"]);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_grapheme_secondary_key() {
+        use ord_by_key::ByGrapheme;
+
+        #[ord_eq_by_key_selector(|m| m.thread, ByGrapheme(m.text))]
+        pub struct Message {
+            pub thread: u32,
+            pub text: &'static str,
+        }
+
+        let message = |thread, text| Message { thread, text };
+
+        assert!(message(1, "e\u{301}").cmp(&message(2, "a")) == Ordering::Less);
+        assert!(message(1, "e\u{1F600}").cmp(&message(1, "e\u{301}")) == Ordering::Less);
+    }
+}
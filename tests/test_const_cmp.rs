@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::const_cmp;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|p| p.0, p.1)]
+    #[derive(Clone, Copy, Debug)]
+    pub struct Pair(pub u8, pub i32);
+
+    #[derive(Clone, Copy)]
+    pub struct Code {
+        pub group: char,
+        pub pair: Pair,
+        pub active: bool,
+    }
+
+    const fn sorted<const N: usize>(mut pairs: [Pair; N]) -> [Pair; N] {
+        let mut i = 1;
+
+        while i < N {
+            let mut j = i;
+
+            while j > 0 {
+                if let Ordering::Greater = const_cmp!(pairs[j - 1], pairs[j], .0, .1) {
+                    let swap = pairs[j - 1];
+                    pairs[j - 1] = pairs[j];
+                    pairs[j] = swap;
+                }
+
+                j -= 1;
+            }
+
+            i += 1;
+        }
+
+        pairs
+    }
+
+    const SORTED: [Pair; 6] = sorted([
+        Pair(3, 0),
+        Pair(1, 5),
+        Pair(1, -5),
+        Pair(0, i32::MAX),
+        Pair(3, -1),
+        Pair(1, 5),
+    ]);
+
+    const CMP_CODES: Ordering = const_cmp!(
+        Code { group: 'a', pair: Pair(1, 2), active: true },
+        Code { group: 'a', pair: Pair(1, 3), active: false },
+        .group,
+        .pair.0,
+        .pair.1,
+        .active,
+    );
+
+    fn pairs(pairs: &[Pair]) -> Vec<(u8, i32)> {
+        pairs.iter().map(|p| (p.0, p.1)).collect()
+    }
+
+    #[test]
+    fn test_sorted_at_compile_time() {
+        assert!(pairs(&SORTED) == [(0, i32::MAX), (1, -5), (1, 5), (1, 5), (3, -1), (3, 0)]);
+
+        // Order matches the ordering generated for the same keys
+        assert!(SORTED.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(SORTED.binary_search(&Pair(3, -1)) == Ok(4));
+    }
+
+    #[test]
+    fn test_matches_ord() {
+        let values = [Pair(0, 0), Pair(0, 1), Pair(1, -1), Pair(u8::MAX, i32::MIN)];
+
+        for a in &values {
+            for b in &values {
+                assert!(const_cmp!(a, b, .0, .1) == a.cmp(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        assert!(CMP_CODES == Ordering::Less);
+
+        let code = |group, active| Code {
+            group,
+            pair: Pair(0, 0),
+            active,
+        };
+
+        assert!(const_cmp!(code('b', false), code('a', true), .group) == Ordering::Greater);
+        assert!(const_cmp!(code('a', false), code('a', true), .group, .active) == Ordering::Less);
+        assert!(const_cmp!(code('a', false), code('b', true)) == Ordering::Equal);
+    }
+}
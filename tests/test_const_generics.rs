@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|b| b.used, N)]
+    pub struct Buf<const N: usize> {
+        pub data: [u8; N],
+        pub used: usize,
+    }
+
+    #[ord_eq_by_key_selector(|b| N - b.used, &b.data[..b.used])]
+    pub struct Remaining<const N: usize> {
+        pub data: [u8; N],
+        pub used: usize,
+    }
+
+    #[ord_eq_by_key_selector(|(data)| N, M, data)]
+    pub struct Matrix<T: Ord, const N: usize, const M: usize>([[T; M]; N]);
+
+    fn buf<const N: usize>(used: usize) -> Buf<N> {
+        Buf { data: [0; N], used }
+    }
+
+    #[test]
+    fn test_const_key() {
+        assert!(buf::<4>(1).cmp(&buf::<4>(2)) == Ordering::Less);
+        assert!(buf::<4>(2).cmp(&buf::<4>(2)) == Ordering::Equal);
+        assert!(buf::<0>(0).cmp(&buf::<0>(0)) == Ordering::Equal);
+        assert!(buf::<1024>(7).cmp(&buf::<1024>(3)) == Ordering::Greater);
+
+        // `data` is not a key
+        let mut a = buf::<2>(1);
+        a.data[0] = 9;
+        assert!(a.cmp(&buf::<2>(1)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_const_in_expression() {
+        let remaining = |data: [u8; 4], used| Remaining { data, used };
+
+        assert!(remaining([0; 4], 3).cmp(&remaining([0; 4], 1)) == Ordering::Less);
+        assert!(remaining([1, 2, 0, 0], 2).cmp(&remaining([1, 3, 9, 9], 2)) == Ordering::Less);
+        assert!(remaining([1, 2, 0, 0], 2).cmp(&remaining([1, 2, 9, 9], 2)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_multiple_consts() {
+        assert!(Matrix([[1, 2]]).cmp(&Matrix([[1, 3]])) == Ordering::Less);
+        assert!(Matrix([[1], [2]]).cmp(&Matrix([[1], [2]])) == Ordering::Equal);
+        assert!(Matrix::<u8, 0, 3>([]).cmp(&Matrix([])) == Ordering::Equal);
+    }
+}
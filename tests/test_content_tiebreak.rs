@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::StableHash;
+
+    #[ord_eq_by_key_selector(|t| t.priority; content_tiebreak)]
+    #[derive(Clone, Debug, Hash)]
+    pub struct Task {
+        pub priority: u32,
+        pub name: &'static str,
+    }
+
+    #[ord_eq_by_key_selector(|p| p.0; content_tiebreak, partial_only)]
+    #[derive(Debug, Hash)]
+    pub struct Partial(u32, &'static str);
+
+    fn task(priority: u32, name: &'static str) -> Task {
+        Task { priority, name }
+    }
+
+    fn names(tasks: &[Task]) -> Vec<&'static str> {
+        tasks.iter().map(|t| t.name).collect()
+    }
+
+    #[test]
+    fn test_keys_compared_first() {
+        assert!(task(0, "z").cmp(&task(1, "a")) == Ordering::Less);
+        assert!(task(2, "a").cmp(&task(1, "z")) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_ties_ordered_by_hash() {
+        let a = task(1, "a");
+        let b = task(1, "b");
+
+        assert!(a != b);
+        assert!(a.cmp(&b) == StableHash(&a).value().cmp(&StableHash(&b).value()));
+        assert!(a == a.clone());
+        assert!(a.cmp(&a.clone()) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_independent_of_insertion_order() {
+        let mut forward = vec![task(1, "a"), task(1, "b"), task(1, "c"), task(0, "d")];
+        let mut backward: Vec<_> = forward.iter().rev().cloned().collect();
+
+        forward.sort();
+        backward.sort();
+
+        assert_eq!(names(&forward), names(&backward));
+
+        // Recorded order, stable across runs and platforms
+        assert_eq!(names(&forward), ["d", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_partial_only() {
+        assert_eq!(
+            Partial(0, "b").partial_cmp(&Partial(1, "a")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Partial(1, "a").partial_cmp(&Partial(1, "b")),
+            Some(StableHash(&Partial(1, "a")).cmp(&StableHash(&Partial(1, "b"))))
+        );
+        assert!(Partial(1, "a") != Partial(1, "b"));
+        assert!(Partial(1, "a") == Partial(1, "a"));
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(debug_assertions)]
+
+#[cfg(test)]
+mod tests {
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|(v)| v; debug_counters)]
+    pub struct Counted(u32);
+
+    #[ord_eq_by_key_selector(|(v)| v; debug_counters, partial_only)]
+    pub struct CountedPartial(f64);
+
+    /// Deterministic pseudo-random sequence
+    fn values(n: usize) -> impl Iterator<Item = u32> {
+        (0..n as u32).map(|i| i.wrapping_mul(2_654_435_761) >> 8)
+    }
+
+    #[test]
+    fn test_sort() {
+        for &n in &[10, 100, 1000, 10000] {
+            let mut items: Vec<_> = values(n).map(Counted).collect();
+
+            let before = Counted::comparison_count();
+            items.sort();
+            let comparisons = (Counted::comparison_count() - before) as f64;
+
+            let n = n as f64;
+            assert!(comparisons >= n - 1.0);
+            assert!(
+                comparisons <= 2.0 * n * n.log2(),
+                "{} comparisons",
+                comparisons
+            );
+        }
+    }
+
+    #[test]
+    fn test_single_comparison() {
+        let before = Counted::comparison_count();
+
+        assert!(Counted(1) < Counted(2));
+        assert!(Counted(1).cmp(&Counted(1)) == core::cmp::Ordering::Equal);
+        assert_eq!(Counted::comparison_count() - before, 2);
+
+        // Equality doesn't count
+        assert!(Counted(1) == Counted(1));
+        assert_eq!(Counted::comparison_count() - before, 2);
+    }
+
+    #[test]
+    fn test_partial_only() {
+        let before = CountedPartial::comparison_count();
+
+        assert!(CountedPartial(1.0) < CountedPartial(2.0));
+        assert!(CountedPartial(f64::NAN)
+            .partial_cmp(&CountedPartial(1.0))
+            .is_none());
+        assert_eq!(CountedPartial::comparison_count() - before, 2);
+    }
+
+    #[test]
+    fn test_thread_local() {
+        let _ = Counted(1) < Counted(2);
+        assert!(Counted::comparison_count() > 0);
+
+        std::thread::spawn(|| {
+            assert_eq!(Counted::comparison_count(), 0);
+            let _ = Counted(1) < Counted(2);
+            assert_eq!(Counted::comparison_count(), 1);
+        })
+        .join()
+        .unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|p| &p.name, Reverse(p.score); debug_keys)]
+    pub struct Player {
+        pub name: &'static str,
+        pub score: u32,
+    }
+
+    #[ord_eq_by_key_selector(|e| std::cmp::Reverse(&e.date), e.id, ::core::cmp::Reverse(e.id); debug_keys)]
+    pub struct Event {
+        pub date: &'static str,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(|(v)| *v; debug_keys, vis = pub(crate))]
+    pub struct Single(pub i32);
+
+    #[ord_eq_by_key_selector(methods = [len]; debug_keys)]
+    pub struct Methods(pub &'static str);
+
+    impl Methods {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[ord_eq_by_key_selector(|w| ord_by_key::CaseInsensitive(w.0), Reverse(w.0.len()); debug_keys)]
+    pub struct Word(pub &'static str);
+
+    #[ord_eq_by_key_selector(|s| Reverse(s.0); partial_only, debug_keys)]
+    pub struct Score(pub f64);
+
+    #[test]
+    fn test_mixed_directions() {
+        let player = Player {
+            name: "ann",
+            score: 7,
+        };
+
+        assert!(format!("{:?}", player.debug_keys()) == "(\"ann\", ↓7)");
+    }
+
+    #[test]
+    fn test_reverse_paths() {
+        let event = Event {
+            date: "2024-01-01",
+            id: 3,
+        };
+
+        assert!(format!("{:?}", event.debug_keys()) == "(↓\"2024-01-01\", 3, ↓3)");
+    }
+
+    #[test]
+    fn test_single_key() {
+        assert!(format!("{:?}", Single(-1).debug_keys()) == "(-1,)");
+        assert!(format!("{:?}", Methods("abc").debug_keys()) == "(3,)");
+        assert!(format!("{:?}", Score(0.5).debug_keys()) == "(↓0.5,)");
+    }
+
+    #[test]
+    fn test_other_wrappers() {
+        // Only `Reverse` is shown as a direction, other key wrappers are printed as is
+        assert!(
+            format!("{:?}", Word("Ab").debug_keys()) == "(CaseInsensitive(\"Ab\"), ↓2)",
+            "{:?}",
+            Word("Ab").debug_keys()
+        );
+    }
+
+    #[test]
+    fn test_order_matches_keys() {
+        let players = [
+            Player {
+                name: "bob",
+                score: 1,
+            },
+            Player {
+                name: "ann",
+                score: 3,
+            },
+            Player {
+                name: "ann",
+                score: 9,
+            },
+        ];
+        let mut sorted: Vec<_> = players.iter().collect();
+        sorted.sort();
+
+        let keys: Vec<_> = sorted
+            .iter()
+            .map(|p| format!("{:?}", p.debug_keys()))
+            .collect();
+        assert!(keys == ["(\"ann\", ↓9)", "(\"ann\", ↓3)", "(\"bob\", ↓1)"]);
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::HasKey;
+
+    #[ord_eq_by_key_selector(|t| t.priority, t.name.clone(); gen_has_key = (u8, String))]
+    #[derive(Debug)]
+    pub struct Task {
+        pub priority: u8,
+        pub name: String,
+        pub id: u32,
+    }
+
+    /// Equality is coarser than ordering, so `dedup` and `dedup_by_key` differ
+    #[ord_eq_by_key_selector(ord = |v| v.major, v.minor; eq = |v| v.major; unsafe_inconsistent; gen_has_key = (u32, u32))]
+    #[derive(Debug)]
+    pub struct Version {
+        pub major: u32,
+        pub minor: u32,
+    }
+
+    fn tasks() -> Vec<Task> {
+        [
+            (2, "test", 1),
+            (1, "build", 2),
+            (2, "test", 3),
+            (1, "lint", 4),
+            (1, "build", 5),
+            (3, "deploy", 6),
+        ]
+        .iter()
+        .map(|&(priority, name, id)| Task {
+            priority,
+            name: name.to_string(),
+            id,
+        })
+        .collect()
+    }
+
+    fn ids(tasks: &[Task]) -> Vec<u32> {
+        tasks.iter().map(|t| t.id).collect()
+    }
+
+    #[test]
+    fn test_sort_then_dedup_by_key() {
+        let mut tasks = tasks();
+        tasks.sort();
+        tasks.dedup_by_key(|t| t.key());
+
+        // Sort is stable, so the first value of every key in the input is kept
+        assert_eq!(ids(&tasks), [2, 4, 1, 6]);
+
+        let keys: Vec<_> = tasks.iter().map(HasKey::key).collect();
+        assert_eq!(
+            keys,
+            [
+                (1, "build".to_string()),
+                (1, "lint".to_string()),
+                (2, "test".to_string()),
+                (3, "deploy".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dedup_matches_dedup_by_key() {
+        let mut by_key = tasks();
+        by_key.sort();
+        by_key.dedup_by_key(|t| t.key());
+
+        let mut by_eq = tasks();
+        by_eq.sort();
+        by_eq.dedup();
+
+        assert_eq!(ids(&by_key), ids(&by_eq));
+    }
+
+    #[test]
+    fn test_dedup_with_eq_keys() {
+        let version = |major, minor| Version { major, minor };
+
+        let mut by_key = vec![version(1, 2), version(1, 0), version(2, 0), version(1, 2)];
+        by_key.sort();
+        by_key.dedup_by_key(|v| v.key());
+
+        let mut by_eq = vec![version(1, 2), version(1, 0), version(2, 0), version(1, 2)];
+        by_eq.sort();
+        by_eq.dedup();
+
+        let pairs = |v: &[Version]| -> Vec<_> { v.iter().map(|v| (v.major, v.minor)).collect() };
+        assert_eq!(pairs(&by_key), [(1, 0), (1, 2), (2, 0)]);
+        assert_eq!(pairs(&by_eq), [(1, 0), (2, 0)]);
+    }
+}
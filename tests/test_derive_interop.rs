@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::ord_eq_by_key_selector;
+    use sorted_derive::Sorted;
+
+    /// Attribute above the derive
+    #[ord_eq_by_key_selector(|p| p.rank)]
+    #[derive(Sorted, Debug)]
+    pub struct Above {
+        pub rank: u32,
+        pub name: &'static str,
+    }
+
+    /// Attribute below the derive
+    #[derive(Sorted, Debug)]
+    #[ord_eq_by_key_selector(|p| p.rank)]
+    pub struct Below {
+        pub rank: u32,
+        pub name: &'static str,
+    }
+
+    #[ord_eq_by_key_selector(|(v, _n)| v)]
+    #[derive(Sorted)]
+    pub struct Generic<T: Ord>(T, &'static str);
+
+    #[ord_eq_by_key_selector(enum_fields)]
+    #[derive(Sorted, Debug)]
+    pub enum Level {
+        Low,
+        High(u8),
+    }
+
+    #[test]
+    fn test_attribute_above_derive() {
+        let mut values = [Above { rank: 2, name: "b" }, Above { rank: 1, name: "a" }];
+        Above::sort_all(&mut values);
+        assert_eq!(values[0].name, "a");
+
+        let set = Above::sorted_set(vec![
+            Above { rank: 3, name: "c" },
+            Above { rank: 3, name: "d" },
+        ]);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_attribute_below_derive() {
+        let mut values = [Below { rank: 2, name: "b" }, Below { rank: 1, name: "a" }];
+        Below::sort_all(&mut values);
+        assert_eq!(values[0].name, "a");
+    }
+
+    #[test]
+    fn test_generic_and_enum() {
+        let mut values = [Generic(2, "b"), Generic(1, "a")];
+        Generic::sort_all(&mut values);
+        assert_eq!(values[0].1, "a");
+
+        let set = Level::sorted_set(vec![Level::High(1), Level::Low, Level::High(0)]);
+        let levels: Vec<_> = set.into_iter().collect();
+        assert_eq!(format!("{:?}", levels), "[Low, High(0), High(1)]");
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeSet;
+
+    /// Neither `Ord` nor `Eq` (nor `Debug`) is a supertrait, so trait objects are not comparable
+    pub trait Handler {
+        fn priority(&self) -> u32;
+        fn name(&self) -> &str;
+    }
+
+    pub struct Named(&'static str, u32);
+
+    impl Handler for Named {
+        fn priority(&self) -> u32 {
+            self.1
+        }
+
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    #[ord_eq_by_key_selector(|p| p.order, p.handler.priority())]
+    pub struct Plugin {
+        pub order: i32,
+        pub handler: Box<dyn Handler>,
+    }
+
+    #[ord_eq_by_key_selector(|p| p.handler.name(), p.handler.priority())]
+    pub struct SharedPlugin {
+        pub handler: Box<dyn Handler + Send + Sync>,
+    }
+
+    #[ord_eq_by_key_selector(|p| p.handler.priority())]
+    pub struct BorrowedPlugin<'a> {
+        pub handler: &'a dyn Handler,
+    }
+
+    /// No `Ord` bound is added to `H`, so it can be instantiated with a trait object
+    #[ord_eq_by_key_selector(|s| s.handler.priority())]
+    pub struct Slot<H: ?Sized + Handler> {
+        pub handler: Box<H>,
+    }
+
+    fn plugin(order: i32, name: &'static str, priority: u32) -> Plugin {
+        Plugin {
+            order,
+            handler: Box::new(Named(name, priority)),
+        }
+    }
+
+    fn names<'a>(plugins: impl IntoIterator<Item = &'a Plugin>) -> Vec<&'a str> {
+        plugins.into_iter().map(|p| p.handler.name()).collect()
+    }
+
+    #[test]
+    fn test_order_by_trait_method() {
+        assert!(plugin(0, "a", 9) < plugin(1, "b", 0));
+        assert!(plugin(1, "a", 1) < plugin(1, "b", 2));
+        assert!(plugin(1, "a", 2) == plugin(1, "b", 2));
+        assert!(plugin(1, "a", 2).cmp(&plugin(1, "b", 1)) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = vec![
+            plugin(2, "cache", 1),
+            plugin(1, "auth", 5),
+            plugin(1, "log", 0),
+            plugin(2, "metrics", 3),
+        ];
+        registry.sort();
+
+        assert!(names(&registry) == ["log", "auth", "cache", "metrics"]);
+
+        let set: BTreeSet<_> = registry.into_iter().collect();
+        assert!(names(&set) == ["log", "auth", "cache", "metrics"]);
+        assert!(set.contains(&plugin(2, "other", 3)));
+    }
+
+    #[test]
+    fn test_trait_object_variants() {
+        let shared = |name, priority| SharedPlugin {
+            handler: Box::new(Named(name, priority)),
+        };
+        assert!(shared("a", 2) < shared("b", 1));
+        assert!(shared("a", 1) < shared("a", 2));
+
+        let (low, high) = (Named("low", 1), Named("high", 2));
+        assert!(BorrowedPlugin { handler: &low } < BorrowedPlugin { handler: &high });
+
+        let slot = |priority| Slot::<dyn Handler> {
+            handler: Box::new(Named("slot", priority)),
+        };
+        assert!(slot(1) < slot(2));
+        assert!(slot(2) == slot(2));
+        // Concrete handler types work as well
+        let concrete = |priority| Slot {
+            handler: Box::new(Named("concrete", priority)),
+        };
+        assert!(concrete(3) > concrete(1));
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::EmailCanonical;
+
+    #[ord_eq_by_key_selector(|u| EmailCanonical::gmail(&u.email))]
+    #[derive(Debug)]
+    pub struct User {
+        pub email: String,
+    }
+
+    /// Gmail-style rules only for Gmail domains, chosen in the key expression
+    #[ord_eq_by_key_selector(|c|
+        if c.email.to_lowercase().ends_with("@gmail.com") {
+            EmailCanonical::gmail(&c.email)
+        } else {
+            EmailCanonical::new(&c.email)
+        })]
+    #[derive(Debug)]
+    pub struct Contact {
+        pub email: String,
+    }
+
+    fn user(email: &str) -> User {
+        User {
+            email: email.to_string(),
+        }
+    }
+
+    fn contact(email: &str) -> Contact {
+        Contact {
+            email: email.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_case() {
+        assert!(EmailCanonical::new("user@Example.COM") == EmailCanonical::new("user@example.com"));
+        assert!(EmailCanonical::new("User@example.com") != EmailCanonical::new("user@example.com"));
+        assert!(
+            EmailCanonical::gmail("User@example.com") == EmailCanonical::gmail("user@EXAMPLE.com")
+        );
+
+        // Only the domain after the last `@` is lowercased
+        assert!(EmailCanonical::new("\"A@B\"@X.org") == EmailCanonical::new("\"A@B\"@x.org"));
+        assert!(EmailCanonical::new("\"A@B\"@x.org") != EmailCanonical::new("\"a@b\"@x.org"));
+    }
+
+    #[test]
+    fn test_tags() {
+        assert!(user("name+news@gmail.com") == user("name@gmail.com"));
+        assert!(user("name+a+b@gmail.com") == user("name+c@gmail.com"));
+        assert!(user("+tag@gmail.com") == user("@gmail.com"));
+        assert!(
+            EmailCanonical::new("name+news@gmail.com") != EmailCanonical::new("name@gmail.com")
+        );
+    }
+
+    #[test]
+    fn test_dots() {
+        assert!(user("user.name@gmail.com") == user("username@gmail.com"));
+        assert!(user("u.s.e.r@gmail.com") == user("user@gmail.com"));
+        // Dots of the domain are kept
+        assert!(user("user@gmail.com") != user("user@gmailcom"));
+        assert!(
+            EmailCanonical::new("user.name@gmail.com") != EmailCanonical::new("username@gmail.com")
+        );
+    }
+
+    #[test]
+    fn test_combined() {
+        assert!(user("User.Name+tag@Gmail.com") == user("username@gmail.com"));
+        assert!(
+            user("User.Name+tag@Gmail.com").cmp(&user("username@gmail.com")) == Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_order() {
+        let mut users = [
+            user("carol@b.com"),
+            user("Bob+x@a.com"),
+            user("b.o.b@a.com"),
+            user("alice@z.com"),
+            user("no-domain"),
+        ];
+        users.sort();
+
+        let emails: Vec<_> = users.iter().map(|u| &u.email[..]).collect();
+        assert_eq!(
+            emails,
+            [
+                "alice@z.com",
+                "Bob+x@a.com",
+                "b.o.b@a.com",
+                "carol@b.com",
+                "no-domain"
+            ]
+        );
+
+        // Address without domain goes after the same local part with a domain, `@` < `x`
+        assert!(user("bob@x") < user("bobx"));
+        assert!(user("bob") < user("bob@x"));
+    }
+
+    #[test]
+    fn test_rules_by_domain() {
+        assert!(contact("J.Doe+work@GMAIL.com") == contact("jdoe@gmail.com"));
+        assert!(contact("j.doe@example.com") != contact("jdoe@example.com"));
+        assert!(contact("j.doe@Example.com") == contact("j.doe@example.com"));
+    }
+}
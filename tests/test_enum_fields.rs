@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(enum_fields)]
+    #[derive(Debug, Clone)]
+    pub enum Shape {
+        Empty,
+        Circle(u32),
+        Rect { width: u32, height: u32 },
+        Named(String, u32),
+    }
+
+    /// Same enum as `Shape` with derived ordering
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum DerivedShape {
+        Empty,
+        Circle(u32),
+        Rect { width: u32, height: u32 },
+        Named(String, u32),
+    }
+
+    fn derived(shape: &Shape) -> DerivedShape {
+        match shape.clone() {
+            Shape::Empty => DerivedShape::Empty,
+            Shape::Circle(r) => DerivedShape::Circle(r),
+            Shape::Rect { width, height } => DerivedShape::Rect { width, height },
+            Shape::Named(name, n) => DerivedShape::Named(name, n),
+        }
+    }
+
+    fn shapes() -> Vec<Shape> {
+        vec![
+            Shape::Named("b".to_string(), 1),
+            Shape::Rect {
+                width: 2,
+                height: 1,
+            },
+            Shape::Circle(3),
+            Shape::Empty,
+            Shape::Named("a".to_string(), 2),
+            Shape::Rect {
+                width: 1,
+                height: 5,
+            },
+            Shape::Circle(1),
+            Shape::Named("a".to_string(), 1),
+            Shape::Rect {
+                width: 1,
+                height: 2,
+            },
+            Shape::Empty,
+        ]
+    }
+
+    #[test]
+    fn test_same_as_derive() {
+        let shapes = shapes();
+
+        for a in &shapes {
+            for b in &shapes {
+                assert_eq!(a.cmp(b), derived(a).cmp(&derived(b)), "{:?} {:?}", a, b);
+                assert_eq!(a == b, derived(a) == derived(b), "{:?} {:?}", a, b);
+                assert_eq!(
+                    a.partial_cmp(b),
+                    derived(a).partial_cmp(&derived(b)),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_variant_then_fields() {
+        let mut shapes = shapes();
+        shapes.sort();
+
+        let expected: Vec<_> = {
+            let mut derived: Vec<_> = shapes.iter().map(derived).collect();
+            derived.sort();
+            derived
+        };
+
+        assert_eq!(shapes.iter().map(derived).collect::<Vec<_>>(), expected);
+
+        assert!(Shape::Empty.cmp(&Shape::Circle(0)) == Ordering::Less);
+        assert!(
+            Shape::Circle(100).cmp(&Shape::Rect {
+                width: 0,
+                height: 0
+            }) == Ordering::Less
+        );
+        assert!(
+            Shape::Rect {
+                width: 1,
+                height: 2
+            }
+            .cmp(&Shape::Rect {
+                width: 1,
+                height: 1
+            }) == Ordering::Greater
+        );
+        assert!(Shape::Empty.cmp(&Shape::Empty) == Ordering::Equal);
+    }
+
+    /// Explicit discriminants don't affect the order, variants are ordered as declared
+    #[ord_eq_by_key_selector(enum_fields)]
+    #[derive(Debug)]
+    pub enum Priority {
+        High = 10,
+        Low = 1,
+    }
+
+    #[test]
+    fn test_declaration_order() {
+        assert!(Priority::High.cmp(&Priority::Low) == Ordering::Less);
+        assert!(Priority::Low == Priority::Low);
+    }
+
+    #[ord_eq_by_key_selector(enum_fields)]
+    #[derive(Debug)]
+    pub enum Single {
+        Only(i32, &'static str),
+    }
+
+    #[test]
+    fn test_single_variant() {
+        assert!(Single::Only(1, "b").cmp(&Single::Only(1, "a")) == Ordering::Greater);
+        assert!(Single::Only(1, "a") == Single::Only(1, "a"));
+    }
+
+    #[ord_eq_by_key_selector(enum_fields)]
+    pub enum Never {}
+
+    #[test]
+    fn test_empty() {
+        fn assert_ord<T: Ord>() {}
+
+        assert_ord::<Never>();
+    }
+
+    #[ord_eq_by_key_selector(enum_fields)]
+    #[derive(Debug)]
+    pub enum Tree<T: Ord> {
+        Leaf(T),
+        Node(Box<Tree<T>>, Box<Tree<T>>),
+    }
+
+    #[test]
+    fn test_generic() {
+        let node = |a, b| Tree::Node(Box::new(Tree::Leaf(a)), Box::new(Tree::Leaf(b)));
+
+        assert!(Tree::Leaf(5).cmp(&node(1, 2)) == Ordering::Less);
+        assert!(node(1, 3).cmp(&node(1, 2)) == Ordering::Greater);
+        assert!(node(1, 2) == node(1, 2));
+        assert!(Tree::Leaf("a") != Tree::Leaf("b"));
+    }
+
+    #[ord_eq_by_key_selector(|m| match m {
+        Message::Text(text) => text.len(),
+        Message::Ping => 0,
+    })]
+    pub enum Message {
+        Text(String),
+        Ping,
+    }
+
+    #[test]
+    fn test_key_selector() {
+        let text = |s: &str| Message::Text(s.to_string());
+
+        assert!(text("ab").cmp(&text("b")) == Ordering::Greater);
+        assert!(text("").cmp(&Message::Ping) == Ordering::Equal);
+        assert!(text("a") == text("b"));
+    }
+
+    #[ord_eq_by_key_selector(enum_fields; eq = |k| k.len(); unsafe_inconsistent)]
+    #[derive(Debug)]
+    pub enum Key {
+        Short(u8),
+        Long(u8, u8),
+    }
+
+    impl Key {
+        fn len(&self) -> usize {
+            match self {
+                Key::Short(_) => 1,
+                Key::Long(..) => 2,
+            }
+        }
+    }
+
+    #[test]
+    fn test_coarse_eq() {
+        assert!(Key::Short(1).cmp(&Key::Short(2)) == Ordering::Less);
+        assert!(Key::Short(1) == Key::Short(2));
+        assert!(Key::Short(1) != Key::Long(1, 1));
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(by_field_comparator = rule; unsafe_dynamic)]
+    pub struct Entry {
+        pub name: &'static str,
+        pub size: u32,
+        pub rule: fn(&Entry, &Entry) -> Ordering,
+    }
+
+    fn by_name(a: &Entry, b: &Entry) -> Ordering {
+        a.name.cmp(b.name)
+    }
+
+    fn by_size(a: &Entry, b: &Entry) -> Ordering {
+        a.size.cmp(&b.size)
+    }
+
+    fn sorted(rule: fn(&Entry, &Entry) -> Ordering) -> Vec<&'static str> {
+        let mut entries =
+            [("b", 1), ("c", 3), ("a", 2)].map(|(name, size)| Entry { name, size, rule });
+        entries.sort();
+        entries.iter().map(|e| e.name).collect()
+    }
+
+    #[test]
+    fn test_different_comparators() {
+        assert_eq!(sorted(by_name), ["a", "b", "c"]);
+        assert_eq!(sorted(by_size), ["b", "a", "c"]);
+
+        let entry = |name, size, rule| Entry { name, size, rule };
+        assert!(entry("a", 2, by_name).cmp(&entry("b", 1, by_name)) == Ordering::Less);
+        assert!(entry("a", 2, by_size).cmp(&entry("b", 1, by_size)) == Ordering::Greater);
+
+        // Equality follows the comparator
+        assert!(entry("a", 1, by_size) == entry("b", 1, by_size));
+        assert!(entry("a", 1, by_name) != entry("b", 1, by_name));
+    }
+
+    pub type Comparator = Box<dyn Fn(&Configured, &Configured) -> Ordering>;
+
+    /// Boxed closure capturing configuration
+    #[ord_eq_by_key_selector(by_field_comparator = 1; unsafe_dynamic)]
+    pub struct Configured(i32, Comparator);
+
+    fn configured(value: i32, descending: bool) -> Configured {
+        Configured(
+            value,
+            Box::new(move |a, b| {
+                if descending {
+                    b.0.cmp(&a.0)
+                } else {
+                    a.0.cmp(&b.0)
+                }
+            }),
+        )
+    }
+
+    #[test]
+    fn test_boxed_closure() {
+        assert!(configured(1, false).cmp(&configured(2, false)) == Ordering::Less);
+        assert!(configured(1, true).cmp(&configured(2, true)) == Ordering::Greater);
+        assert!(configured(1, true) == configured(1, true));
+    }
+}
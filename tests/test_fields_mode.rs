@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(fields)]
+    pub struct Named {
+        pub id: u32,
+        pub name: String,
+        pub score: u32,
+    }
+
+    #[ord_eq_by_key_selector(fields)]
+    pub struct Unnamed(u32, String, u32);
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Derived {
+        pub id: u32,
+        pub name: String,
+        pub score: u32,
+    }
+
+    fn values() -> Vec<(u32, &'static str, u32)> {
+        let mut values = vec![];
+
+        for id in 0..3 {
+            for name in &["", "a", "ab", "b"] {
+                for score in 0..3 {
+                    values.push((id, *name, score));
+                }
+            }
+        }
+
+        values
+    }
+
+    fn named((id, name, score): (u32, &str, u32)) -> Named {
+        Named {
+            id,
+            name: name.to_string(),
+            score,
+        }
+    }
+
+    fn unnamed((id, name, score): (u32, &str, u32)) -> Unnamed {
+        Unnamed(id, name.to_string(), score)
+    }
+
+    fn derived((id, name, score): (u32, &str, u32)) -> Derived {
+        Derived {
+            id,
+            name: name.to_string(),
+            score,
+        }
+    }
+
+    #[test]
+    fn test_same_as_derive() {
+        for a in values() {
+            for b in values() {
+                let expected = derived(a).cmp(&derived(b));
+
+                assert_eq!(named(a).cmp(&named(b)), expected);
+                assert_eq!(unnamed(a).cmp(&unnamed(b)), expected);
+
+                assert_eq!(named(a).partial_cmp(&named(b)), Some(expected));
+                assert_eq!(unnamed(a).partial_cmp(&unnamed(b)), Some(expected));
+
+                assert_eq!(named(a) == named(b), derived(a) == derived(b));
+                assert_eq!(unnamed(a) == unnamed(b), derived(a) == derived(b));
+            }
+        }
+    }
+}
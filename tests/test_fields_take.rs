@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    // Payload is not comparable at all
+    pub struct Payload;
+
+    #[ord_eq_by_key_selector(fields(take = 3))]
+    #[allow(dead_code)]
+    pub struct Record {
+        pub table: &'static str,
+        pub shard: u32,
+        pub id: u64,
+        pub payload: Payload,
+        pub weight: f64,
+    }
+
+    #[ord_eq_by_key_selector(fields(take = 1))]
+    pub struct Tuple(u32, Payload);
+
+    #[ord_eq_by_key_selector(fields(take = 2))]
+    pub struct AllFields(u32, u32);
+
+    fn record(table: &'static str, shard: u32, id: u64) -> Record {
+        Record {
+            table,
+            shard,
+            id,
+            payload: Payload,
+            weight: f64::NAN,
+        }
+    }
+
+    #[test]
+    fn test_take() {
+        assert!(record("a", 1, 1).cmp(&record("a", 1, 1)) == Ordering::Equal);
+        assert!(record("a", 1, 1).cmp(&record("b", 0, 0)) == Ordering::Less);
+        assert!(record("a", 1, 1).cmp(&record("a", 2, 0)) == Ordering::Less);
+        assert!(record("a", 1, 1).cmp(&record("a", 1, 0)) == Ordering::Greater);
+        assert!(record("a", 1, 1) == record("a", 1, 1));
+
+        assert!(Tuple(1, Payload).cmp(&Tuple(2, Payload)) == Ordering::Less);
+        assert!(Tuple(1, Payload) == Tuple(1, Payload));
+
+        assert!(AllFields(1, 2).cmp(&AllFields(1, 3)) == Ordering::Less);
+    }
+}
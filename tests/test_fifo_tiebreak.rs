@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BinaryHeap;
+
+    #[ord_eq_by_key_selector(|j| j.priority; fifo_tiebreak)]
+    #[derive(Debug, Clone)]
+    pub struct Job {
+        pub priority: u32,
+        pub name: &'static str,
+    }
+
+    #[test]
+    fn test_equal_priority_pops_in_insertion_order() {
+        let mut heap = BinaryHeap::new();
+
+        for &(priority, name) in &[(2, "a"), (1, "b"), (2, "c"), (1, "d"), (2, "e"), (1, "f")] {
+            heap.push(Reverse(Job::new(priority, name)));
+        }
+
+        let mut names = vec![];
+        while let Some(Reverse(job)) = heap.pop() {
+            names.push(job.name);
+        }
+
+        assert_eq!(names, ["b", "d", "f", "a", "c", "e"]);
+    }
+
+    #[test]
+    fn test_keys_go_first() {
+        let first = Job::new(5, "first");
+        let second = Job::new(1, "second");
+        let third = Job::new(1, "third");
+
+        assert!(first.cmp(&second) == Ordering::Greater);
+        assert!(second.cmp(&third) == Ordering::Less);
+        assert!(third.cmp(&second) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_eq_only_for_clones() {
+        let job = Job::new(1, "a");
+        let same_keys = Job::new(1, "a");
+
+        assert!(job != same_keys);
+        assert!(job == job.clone());
+        assert!(job.cmp(&job.clone()) == Ordering::Equal);
+
+        // Clone keeps the sequence number, but keys are still compared
+        let mut changed = job.clone();
+        changed.priority = 0;
+        assert!(changed.cmp(&job) == Ordering::Less);
+        assert!(changed != job);
+    }
+
+    #[ord_eq_by_key_selector(fields; fifo_tiebreak)]
+    #[derive(Debug)]
+    pub struct Task<T: Ord> {
+        pub deadline: u64,
+        pub payload: T,
+    }
+
+    #[test]
+    fn test_fields_generic() {
+        let a = Task::new(1, "x");
+        let b = Task::new(1, "x");
+        let c = Task::new(0, "y");
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(c.cmp(&a) == Ordering::Less);
+        assert!(Task::new(1, "a").cmp(&a) == Ordering::Less);
+    }
+
+    #[ord_eq_by_key_selector(|m| m.value; partial_only, fifo_tiebreak)]
+    #[derive(Debug)]
+    pub struct Measurement {
+        pub value: f64,
+    }
+
+    #[test]
+    fn test_partial_only() {
+        let a = Measurement::new(1.0);
+        let b = Measurement::new(1.0);
+
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Less));
+        assert_eq!(b.partial_cmp(&a), Some(Ordering::Greater));
+        assert_eq!(a.partial_cmp(&Measurement::new(f64::NAN)), None);
+        assert!(a != b);
+        assert!(a == a);
+    }
+}
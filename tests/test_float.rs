@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::NumericF64;
+    use ord_by_key::StrictF64;
+    use ord_by_key::TotalF64;
+    use std::collections::BTreeSet;
+
+    #[ord_eq_by_key_selector(|(v)| ord_by_key::StrictF64(*v))]
+    pub struct Strict(f64);
+
+    #[ord_eq_by_key_selector(|(v)| ord_by_key::NumericF64(*v))]
+    pub struct Numeric(f64);
+
+    fn negative_nan() -> f64 {
+        -f64::NAN
+    }
+
+    fn nan_with_payload() -> f64 {
+        f64::from_bits(f64::NAN.to_bits() | 1)
+    }
+
+    #[test]
+    fn test_strict_zero() {
+        assert!(StrictF64(-0.0).cmp(&StrictF64(0.0)) == Ordering::Less);
+        assert!(StrictF64(-0.0) != StrictF64(0.0));
+        assert!(StrictF64(0.0) == StrictF64(0.0));
+        assert!(StrictF64(-0.0) == StrictF64(-0.0));
+
+        let set: BTreeSet<_> = [0.0, -0.0, 0.0].iter().map(|&v| Strict(v)).collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_strict_nan() {
+        assert!(StrictF64(f64::NAN) == StrictF64(f64::NAN));
+        assert!(StrictF64(f64::NAN).cmp(&StrictF64(f64::INFINITY)) == Ordering::Greater);
+        assert!(StrictF64(negative_nan()).cmp(&StrictF64(f64::NEG_INFINITY)) == Ordering::Less);
+        assert!(StrictF64(f64::NAN) != StrictF64(negative_nan()));
+        assert!(StrictF64(f64::NAN) != StrictF64(nan_with_payload()));
+        assert!(StrictF64(f64::NAN).cmp(&StrictF64(nan_with_payload())) == Ordering::Less);
+
+        // `TotalF64` is the same ordering
+        assert!(TotalF64(-0.0) < TotalF64(0.0));
+        assert!(TotalF64(f64::NAN) != TotalF64(nan_with_payload()));
+    }
+
+    #[test]
+    fn test_numeric_zero() {
+        assert!(NumericF64(-0.0).cmp(&NumericF64(0.0)) == Ordering::Equal);
+        assert!(NumericF64(-0.0) == NumericF64(0.0));
+        assert!(NumericF64(-0.0).cmp(&NumericF64(f64::MIN_POSITIVE)) == Ordering::Less);
+
+        let set: BTreeSet<_> = [0.0, -0.0, 0.0].iter().map(|&v| Numeric(v)).collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_numeric_nan() {
+        assert!(NumericF64(f64::NAN) == NumericF64(f64::NAN));
+        assert!(NumericF64(f64::NAN) == NumericF64(negative_nan()));
+        assert!(NumericF64(f64::NAN) == NumericF64(nan_with_payload()));
+        assert!(NumericF64(f64::NAN).cmp(&NumericF64(f64::INFINITY)) == Ordering::Greater);
+        assert!(NumericF64(negative_nan()).cmp(&NumericF64(f64::INFINITY)) == Ordering::Greater);
+
+        let mut values: Vec<_> = [f64::NAN, 1.0, negative_nan(), f64::NEG_INFINITY, -0.0]
+            .iter()
+            .map(|&v| Numeric(v))
+            .collect();
+        values.sort();
+        values.dedup();
+
+        let values: Vec<_> = values.iter().map(|v| v.0).collect();
+        assert_eq!(values[..3], [f64::NEG_INFINITY, -0.0, 1.0]);
+        assert!(values.len() == 4 && values[3].is_nan());
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::NumericF64Eps;
+
+    #[ord_eq_by_key_selector(|m| NumericF64Eps(m.value, 0.1))]
+    #[derive(Debug)]
+    pub struct Measurement {
+        pub value: f64,
+    }
+
+    fn values() -> Vec<f64> {
+        let mut values: Vec<f64> = (-40..=40).map(|i| i as f64 * 0.037).collect();
+        values.extend([
+            0.05,
+            -0.05,
+            0.15,
+            1e300,
+            -1e300,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -0.0,
+        ]);
+        values
+    }
+
+    #[test]
+    fn test_buckets() {
+        let m = |value| Measurement { value };
+
+        assert!(m(1.02) == m(0.97));
+        assert!(m(1.02) == m(1.0));
+        assert!(m(0.04).cmp(&m(0.06)) == Ordering::Less);
+        assert!(m(-0.04) == m(0.04));
+        assert!(m(-0.0) == m(0.0));
+        assert!(m(0.2).cmp(&m(0.1)) == Ordering::Greater);
+        assert!(m(f64::NAN) == m(-f64::NAN));
+        assert!(m(f64::NAN).cmp(&m(f64::INFINITY)) == Ordering::Greater);
+        assert!(m(f64::NEG_INFINITY).cmp(&m(-1e300)) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let values = [
+            0.5,
+            -0.5,
+            1.5,
+            2.5,
+            -2.5,
+            0.49999999999999994,
+            -0.49999999999999994,
+            4503599627370495.5,
+            4503599627370497.0,
+            -4503599627370495.5,
+            1234.4999,
+        ];
+
+        for &value in &values {
+            assert!(NumericF64Eps(value, 1.0) == NumericF64Eps(value.round(), 1.0));
+            assert!(NumericF64Eps(value, 1.0) != NumericF64Eps(value.round() + 1.0, 1.0));
+        }
+    }
+
+    /// Naive `(a - b).abs() < eps` equality is not transitive, buckets are
+    #[test]
+    fn test_transitivity() {
+        let naive_eq = |a: f64, b: f64| (a - b).abs() < 0.1;
+        assert!(naive_eq(0.0, 0.06) && naive_eq(0.06, 0.12) && !naive_eq(0.0, 0.12));
+
+        let values = values();
+
+        for &a in &values {
+            for &b in &values {
+                let ab = NumericF64Eps(a, 0.1).cmp(&NumericF64Eps(b, 0.1));
+                let ba = NumericF64Eps(b, 0.1).cmp(&NumericF64Eps(a, 0.1));
+                assert_eq!(ab, ba.reverse(), "{} {}", a, b);
+                assert_eq!(
+                    ab == Ordering::Equal,
+                    NumericF64Eps(a, 0.1) == NumericF64Eps(b, 0.1)
+                );
+
+                for &c in &values {
+                    let bc = NumericF64Eps(b, 0.1).cmp(&NumericF64Eps(c, 0.1));
+                    let ac = NumericF64Eps(a, 0.1).cmp(&NumericF64Eps(c, 0.1));
+
+                    if ab == bc || bc == Ordering::Equal {
+                        assert_eq!(ac, ab, "{} {} {}", a, b, c);
+                    } else if ab == Ordering::Equal {
+                        assert_eq!(ac, bc, "{} {} {}", a, b, c);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut measurements: Vec<_> = values()
+            .into_iter()
+            .map(|value| Measurement { value })
+            .collect();
+
+        measurements.sort();
+
+        for pair in measurements.windows(2) {
+            assert!(pair[0] <= pair[1]);
+        }
+    }
+}
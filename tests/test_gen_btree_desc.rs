@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|s| s.0; gen_btree_desc = Leaderboard)]
+    #[derive(Clone, Copy, Debug)]
+    pub struct Score(pub u32);
+
+    #[ord_eq_by_key_selector(|n| &n.0; gen_btree_desc = Names, vis = pub(crate))]
+    #[derive(Clone, Debug)]
+    pub struct Name(pub String);
+
+    #[ord_eq_by_key_selector(|w| &w.0; gen_btree_desc = ByWrapper)]
+    #[derive(Clone, Debug)]
+    pub struct Wrapper<V: Ord>(pub V);
+
+    fn board() -> Leaderboard<&'static str> {
+        vec![(Score(10), "b"), (Score(30), "c"), (Score(20), "a")]
+            .into_iter()
+            .collect()
+    }
+
+    fn scores<'a>(entries: impl Iterator<Item = (&'a Score, &'a &'static str)>) -> Vec<u32> {
+        entries.map(|(score, _)| score.0).collect()
+    }
+
+    #[test]
+    fn test_descending() {
+        let board = board();
+
+        assert!(scores(board.iter()) == [30, 20, 10]);
+        assert!(scores(board.iter().rev()) == [10, 20, 30]);
+        assert!(board.len() == 3);
+        assert!(!board.is_empty());
+        assert!(board.as_map().keys().next().map(|Reverse(s)| s.0) == Some(30));
+    }
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut board = board();
+
+        assert!(board.insert(Score(20), "d") == Some("a"));
+        assert!(board.insert(Score(5), "e").is_none());
+        assert!(board.get(&Score(20)) == Some(&"d"));
+        assert!(board.get(&Score(15)).is_none());
+
+        if let Some(name) = board.get_mut(&Score(5)) {
+            *name = "f";
+        }
+        assert!(board.get(&Score(5)) == Some(&"f"));
+
+        assert!(board.contains_key(&Score(30)));
+        assert!(board.remove(&Score(30)) == Some("c"));
+        assert!(!board.contains_key(&Score(30)));
+        assert!(scores(board.iter()) == [20, 10, 5]);
+    }
+
+    #[test]
+    fn test_range() {
+        let board = board();
+
+        assert!(scores(board.range(Score(10)..Score(30))) == [20, 10]);
+        assert!(scores(board.range(Score(10)..=Score(30))) == [30, 20, 10]);
+        assert!(scores(board.range(Score(15)..)) == [30, 20]);
+        assert!(scores(board.range(..Score(20))) == [10]);
+        assert!(scores(board.range(Score(20)..Score(20))).is_empty());
+    }
+
+    #[test]
+    fn test_default_extend() {
+        let mut names = Names::default();
+        assert!(names.is_empty());
+
+        names.extend(vec![(Name("a".to_string()), 1), (Name("c".to_string()), 2)]);
+        names.insert(Name("b".to_string()), 3);
+
+        let keys: Vec<_> = names.iter().map(|(name, _)| name.0.as_str()).collect();
+        assert!(keys == ["c", "b", "a"]);
+        assert!(names.get(&Name("c".to_string())) == Some(&2));
+    }
+
+    #[test]
+    fn test_generics() {
+        let mut map = ByWrapper::new();
+        map.insert(Wrapper(1), "one");
+        map.insert(Wrapper(2), "two");
+
+        let values: Vec<_> = map.iter().map(|(_, v)| *v).collect();
+        assert!(values == ["two", "one"]);
+        assert!(map.get(&Wrapper(1)) == Some(&"one"));
+    }
+}
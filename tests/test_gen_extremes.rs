@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|(p, name)| p; gen_extremes = highest/lowest)]
+    #[derive(Debug)]
+    pub struct Priority(u8, &'static str);
+
+    #[ord_eq_by_key_selector(|t| t.due; gen_extremes = latest/earliest,)]
+    #[derive(Debug)]
+    pub struct Task<'a> {
+        pub due: u32,
+        pub name: &'a str,
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(Priority(1, "a").highest(Priority(3, "b")).1, "b");
+        assert_eq!(Priority(3, "a").highest(Priority(1, "b")).1, "a");
+        assert_eq!(Priority(1, "a").lowest(Priority(3, "b")).1, "a");
+        assert_eq!(Priority(3, "a").lowest(Priority(1, "b")).1, "b");
+
+        let a = Task { due: 1, name: "a" };
+        let b = Task { due: 2, name: "b" };
+
+        assert_eq!(a.latest(b).name, "b");
+    }
+
+    #[test]
+    fn test_ties_same_as_ord() {
+        // `Ord::max` returns the second argument and `Ord::min` returns the first one on ties
+        assert_eq!(Priority(1, "a").highest(Priority(1, "b")).1, "b");
+        assert_eq!(Priority(1, "a").lowest(Priority(1, "b")).1, "a");
+    }
+}
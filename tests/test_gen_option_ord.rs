@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|t| t.priority; gen_option_ord = none_first)]
+    #[derive(Debug)]
+    pub struct Task {
+        pub priority: u32,
+    }
+
+    #[ord_eq_by_key_selector(|(p)| p; gen_option_ord = none_last)]
+    #[derive(Debug)]
+    pub struct Price(u32);
+
+    #[ord_eq_by_key_selector(|(n, _t)| n; gen_option_ord = none_last)]
+    pub struct Tagged<'a, T>(u32, &'a T);
+
+    fn task(priority: Option<u32>) -> OptTask {
+        priority.map(|priority| Task { priority }).into()
+    }
+
+    fn price(price: Option<u32>) -> OptPrice {
+        OptPrice::from(price.map(Price))
+    }
+
+    #[test]
+    fn test_none_first() {
+        assert!(task(None).cmp(&task(Some(0))) == Ordering::Less);
+        assert!(task(Some(0)).cmp(&task(None)) == Ordering::Greater);
+        assert!(task(None).cmp(&task(None)) == Ordering::Equal);
+        assert!(task(Some(2)).cmp(&task(Some(1))) == Ordering::Greater);
+        assert!(task(Some(1)) == task(Some(1)));
+        assert!(task(None) != task(Some(1)));
+
+        let mut tasks = [task(Some(3)), task(None), task(Some(1)), task(None)];
+        tasks.sort();
+
+        let priorities: Vec<_> = tasks
+            .iter()
+            .map(|t| t.0.as_ref().map(|t| t.priority))
+            .collect();
+        assert_eq!(priorities, [None, None, Some(1), Some(3)]);
+    }
+
+    #[test]
+    fn test_none_last() {
+        assert!(price(None).cmp(&price(Some(0))) == Ordering::Greater);
+        assert!(price(Some(0)).cmp(&price(None)) == Ordering::Less);
+        assert!(price(None).cmp(&price(None)) == Ordering::Equal);
+        assert!(price(Some(1)).cmp(&price(Some(2))) == Ordering::Less);
+
+        let mut prices = [price(None), price(Some(5)), price(Some(1))];
+        prices.sort();
+
+        let prices: Vec<_> = prices.iter().map(|p| p.0.as_ref().map(|p| p.0)).collect();
+        assert_eq!(prices, [Some(1), Some(5), None]);
+    }
+
+    #[test]
+    fn test_generic() {
+        let payload = String::from("payload");
+
+        let some = |n| OptTagged::from(Some(Tagged(n, &payload)));
+
+        assert!(some(1).cmp(&some(2)) == Ordering::Less);
+        assert!(OptTagged(None).cmp(&some(2)) == Ordering::Greater);
+        assert!(OptTagged::<String>(None) == OptTagged(None));
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|t| t.priority; fifo_tiebreak, gen_min_queue = TaskQueue)]
+    pub struct Task {
+        pub priority: u32,
+        pub name: &'static str,
+    }
+
+    #[ord_eq_by_key_selector(|s| s.0; gen_min_queue = MinScores, gen_max_queue = MaxScores)]
+    #[derive(Debug)]
+    pub struct Score(pub u32, pub &'static str);
+
+    #[ord_eq_by_key_selector(|w| &w.0; gen_max_queue = WrapperQueue, vis = pub(crate))]
+    pub struct Wrapper<T: Ord>(pub T);
+
+    fn drain_min(mut queue: MinScores) -> Vec<u32> {
+        ::core::iter::from_fn(|| queue.pop()).map(|s| s.0).collect()
+    }
+
+    fn drain_max(mut queue: MaxScores) -> Vec<u32> {
+        ::core::iter::from_fn(|| queue.pop()).map(|s| s.0).collect()
+    }
+
+    #[test]
+    fn test_min_queue() {
+        let mut queue = MinScores::new();
+        assert!(queue.is_empty());
+        assert!(queue.peek().is_none());
+
+        queue.push(Score(3, "c"));
+        queue.push(Score(1, "a"));
+        queue.push(Score(2, "b"));
+
+        assert!(queue.len() == 3);
+        assert!(queue.peek().map(|s| s.1) == Some("a"));
+        assert!(drain_min(queue) == [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_max_queue() {
+        let mut queue = MaxScores::default();
+        queue.extend(vec![Score(3, "c"), Score(1, "a"), Score(2, "b")]);
+
+        assert!(queue.len() == 3);
+        assert!(queue.peek().map(|s| s.1) == Some("c"));
+        assert!(drain_max(queue) == [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let scores = [5, 3, 8, 1];
+
+        let min: MinScores = scores.iter().map(|&s| Score(s, "")).collect();
+        let max: MaxScores = scores.iter().map(|&s| Score(s, "")).collect();
+
+        assert!(drain_min(min) == [1, 3, 5, 8]);
+        assert!(drain_max(max) == [8, 5, 3, 1]);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut queue: MaxScores = vec![Score(1, "a"), Score(2, "b")].into_iter().collect();
+        queue.clear();
+
+        assert!(queue.is_empty());
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn test_fifo() {
+        let mut queue = TaskQueue::new();
+        queue.push(Task::new(2, "later"));
+        queue.push(Task::new(1, "first"));
+        queue.push(Task::new(0, "urgent"));
+        queue.push(Task::new(1, "second"));
+        queue.push(Task::new(1, "third"));
+
+        let order: Vec<_> = ::core::iter::from_fn(|| queue.pop())
+            .map(|t| t.name)
+            .collect();
+        assert!(order == ["urgent", "first", "second", "third", "later"]);
+    }
+
+    #[test]
+    fn test_generics() {
+        let mut queue = WrapperQueue::new();
+        queue.push(Wrapper("b"));
+        queue.push(Wrapper("c"));
+        queue.push(Wrapper("a"));
+
+        assert!(queue.pop().map(|w| w.0) == Some("c"));
+        assert!(queue.peek().map(|w| w.0.cmp("b")) == Some(Ordering::Equal));
+        assert!(queue.len() == 2);
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cell::Cell;
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    thread_local! {
+        static KEYS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[ord_eq_by_key_selector(|f| f.lowercase_path(), f.size; gen_sorted)]
+    #[derive(Debug)]
+    pub struct File {
+        pub path: &'static str,
+        pub size: u64,
+    }
+
+    impl File {
+        fn lowercase_path(&self) -> String {
+            KEYS.with(|keys| keys.set(keys.get() + 1));
+            self.path.to_lowercase()
+        }
+    }
+
+    #[ord_eq_by_key_selector(|j| j.priority; gen_sorted, fifo_tiebreak)]
+    pub struct Job {
+        pub priority: u32,
+        pub name: &'static str,
+    }
+
+    #[ord_eq_by_key_selector(|(n)| n.to_string(); gen_sorted, vis = pub(crate))]
+    pub struct Label(pub u32);
+
+    fn file(path: &'static str, size: u64) -> File {
+        File { path, size }
+    }
+
+    fn paths(files: &[File]) -> Vec<(&'static str, u64)> {
+        files.iter().map(|f| (f.path, f.size)).collect()
+    }
+
+    #[test]
+    fn test_sorted() {
+        let files = File::sorted(vec![file("b", 2), file("A", 1), file("a", 0), file("B", 1)]);
+
+        assert!(paths(&files) == [("a", 0), ("A", 1), ("B", 1), ("b", 2)]);
+        assert!(files[0].cmp(&files[1]) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_sorted_by_cached_key() {
+        let values = vec![file("b", 2), file("A", 1), file("a", 0), file("B", 1)];
+        let mut expected: Vec<_> = paths(&values);
+        expected.sort_by_key(|&(path, size)| (path.to_lowercase(), size));
+
+        KEYS.with(|keys| keys.set(0));
+        let files = File::sorted_by_cached_key(values);
+
+        assert!(paths(&files) == expected);
+        assert!(KEYS.with(|keys| keys.get()) == 4);
+    }
+
+    #[test]
+    fn test_sorted_from_iterator() {
+        let files = File::sorted(["c", "a", "b"].iter().map(|&path| file(path, 0)));
+        assert!(paths(&files) == [("a", 0), ("b", 0), ("c", 0)]);
+
+        let files = File::sorted_by_cached_key(::core::iter::empty());
+        assert!(files.is_empty());
+    }
+
+    #[test]
+    fn test_sorted_final_key() {
+        let second = Job::new(1, "second");
+        let urgent = Job::new(0, "urgent");
+        let first = Job::new(1, "first");
+
+        let names = |jobs: Vec<Job>| jobs.iter().map(|j| j.name).collect::<Vec<_>>();
+
+        // `sorted` compares sequence numbers, the cached variant keeps the order of equal keys
+        assert!(names(Job::sorted(vec![first, urgent, second])) == ["urgent", "second", "first"]);
+        assert!(
+            names(Job::sorted_by_cached_key(vec![
+                Job::new(1, "first"),
+                Job::new(0, "urgent"),
+                Job::new(1, "second"),
+            ])) == ["urgent", "first", "second"]
+        );
+    }
+
+    #[test]
+    fn test_sorted_tuple_struct() {
+        let labels = Label::sorted_by_cached_key(vec![Label(10), Label(9), Label(100)]);
+        let labels: Vec<_> = labels.iter().map(|l| l.0).collect();
+
+        assert!(labels == [10, 100, 9]);
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|(s, t)| s, t)]
+    pub struct Entry<'a, T: Ord>(&'a str, T);
+
+    #[ord_eq_by_key_selector(|(s, t)| s)]
+    pub struct Tagged<'a, T>(&'a str, T);
+
+    #[ord_eq_by_key_selector(|e| e.name, &e.value)]
+    pub struct Named<'a, T>
+    where
+        T: Ord,
+    {
+        pub name: &'a str,
+        pub value: T,
+    }
+
+    #[ord_eq_by_key_selector(|e| e.name, e.values.len(), e.values)]
+    pub struct Borrowed<'a, 'b, T: Ord + 'b> {
+        pub name: &'a str,
+        pub values: &'b [T],
+    }
+
+    #[ord_eq_by_key_selector(|(s)| s)]
+    pub struct Array<'a, const N: usize>(&'a [u8; N]);
+
+    /// Doesn't implement `Ord`
+    pub struct Payload;
+
+    #[test]
+    fn test_type_in_key() {
+        assert!(Entry("a", 2).cmp(&Entry("a", 1)) == Ordering::Greater);
+        assert!(Entry("a", 2).cmp(&Entry("b", 1)) == Ordering::Less);
+        assert!(Entry("a", "x").cmp(&Entry("a", "x")) == Ordering::Equal);
+
+        let name = String::from("a");
+        let value = vec![1, 2];
+        let named = Named {
+            name: &name,
+            value: &value,
+        };
+        assert!(
+            named.cmp(&Named {
+                name: "a",
+                value: &vec![1, 3],
+            }) == Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_type_not_in_key() {
+        assert!(Tagged("a", Payload).cmp(&Tagged("b", Payload)) == Ordering::Less);
+        assert!(Tagged("a", Payload).cmp(&Tagged("a", Payload)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_multiple_lifetimes() {
+        let values = vec![3, 1];
+        let borrowed = |name, values| Borrowed { name, values };
+
+        assert!(borrowed("a", &values).cmp(&borrowed("a", &[1, 2, 3])) == Ordering::Less);
+        assert!(borrowed("a", &values).cmp(&borrowed("a", &[3, 0])) == Ordering::Greater);
+        assert!(borrowed("a", &values).cmp(&borrowed("b", &[])) == Ordering::Less);
+
+        assert!(Array(&[1, 2]).cmp(&Array(&[1, 3])) == Ordering::Less);
+    }
+}
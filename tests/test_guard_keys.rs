@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::ByDeref;
+    use std::cell::Ref;
+    use std::cell::RefCell;
+    use std::sync::Mutex;
+
+    #[ord_eq_by_key_selector(|l| ByDeref(l.name.borrow()))]
+    #[derive(Debug)]
+    pub struct Label {
+        pub name: RefCell<String>,
+    }
+
+    /// Guard key followed by a regular key, and a guard mapped to a part of the value
+    #[ord_eq_by_key_selector(|t|
+        ByDeref(Ref::map(t.title.borrow(), |title| title.trim())),
+        t.id,
+    )]
+    #[derive(Debug)]
+    pub struct Ticket {
+        pub title: RefCell<String>,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(|(value)| ByDeref(value.lock().unwrap()))]
+    #[derive(Debug)]
+    pub struct Shared(Mutex<u32>);
+
+    fn label(name: &str) -> Label {
+        Label {
+            name: RefCell::new(name.to_string()),
+        }
+    }
+
+    fn ticket(title: &str, id: u32) -> Ticket {
+        Ticket {
+            title: RefCell::new(title.to_string()),
+            id,
+        }
+    }
+
+    #[test]
+    fn test_ref_cell_contents() {
+        let a = label("apple");
+        let b = label("banana");
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(a == label("apple"));
+
+        // Guards are released after comparison, so the contents can be changed
+        b.name.borrow_mut().replace_range(.., "aardvark");
+        assert!(a.cmp(&b) == Ordering::Greater);
+
+        // Shared borrows of the same cell don't conflict
+        assert!(a.cmp(&a) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_mapped_guard_then_key() {
+        assert!(ticket("  fix", 2).cmp(&ticket("fix ", 1)) == Ordering::Greater);
+        assert!(ticket("a", 9).cmp(&ticket(" b", 0)) == Ordering::Less);
+        assert!(ticket(" a ", 1) == ticket("a", 1));
+    }
+
+    #[test]
+    fn test_mutex_guard() {
+        assert!(Shared(Mutex::new(1)).cmp(&Shared(Mutex::new(2))) == Ordering::Less);
+        assert!(Shared(Mutex::new(3)) == Shared(Mutex::new(3)));
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::HasKey;
+    use ord_by_key::Merged;
+
+    #[ord_eq_by_key_selector(|e| e.timestamp; gen_has_key = u64)]
+    #[derive(Debug)]
+    pub struct LocalEvent {
+        pub timestamp: u64,
+        pub pid: u32,
+    }
+
+    #[ord_eq_by_key_selector(|e| e.received_at; gen_has_key = u64)]
+    #[derive(Debug)]
+    pub struct RemoteEvent {
+        pub received_at: u64,
+        pub host: &'static str,
+    }
+
+    fn local(timestamp: u64, pid: u32) -> LocalEvent {
+        LocalEvent { timestamp, pid }
+    }
+
+    fn remote(received_at: u64, host: &'static str) -> RemoteEvent {
+        RemoteEvent { received_at, host }
+    }
+
+    /// Describes merged item, since `PartialEq` of events compares only keys
+    fn describe(item: Merged<LocalEvent, RemoteEvent>) -> String {
+        match item {
+            Merged::Left(e) => format!("local {} {}", e.timestamp, e.pid),
+            Merged::Right(e) => format!("remote {} {}", e.received_at, e.host),
+        }
+    }
+
+    #[test]
+    fn test_merge_by_key() {
+        let locals = vec![local(1, 10), local(3, 11), local(3, 12), local(8, 13)];
+        let remotes = vec![remote(0, "a"), remote(3, "b"), remote(9, "c")];
+
+        let merged: Vec<_> = ord_by_key::merge_by_key(locals, remotes)
+            .map(describe)
+            .collect();
+
+        assert_eq!(
+            merged,
+            [
+                "remote 0 a",
+                "local 1 10",
+                // Ties keep order of sequences, first sequence goes first
+                "local 3 11",
+                "local 3 12",
+                "remote 3 b",
+                "local 8 13",
+                "remote 9 c",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        let merged: Vec<_> =
+            ord_by_key::merge_by_key(Vec::<LocalEvent>::new(), vec![remote(1, "a")])
+                .map(describe)
+                .collect();
+        assert_eq!(merged, ["remote 1 a"]);
+
+        let merged: Vec<_> =
+            ord_by_key::merge_by_key(Vec::<LocalEvent>::new(), Vec::<RemoteEvent>::new()).collect();
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn test_keyed_merge_by_reference() {
+        let locals = [local(2, 1), local(4, 2)];
+        let remotes = [remote(1, "a"), remote(5, "b")];
+
+        let order: Vec<_> =
+            ord_by_key::keyed_merge(&locals, &remotes, |e| e.timestamp, |e| e.received_at)
+                .map(|item| match item {
+                    Merged::Left(e) => e.timestamp,
+                    Merged::Right(e) => e.received_at,
+                })
+                .collect();
+
+        assert_eq!(order, [1, 2, 4, 5]);
+    }
+
+    #[ord_eq_by_key_selector(|r| r.priority, r.name.clone(); gen_has_key = (u8, String))]
+    pub struct Request {
+        pub priority: u8,
+        pub name: String,
+    }
+
+    #[ord_eq_by_key_selector(methods = [weight]; gen_has_key = u32)]
+    pub struct Parcel {
+        pub grams: u32,
+    }
+
+    impl Parcel {
+        fn weight(&self) -> u32 {
+            self.grams / 1000
+        }
+    }
+
+    #[ord_eq_by_key_selector(|(v)| v.clone(); gen_has_key = T)]
+    pub struct Wrapper<T: Ord + Clone>(T);
+
+    #[test]
+    fn test_generated_keys() {
+        let request = Request {
+            priority: 2,
+            name: "sync".to_string(),
+        };
+        assert_eq!(request.key(), (2, "sync".to_string()));
+
+        assert_eq!(Parcel { grams: 2500 }.key(), 2);
+        assert_eq!(Wrapper("a").key(), "a");
+    }
+
+    /// Generic code over `HasKey` accepts generated and hand-written implementations
+    fn max_key<T: HasKey>(values: &[T]) -> Option<T::Key> {
+        values.iter().map(HasKey::key).max()
+    }
+
+    pub struct Manual(i32);
+
+    impl HasKey for Manual {
+        type Key = i32;
+
+        fn key(&self) -> i32 {
+            -self.0
+        }
+    }
+
+    #[test]
+    fn test_generic_algorithm() {
+        assert_eq!(max_key(&[local(3, 0), local(7, 0)]), Some(7));
+        assert_eq!(max_key(&[Manual(3), Manual(7)]), Some(-3));
+        assert_eq!(max_key::<Manual>(&[]), None);
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::StableHash;
+    use std::collections::BTreeSet;
+
+    /// Labels can't be meaningfully ordered, and `notes` is neither `Ord` nor `Hash`
+    #[ord_eq_by_key_selector(|i| i.priority; hash_residual = [labels, author])]
+    #[derive(Debug)]
+    pub struct Issue {
+        pub priority: u32,
+        pub labels: Vec<&'static str>,
+        pub author: &'static str,
+        pub notes: Vec<f64>,
+    }
+
+    #[ord_eq_by_key_selector(|t| t.0; hash_residual = [1])]
+    #[derive(Debug)]
+    pub struct Tagged(pub u8, pub [u16; 2]);
+
+    fn issue(priority: u32, labels: &[&'static str], author: &'static str) -> Issue {
+        Issue {
+            priority,
+            labels: labels.to_vec(),
+            author,
+            notes: vec![f64::NAN],
+        }
+    }
+
+    #[test]
+    fn test_keys_decide_first() {
+        assert!(issue(0, &["z"], "z").cmp(&issue(1, &["a"], "a")) == Ordering::Less);
+        assert!(issue(2, &[], "").cmp(&issue(1, &["a"], "a")) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_hashed_fields_break_ties() {
+        let a = issue(1, &["bug"], "alice");
+        let b = issue(1, &["docs"], "alice");
+        let c = issue(1, &["bug"], "bob");
+
+        assert!(a != b);
+        assert!(a != c);
+        assert!(a == issue(1, &["bug"], "alice"));
+        assert!(a.notes[0].is_nan());
+
+        // Relative order is defined by hashes of the listed fields in the listed order
+        let hash = |i: &Issue| StableHash(&(&i.labels, &i.author)).value();
+        assert_eq!(a.cmp(&b), hash(&a).cmp(&hash(&b)));
+        assert_eq!(a.cmp(&c), hash(&a).cmp(&hash(&c)));
+        assert_eq!(b.cmp(&a), a.cmp(&b).reverse());
+    }
+
+    #[test]
+    fn test_stable_relative_order() {
+        let make = || {
+            vec![
+                issue(1, &["docs"], "alice"),
+                issue(0, &["bug"], "bob"),
+                issue(1, &["bug"], "alice"),
+                issue(1, &["bug", "ui"], "carol"),
+                issue(1, &[], "alice"),
+            ]
+        };
+
+        let describe = |issues: &[Issue]| -> Vec<_> {
+            issues
+                .iter()
+                .map(|i| (i.priority, i.labels.clone(), i.author))
+                .collect()
+        };
+
+        let mut forward = make();
+        forward.sort();
+        let mut backward = make();
+        backward.reverse();
+        backward.sort();
+
+        // Same order regardless of the input order, and recorded to catch changes of the hash
+        assert_eq!(describe(&forward), describe(&backward));
+        assert_eq!(
+            describe(&forward),
+            [
+                (0, vec!["bug"], "bob"),
+                (1, vec!["bug"], "alice"),
+                (1, vec!["bug", "ui"], "carol"),
+                (1, vec![], "alice"),
+                (1, vec!["docs"], "alice"),
+            ]
+        );
+
+        let set: BTreeSet<_> = make().into_iter().chain(make()).collect();
+        assert_eq!(set.len(), 5);
+    }
+
+    #[test]
+    fn test_unnamed_fields() {
+        assert!(Tagged(1, [1, 2]) != Tagged(1, [2, 1]));
+        assert!(Tagged(1, [1, 2]) == Tagged(1, [1, 2]));
+        assert!(Tagged(0, [9, 9]) < Tagged(1, [0, 0]));
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    static ALPHABET: [char; 3] = ['c', 'b', 'a'];
+
+    /// Owns the data, helpers borrow from fields of the struct
+    #[ord_eq_by_key_selector(|f|
+        ord_by_key::NumericStr(&f.name),
+        ord_by_key::Utf16Order(&f.name),
+        ord_by_key::PathOrd(&f.path, '/'),
+        ord_by_key::LastSegment(&f.path, "/"),
+        ord_by_key::Bytes(&f.data),
+        ord_by_key::chained(&f.name, &f.path),
+        ord_by_key::StableHash(&f.name),
+        ord_by_key::WithAlphabet(&f.name, &ALPHABET),
+        ord_by_key::by_len_then(&f.data),
+        ord_by_key::NoneFirst(f.tag.as_deref()))]
+    #[derive(Debug)]
+    pub struct Owned {
+        pub name: String,
+        pub path: String,
+        pub data: Vec<u8>,
+        pub tag: Option<String>,
+    }
+
+    /// Same keys as `Owned`, helpers borrow with the lifetime of the struct
+    #[ord_eq_by_key_selector(|f|
+        ord_by_key::NumericStr(f.name),
+        ord_by_key::Utf16Order(f.name),
+        ord_by_key::PathOrd(f.path, '/'),
+        ord_by_key::LastSegment(f.path, "/"),
+        ord_by_key::Bytes(f.data),
+        ord_by_key::chained(f.name, f.path),
+        ord_by_key::StableHash(f.name),
+        ord_by_key::WithAlphabet(f.name, &ALPHABET),
+        ord_by_key::by_len_then(f.data),
+        ord_by_key::NoneFirst(f.tag))]
+    #[derive(Debug)]
+    pub struct Borrowed<'a> {
+        pub name: &'a str,
+        pub path: &'a str,
+        pub data: &'a [u8],
+        pub tag: Option<&'a str>,
+    }
+
+    /// Tuple struct borrowing with a named lifetime, keys borrow through the field
+    #[ord_eq_by_key_selector(|(s)| ord_by_key::NumericStr(s), ord_by_key::ByDeref(s))]
+    #[derive(Debug)]
+    pub struct Name<'a>(&'a str);
+
+    /// Different lifetimes of fields, keys borrow from both
+    #[ord_eq_by_key_selector(|p| ord_by_key::LastSegment(p.path, p.separator), ord_by_key::Utf16Order(p.path))]
+    #[derive(Debug)]
+    pub struct Path<'p, 's> {
+        pub path: &'p str,
+        pub separator: &'s str,
+    }
+
+    /// Borrowed struct with a generic payload, keys only borrow the string
+    #[ord_eq_by_key_selector(|e| ord_by_key::chained(e.name, e.kind), ord_by_key::StableHash(e.name))]
+    #[derive(Debug)]
+    pub struct Entry<'a, T> {
+        pub name: &'a str,
+        pub kind: &'static str,
+        pub payload: T,
+    }
+
+    fn owned(name: &str, path: &str, data: &[u8], tag: Option<&str>) -> Owned {
+        Owned {
+            name: name.to_string(),
+            path: path.to_string(),
+            data: data.to_vec(),
+            tag: tag.map(String::from),
+        }
+    }
+
+    fn borrowed<'a>(
+        name: &'a str,
+        path: &'a str,
+        data: &'a [u8],
+        tag: Option<&'a str>,
+    ) -> Borrowed<'a> {
+        Borrowed {
+            name,
+            path,
+            data,
+            tag,
+        }
+    }
+
+    #[test]
+    fn test_owned_and_borrowed_agree() {
+        let inputs: [(&str, &str, &[u8], Option<&str>); 6] = [
+            ("10", "a/b", b"x", None),
+            ("9", "a/b", b"x", None),
+            ("9", "a.txt", b"x", None),
+            ("9", "a", b"x", Some("t")),
+            ("9", "a", b"w", None),
+            ("9", "a", b"x", None),
+        ];
+
+        for a in &inputs {
+            for b in &inputs {
+                let by_owned = owned(a.0, a.1, a.2, a.3).cmp(&owned(b.0, b.1, b.2, b.3));
+                let by_borrowed = borrowed(a.0, a.1, a.2, a.3).cmp(&borrowed(b.0, b.1, b.2, b.3));
+                assert_eq!(by_owned, by_borrowed, "{:?} and {:?}", a, b);
+            }
+        }
+
+        assert!(owned("9", "", b"", None).cmp(&owned("10", "", b"", None)) == Ordering::Less);
+        assert!(borrowed("9", "a", b"", None) < borrowed("9", "a/b", b"", None));
+        assert!(borrowed("f", "a", b"", None) < borrowed("f", "a", b"", Some("")));
+        assert!(borrowed("f", "a", b"x", Some("t")) == borrowed("f", "a", b"x", Some("t")));
+    }
+
+    #[test]
+    fn test_borrowed_outlives_source() {
+        let source = String::from("2 10 1");
+        let mut names: Vec<_> = source.split(' ').map(Name).collect();
+        names.sort();
+
+        let sorted: Vec<_> = names.iter().map(|n| n.0).collect();
+        assert_eq!(sorted, ["1", "2", "10"]);
+    }
+
+    #[test]
+    fn test_different_lifetimes() {
+        let separator = String::from("::");
+        let path = |path| Path {
+            path,
+            separator: &separator,
+        };
+
+        assert!(path("b::alpha").cmp(&path("a::beta")) == Ordering::Less);
+        assert!(path("a::alpha").cmp(&path("b::alpha")) == Ordering::Less);
+        assert!(path("x::y") == path("x::y"));
+    }
+
+    #[test]
+    fn test_generic_payload() {
+        let name = String::from("config");
+        let a = Entry {
+            name: &name,
+            kind: "dir",
+            payload: vec![1],
+        };
+        let b = Entry {
+            name: &name,
+            kind: "file",
+            payload: vec![2],
+        };
+
+        assert!(a < b);
+        assert!(a.cmp(&a) == Ordering::Equal);
+        assert!(a.payload < b.payload);
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::InlineSeq;
+
+    /// Minimal stand-in for inline-capacity vectors such as `SmallVec`, ordered like a slice
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    pub struct TinyVec {
+        items: [u8; 3],
+        len: usize,
+    }
+
+    impl TinyVec {
+        fn new(values: &[u8]) -> Self {
+            let mut items = [0; 3];
+            items[..values.len()].copy_from_slice(values);
+            TinyVec {
+                items,
+                len: values.len(),
+            }
+        }
+    }
+
+    #[ord_eq_by_key_selector(|t| TinyVec::new(&t.0[..t.0.len().min(3)]))]
+    pub struct ByTinyVec(&'static [u8]);
+
+    #[ord_eq_by_key_selector(|v| v.digits.iter().copied().collect::<InlineSeq<u8, 3>>())]
+    #[derive(Debug)]
+    pub struct Version {
+        pub digits: Vec<u8>,
+    }
+
+    fn version(digits: &[u8]) -> Version {
+        Version {
+            digits: digits.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_any_ord_sequence() {
+        // Keys of any `Ord` sequence type compare by their own `Ord`
+        assert!(ByTinyVec(&[1, 2]) < ByTinyVec(&[1, 3]));
+        assert!(ByTinyVec(&[2]) > ByTinyVec(&[1, 9, 9]));
+    }
+
+    #[test]
+    fn test_under_capacity() {
+        assert!(version(&[1, 2]).cmp(&version(&[1, 3])) == Ordering::Less);
+        assert!(version(&[1]).cmp(&version(&[1, 0])) == Ordering::Less);
+        assert!(version(&[2]).cmp(&version(&[1, 9])) == Ordering::Greater);
+        assert!(version(&[]) < version(&[0]));
+        assert!(version(&[1, 2]) == version(&[1, 2]));
+    }
+
+    #[test]
+    fn test_at_capacity() {
+        let key = InlineSeq::<u8, 3>::new([1, 2, 3]);
+        assert!(key.len() == 3);
+        assert!(!key.is_truncated());
+        assert!(key.iter().eq([1, 2, 3].iter()));
+
+        assert!(version(&[1, 2, 3]).cmp(&version(&[1, 2, 4])) == Ordering::Less);
+        assert!(version(&[1, 2]) < version(&[1, 2, 0]));
+        assert!(version(&[1, 2, 3]) == version(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_over_capacity() {
+        let key = InlineSeq::<u8, 3>::new([1, 2, 3, 4]);
+        assert!(key.len() == 3);
+        assert!(key.is_truncated());
+        assert!(key.iter().eq([1, 2, 3].iter()));
+
+        // Elements after capacity are ignored
+        assert!(version(&[1, 2, 3, 4]) == version(&[1, 2, 3]));
+        assert!(version(&[1, 2, 3, 4]) == version(&[1, 2, 3, 5, 6]));
+        assert!(version(&[1, 2, 3, 9]) < version(&[1, 2, 4]));
+    }
+
+    #[test]
+    fn test_empty() {
+        let key = InlineSeq::<u8, 0>::new([1]);
+        assert!(key.is_empty());
+        assert!(key.is_truncated());
+        assert!(key == InlineSeq::new(None));
+
+        let key = InlineSeq::<u8, 2>::new(None);
+        assert!(key.is_empty());
+        assert!(!key.is_truncated());
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut versions = [
+            version(&[1, 10]),
+            version(&[1, 2, 3, 7]),
+            version(&[0, 9]),
+            version(&[1, 2]),
+        ];
+        versions.sort();
+
+        let sorted: Vec<_> = versions.iter().map(|v| v.digits.clone()).collect();
+        assert!(sorted == [vec![0, 9], vec![1, 2], vec![1, 2, 3, 7], vec![1, 10]]);
+    }
+
+    #[test]
+    fn test_non_copy_elements() {
+        let words = |s: &str| {
+            s.split(' ')
+                .map(String::from)
+                .collect::<InlineSeq<String, 2>>()
+        };
+
+        assert!(words("b a") > words("a z"));
+        assert!(words("a b c") == words("a b d"));
+        assert!(format!("{:?}", words("a b c")) == r#"["a", "b"]"#);
+        assert!(words("a b").clone() == words("a b"));
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use ord_by_key::intern_sort;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Record {
+        pub category: String,
+        pub id: usize,
+    }
+
+    /// Deterministic pseudo-random records with `categories` distinct categories
+    fn records(count: usize, categories: u64) -> Vec<Record> {
+        let mut state = 0x9e3779b97f4a7c15_u64;
+        (0..count)
+            .map(|id| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                Record {
+                    category: format!("category/{}", state % categories),
+                    id,
+                }
+            })
+            .collect()
+    }
+
+    fn check(records: Vec<Record>) {
+        let mut naive = records.clone();
+        naive.sort_by(|a, b| a.category.cmp(&b.category));
+
+        let mut interned = records;
+        intern_sort(&mut interned, |r| &r.category);
+
+        assert!(interned == naive);
+    }
+
+    #[test]
+    fn test_same_as_naive_sort() {
+        check(records(1000, 1));
+        check(records(1000, 7));
+        check(records(1000, 16));
+        check(records(1000, 17));
+        check(records(1000, 100));
+        check(records(1000, 10_000));
+    }
+
+    #[test]
+    fn test_stable() {
+        let mut values = [("b", 0), ("a", 1), ("b", 2), ("a", 3), ("c", 4), ("a", 5)];
+        intern_sort(&mut values, |v| v.0);
+
+        assert!(values == [("a", 1), ("a", 3), ("a", 5), ("b", 0), ("b", 2), ("c", 4)]);
+    }
+
+    #[test]
+    fn test_small_inputs() {
+        let mut empty: [&str; 0] = [];
+        intern_sort(&mut empty, |s| *s);
+
+        let mut single = ["a"];
+        intern_sort(&mut single, |s| *s);
+        assert!(single == ["a"]);
+
+        let mut sorted = ["a", "b", "c"];
+        intern_sort(&mut sorted, |s| *s);
+        assert!(sorted == ["a", "b", "c"]);
+
+        let mut reversed = ["c", "b", "a"];
+        intern_sort(&mut reversed, |s| *s);
+        assert!(reversed == ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_non_string_keys() {
+        let mut values = [
+            (vec![2, 1], 'x'),
+            (vec![1], 'y'),
+            (vec![2], 'z'),
+            (vec![1], 'w'),
+        ];
+        intern_sort(&mut values, |v| &v.0[..]);
+
+        assert!(
+            values
+                == [
+                    (vec![1], 'y'),
+                    (vec![1], 'w'),
+                    (vec![2], 'z'),
+                    (vec![2, 1], 'x')
+                ]
+        );
+    }
+}
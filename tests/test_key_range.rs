@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+    use ord_by_key::key_range;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::HasKey;
+
+    #[ord_eq_by_key_selector(|r| r.region, r.day; gen_has_key = (u8, u32))]
+    #[derive(Debug)]
+    pub struct Record {
+        pub region: u8,
+        pub day: u32,
+        pub amount: i64,
+    }
+
+    fn records() -> Vec<Record> {
+        let mut records: Vec<_> = (0..30)
+            .map(|i| Record {
+                region: (i % 3) as u8,
+                day: i / 3,
+                amount: i as i64,
+            })
+            .collect();
+        records.sort();
+        records
+    }
+
+    fn keys(records: &[Record]) -> Vec<(u8, u32)> {
+        records.iter().map(HasKey::key).collect()
+    }
+
+    #[test]
+    fn test_boundary_predicates() {
+        let records = records();
+
+        let start = records.partition_point(|r| r.lt_key(&(1, 3)));
+        let end = records.partition_point(|r| r.le_key(&(1, 5)));
+        assert_eq!(keys(&records[start..end]), [(1, 3), (1, 4), (1, 5)]);
+
+        assert!(records[start].ge_key(&(1, 3)));
+        assert!(!records[start].gt_key(&(1, 3)));
+        assert!(records[end].gt_key(&(1, 5)));
+    }
+
+    #[test]
+    fn test_range_scan() {
+        let records = records();
+
+        // All days of region 1
+        let region = key_range(&records, (1, 0)..(2, 0));
+        assert_eq!(region.len(), 10);
+        assert!(region.iter().all(|r| r.region == 1));
+
+        let total: i64 = key_range(&records, (2, 2)..=(2, 4))
+            .iter()
+            .map(|r| r.amount)
+            .sum();
+        assert_eq!(total, 8 + 11 + 14);
+
+        assert_eq!(keys(key_range(&records, (2, 8)..)), [(2, 8), (2, 9)]);
+        assert_eq!(keys(key_range(&records, ..(0, 2))), [(0, 0), (0, 1)]);
+        assert_eq!(key_range(&records, ..).len(), 30);
+        assert_eq!(
+            keys(key_range(
+                &records,
+                (Bound::Excluded((0, 7)), Bound::Included((1, 0)))
+            )),
+            [(0, 8), (0, 9), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        let records = records();
+
+        assert!(key_range(&records, (1, 5)..(1, 5)).is_empty());
+        assert!(key_range(&records, (1, 100)..(2, 0)).is_empty());
+        assert!(key_range(&records, (3, 0)..).is_empty());
+        // Reversed range doesn't panic
+        assert!(key_range(&records, (2, 0)..(1, 0)).is_empty());
+        assert!(key_range(&[] as &[Record], ..).is_empty());
+    }
+}
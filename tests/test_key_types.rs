@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|v| v.version.parse().ok(): Option<u32>, &v.version)]
+    pub struct Version {
+        pub version: String,
+    }
+
+    #[ord_eq_by_key_selector(|w| w.text.split(' ').map(|s| s.len()).collect(): Vec<usize>)]
+    pub struct Words {
+        pub text: &'static str,
+    }
+
+    // Return type of the closure is inferred from the annotation
+    #[ord_eq_by_key_selector(|(a, b)| [a, b].iter().map(|&&x| x.into()).max(): Option<u64>, a: &u32)]
+    pub struct Pair(u32, u32);
+
+    #[ord_eq_by_key_selector(|r| (r.width + r.height): u32, r.width.into(): u64)]
+    pub struct Rect {
+        pub width: u32,
+        pub height: u32,
+    }
+
+    #[test]
+    fn test_parse() {
+        let version = |v: &str| Version {
+            version: v.to_string(),
+        };
+
+        assert!(version("10").cmp(&version("9")) == Ordering::Greater);
+        assert!(version("x").cmp(&version("9")) == Ordering::Less);
+        assert!(version("09").cmp(&version("9")) == Ordering::Less);
+        assert!(version("9") == version("9"));
+    }
+
+    #[test]
+    fn test_collect() {
+        assert!(Words { text: "aaa b" }.cmp(&Words { text: "bb bb" }) == Ordering::Greater);
+        assert!(Words { text: "ab c" } == Words { text: "xy z" });
+    }
+
+    #[test]
+    fn test_closure() {
+        assert!(Pair(1, 5).cmp(&Pair(4, 4)) == Ordering::Greater);
+        assert!(Pair(1, 5).cmp(&Pair(5, 2)) == Ordering::Less);
+        assert!(Pair(1, 5) == Pair(1, 5));
+    }
+
+    #[test]
+    fn test_parenthesized() {
+        let rect = |width, height| Rect { width, height };
+
+        assert!(rect(2, 3).cmp(&rect(1, 5)) == Ordering::Less);
+        assert!(rect(2, 3).cmp(&rect(3, 2)) == Ordering::Less);
+        assert!(rect(4, 3).cmp(&rect(3, 2)) == Ordering::Greater);
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+    use std::collections::BinaryHeap;
+
+    // Only `K` is bounded, `V` is never compared
+    #[ord_eq_by_key_selector(|(k, v)| k)]
+    pub struct Keyed<K: Ord, V>(pub K, pub V);
+
+    // Neither `Ord` nor `Eq`, nor any other trait
+    pub struct Opaque(pub f64);
+
+    fn call<K: Ord, V: Fn() -> u32>(keyed: &Keyed<K, V>) -> u32 {
+        (keyed.1)()
+    }
+
+    #[test]
+    fn test_opaque_value() {
+        let a = Keyed(1, Opaque(f64::NAN));
+        let b = Keyed(1, Opaque(0.0));
+        let c = Keyed(2, Opaque(f64::NAN));
+
+        assert!(a.cmp(&b) == Ordering::Equal);
+        assert!(a == b);
+        assert!(a < c);
+        assert!(a.1 .0.is_nan() && b.1 .0 == 0.0);
+    }
+
+    #[test]
+    fn test_closure_value() {
+        let offset = 10;
+        let entries = vec![
+            Keyed("b", Box::new(move || offset + 2) as Box<dyn Fn() -> u32>),
+            Keyed("a", Box::new(move || offset + 1)),
+            Keyed("c", Box::new(|| 0)),
+        ];
+
+        let set: BTreeSet<_> = entries.into_iter().collect();
+        let calls: Vec<_> = set.iter().map(|k| (k.0, call(k))).collect();
+
+        assert!(calls == [("a", 11), ("b", 12), ("c", 0)]);
+    }
+
+    #[test]
+    fn test_unnameable_closure_value() {
+        // All values have the type of the same closure
+        let keyed = |key: u32| Keyed(key, move || key * 10);
+        let mut entries = [keyed(2), keyed(1), keyed(3)];
+        entries.sort();
+
+        let calls: Vec<_> = entries.iter().map(call).collect();
+        assert!(calls == [10, 20, 30]);
+        assert!(entries[0] < entries[1]);
+    }
+
+    #[test]
+    fn test_fn_pointer_value() {
+        fn one() -> u32 {
+            1
+        }
+
+        fn two() -> u32 {
+            2
+        }
+
+        let a: Keyed<u8, fn() -> u32> = Keyed(0, one);
+        let b: Keyed<u8, fn() -> u32> = Keyed(0, two);
+
+        assert!(a == b);
+        assert!(call(&a) != call(&b));
+        assert!(a.max(Keyed(1, one)).0 == 1);
+    }
+
+    #[test]
+    fn test_map_entries() {
+        let mut heap = BinaryHeap::new();
+        heap.push(Keyed(3, Opaque(0.3)));
+        heap.push(Keyed(5, Opaque(0.5)));
+        heap.push(Keyed(1, Opaque(0.1)));
+
+        let order: Vec<_> = std::iter::from_fn(|| heap.pop()).map(|k| k.0).collect();
+        assert!(order == [5, 3, 1]);
+
+        // As a key of a map, with non-`Ord` payload
+        let mut map = BTreeMap::new();
+        map.insert(Keyed("x", Opaque(1.0)), 1);
+        map.insert(Keyed("x", Opaque(2.0)), 2);
+
+        assert!(map.len() == 1);
+        assert!(map.get(&Keyed("x", Opaque(9.0))) == Some(&2));
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::LastSegment;
+
+    #[ord_eq_by_key_selector(|s| LastSegment(&s.id, "::"), &s.id)]
+    pub struct Symbol {
+        pub id: String,
+    }
+
+    fn symbol(id: &str) -> Symbol {
+        Symbol { id: id.to_string() }
+    }
+
+    #[test]
+    fn test_ignores_namespace() {
+        let mut symbols: Vec<_> = [
+            "myapp::module::thing",
+            "other::apple",
+            "zebra",
+            "myapp::apple",
+            "b::zebra",
+        ]
+        .iter()
+        .map(|s| symbol(s))
+        .collect();
+
+        symbols.sort();
+
+        let ids: Vec<_> = symbols.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "myapp::apple",
+                "other::apple",
+                "myapp::module::thing",
+                "b::zebra",
+                "zebra"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_segments() {
+        assert!(LastSegment("a::b", "::") == LastSegment("c::b", "::"));
+        assert!(LastSegment("b", "::") == LastSegment("a::b", "::"));
+        assert!(LastSegment("a::b::c", "::") == LastSegment("c", "::"));
+
+        // Trailing separator makes the last segment empty
+        assert!(LastSegment("z::", "::") == LastSegment("", "::"));
+        assert!(LastSegment("z::", "::").cmp(&LastSegment("a", "::")) == Ordering::Less);
+
+        // Empty segments in the middle don't matter
+        assert!(LastSegment("a::::b", "::") == LastSegment("b", "::"));
+
+        // Partial separator is a part of the segment
+        assert!(LastSegment("a:b", "::").cmp(&LastSegment("x::b", "::")) == Ordering::Less);
+
+        // Empty separator compares whole strings
+        assert!(LastSegment("a::b", "").cmp(&LastSegment("b", "")) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_tiebreak() {
+        assert!(symbol("a::x").cmp(&symbol("b::x")) == Ordering::Less);
+        assert!(symbol("b::x").cmp(&symbol("a::y")) == Ordering::Less);
+        assert!(symbol("a::x").cmp(&symbol("a::x")) == Ordering::Equal);
+    }
+}
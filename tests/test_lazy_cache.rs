@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::ByDeref;
+    use std::cell::Cell;
+    use std::cell::OnceCell;
+    use std::cell::RefCell;
+
+    #[ord_eq_by_key_selector(|d| {
+        if d.cache.borrow().is_none() {
+            d.computations.set(d.computations.get() + 1);
+            *d.cache.borrow_mut() = Some(d.name.to_lowercase());
+        }
+
+        ord_by_key::ByDeref(d.cache.borrow())
+    })]
+    pub struct Document {
+        pub name: String,
+        pub cache: RefCell<Option<String>>,
+        pub computations: Cell<u32>,
+    }
+
+    #[ord_eq_by_key_selector(|d| d.cache.get_or_init(|| d.name.to_lowercase()))]
+    pub struct OnceDocument {
+        pub name: String,
+        pub cache: OnceCell<String>,
+    }
+
+    fn document(name: &str) -> Document {
+        Document {
+            name: name.to_string(),
+            cache: RefCell::new(None),
+            computations: Cell::new(0),
+        }
+    }
+
+    #[test]
+    fn test_ref_cell_cache() {
+        let apple = document("apple");
+        let banana = document("Banana");
+        assert!(apple.cache.borrow().is_none());
+
+        assert!(apple.cmp(&banana) == Ordering::Less);
+        assert!(banana.cmp(&apple) == Ordering::Greater);
+        assert!(apple.cmp(&document("APPLE")) == Ordering::Equal);
+        assert!(apple.cmp(&apple) == Ordering::Equal);
+
+        // Computed once per value
+        assert_eq!(apple.cache.borrow().as_deref(), Some("apple"));
+        assert_eq!(apple.computations.get(), 1);
+        assert_eq!(banana.computations.get(), 1);
+
+        // Cache can be reset
+        *apple.cache.borrow_mut() = None;
+        assert!(apple.cmp(&banana) == Ordering::Less);
+        assert_eq!(apple.computations.get(), 2);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut documents: Vec<_> = ["b", "C", "a", "B"].iter().map(|n| document(n)).collect();
+        documents.sort();
+
+        let names: Vec<_> = documents.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["a", "b", "B", "C"]);
+        assert!(documents.iter().all(|d| d.computations.get() == 1));
+    }
+
+    #[test]
+    fn test_once_cell_cache() {
+        let document = |name: &str| OnceDocument {
+            name: name.to_string(),
+            cache: OnceCell::new(),
+        };
+
+        let apple = document("Apple");
+        assert!(apple < document("banana"));
+        assert!(apple == document("APPLE"));
+        assert_eq!(apple.cache.get().map(String::as_str), Some("apple"));
+    }
+
+    #[test]
+    fn test_by_deref() {
+        let a = RefCell::new(1);
+        let b = RefCell::new(2);
+
+        assert!(ByDeref(a.borrow()) < ByDeref(b.borrow()));
+        assert!(ByDeref(Box::new(2)) == ByDeref(Box::new(2)));
+        assert!(ByDeref(String::from("a")) < ByDeref(String::from("b")));
+    }
+}
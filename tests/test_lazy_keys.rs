@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeSet;
+
+    /// Later keys panic when evaluated, so comparison only succeeds if they are skipped
+    #[ord_eq_by_key_selector(|r| r.id, r.group, r.expensive(); gen_cmp_mono)]
+    #[derive(Debug)]
+    pub struct Record {
+        pub id: u32,
+        pub group: u32,
+    }
+
+    impl Record {
+        fn expensive(&self) -> u32 {
+            panic!("expensive key of record {} was evaluated", self.id)
+        }
+    }
+
+    fn record(id: u32, group: u32) -> Record {
+        Record { id, group }
+    }
+
+    #[test]
+    fn test_first_key_decides() {
+        let (a, b) = (record(1, 0), record(2, 0));
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(b.partial_cmp(&a) == Some(Ordering::Greater));
+        assert!(a.cmp_mono(&b) == Ordering::Less);
+        assert!(a < b);
+        assert!(a <= b);
+        assert!(b > a);
+        assert!(b >= a);
+        assert!(a != b);
+        assert!(core::cmp::max(&a, &b).id == 2);
+    }
+
+    #[test]
+    fn test_second_key_decides() {
+        let (a, b) = (record(1, 0), record(1, 1));
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(a.partial_cmp(&b) == Some(Ordering::Less));
+        assert!(b.cmp_mono(&a) == Ordering::Greater);
+        assert!(a < b);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn test_collections() {
+        let mut records: Vec<_> = (0..20).rev().map(|i| record(i / 2, i % 2)).collect();
+        records.sort();
+
+        let ids: Vec<_> = records.iter().map(|r| (r.id, r.group)).collect();
+        assert_eq!(ids[..4], [(0, 0), (0, 1), (1, 0), (1, 1)]);
+
+        // Values are distinct by the first two keys, so inserting never reaches the last one
+        let set: BTreeSet<_> = records.into_iter().collect();
+        assert_eq!(set.len(), 20);
+        assert!(!set.contains(&record(3, 2)));
+    }
+
+    /// Guards the tests above: the panicking key is reached once the previous keys are equal
+    #[test]
+    #[should_panic(expected = "expensive key of record 1 was evaluated")]
+    fn test_last_key_reached_when_previous_equal() {
+        let _ = record(1, 0).cmp(&record(1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "expensive key of record 1 was evaluated")]
+    fn test_eq_reaches_last_key_when_previous_equal() {
+        let _ = record(1, 0) == record(1, 0);
+    }
+}
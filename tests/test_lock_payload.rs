@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeSet;
+    use std::sync::Mutex;
+    use std::sync::RwLock;
+
+    /// Neither `Mutex` nor `RwLock` implements comparison traits, only keys have to
+    #[ord_eq_by_key_selector(|r| r.id)]
+    pub struct Resource {
+        pub id: u64,
+        pub data: Mutex<Vec<u8>>,
+        pub meta: RwLock<String>,
+    }
+
+    /// Payload type without any trait, no bound is introduced for the generic parameter
+    pub struct Opaque;
+
+    #[ord_eq_by_key_selector(|h| h.id)]
+    pub struct Handle<T> {
+        pub id: u64,
+        pub payload: Mutex<T>,
+    }
+
+    fn resource(id: u64) -> Resource {
+        Resource {
+            id,
+            data: Mutex::new(Vec::new()),
+            meta: RwLock::new(String::new()),
+        }
+    }
+
+    #[test]
+    fn test_order_by_id() {
+        assert!(resource(1).cmp(&resource(2)) == Ordering::Less);
+        assert!(resource(2) == resource(2));
+        assert!(resource(3) != resource(2));
+    }
+
+    // Key doesn't depend on the interior-mutable payload, so the set stays sorted
+    #[allow(clippy::mutable_key_type)]
+    #[test]
+    fn test_payload_mutated_in_set() {
+        let set: BTreeSet<_> = (1..=3).rev().map(resource).collect();
+
+        for r in &set {
+            r.data.lock().unwrap().push(r.id as u8);
+            r.meta.write().unwrap().push_str("touched");
+        }
+
+        let ids: Vec<_> = set.iter().map(|r| r.id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+        assert!(set.contains(&resource(2)));
+        assert_eq!(*set.first().unwrap().data.lock().unwrap(), [1]);
+    }
+
+    #[test]
+    fn test_comparison_doesnt_lock() {
+        let a = resource(1);
+        let _guard = a.data.lock().unwrap();
+        let _meta = a.meta.write().unwrap();
+
+        // Would deadlock if comparison locked the payload
+        assert!(a.cmp(&resource(1)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_opaque_generic_payload() {
+        let a = Handle {
+            id: 1,
+            payload: Mutex::new(Opaque),
+        };
+        let b = Handle {
+            id: 2,
+            payload: Mutex::new(Opaque),
+        };
+
+        assert!(a < b);
+        assert!(a != b);
+        assert!(b.payload.lock().is_ok());
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::lut;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    const RANKS: [u8; 4] = [2, 0, 1, u8::MAX];
+
+    #[ord_eq_by_key_selector(|s| lut(&RANKS, s.code as usize), s.code)]
+    #[derive(Debug)]
+    pub struct Status {
+        pub code: i32,
+    }
+
+    fn status(code: i32) -> Status {
+        Status { code }
+    }
+
+    #[test]
+    fn test_in_range() {
+        assert!(lut(&RANKS, 0) == 2);
+        assert!(lut(&RANKS, 1) == 0);
+        assert!(lut(&RANKS, 2) == 1);
+
+        assert!(status(1) < status(2));
+        assert!(status(2) < status(0));
+        assert!(status(0).cmp(&status(0)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_boundary() {
+        assert!(lut(&RANKS, RANKS.len() - 1) == u8::MAX);
+        assert!(lut(&RANKS, RANKS.len()) == u8::MAX);
+        assert!(lut(&[7], 0) == 7);
+        assert!(lut(&[7], 1) == 7);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(lut(&RANKS, 4) == u8::MAX);
+        assert!(lut(&RANKS, 1000) == u8::MAX);
+        assert!(lut(&RANKS, usize::MAX) == u8::MAX);
+
+        // Unknown codes (including negative, which wrap on conversion) sort after known ones
+        let mut statuses = [status(1000), status(0), status(-1), status(3), status(1)];
+        statuses.sort();
+
+        let codes: Vec<_> = statuses.iter().map(|s| s.code).collect();
+        assert!(codes == [1, 0, -1, 3, 1000]);
+    }
+
+    #[test]
+    #[should_panic(expected = "lookup table has to be non-empty")]
+    fn test_empty() {
+        let table: [u8; 0] = [];
+        lut(&table, 0);
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(methods = [priority, name, tags, newest_first,])]
+    #[derive(Debug)]
+    pub struct Ticket {
+        pub severity: u8,
+        pub urgent: bool,
+        pub name: String,
+        pub tags: Vec<String>,
+        pub created: u64,
+    }
+
+    impl Ticket {
+        /// Owned value computed from fields
+        pub fn priority(&self) -> u32 {
+            self.severity as u32 * if self.urgent { 10 } else { 1 }
+        }
+
+        /// Borrowed `&str`
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        /// Borrowed slice
+        pub fn tags(&self) -> &[String] {
+            &self.tags
+        }
+
+        /// Owned wrapper
+        pub fn newest_first(&self) -> Reverse<u64> {
+            Reverse(self.created)
+        }
+    }
+
+    fn ticket(severity: u8, urgent: bool, name: &str, tags: &[&str], created: u64) -> Ticket {
+        Ticket {
+            severity,
+            urgent,
+            name: name.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            created,
+        }
+    }
+
+    #[test]
+    fn test_methods_in_order() {
+        assert!(
+            ticket(1, true, "a", &[], 0).cmp(&ticket(9, false, "a", &[], 0)) == Ordering::Greater
+        );
+        assert!(
+            ticket(1, false, "a", &[], 0).cmp(&ticket(1, false, "b", &[], 0)) == Ordering::Less
+        );
+        assert!(
+            ticket(1, false, "a", &["x"], 0).cmp(&ticket(1, false, "a", &["y"], 0))
+                == Ordering::Less
+        );
+        assert!(
+            ticket(1, false, "a", &[], 1).cmp(&ticket(1, false, "a", &[], 2)) == Ordering::Greater
+        );
+        assert!(ticket(2, false, "a", &[], 1) == ticket(2, false, "a", &[], 1));
+    }
+
+    #[ord_eq_by_key_selector(methods = [rank])]
+    pub enum Card {
+        Number(u8),
+        Face(char),
+    }
+
+    impl Card {
+        pub fn rank(&self) -> u8 {
+            match self {
+                Card::Number(n) => *n,
+                Card::Face('J') => 11,
+                Card::Face('Q') => 12,
+                Card::Face(_) => 13,
+            }
+        }
+    }
+
+    #[test]
+    fn test_enum() {
+        assert!(Card::Number(10).cmp(&Card::Face('J')) == Ordering::Less);
+        assert!(Card::Face('K').cmp(&Card::Face('Q')) == Ordering::Greater);
+        assert!(Card::Number(11) == Card::Face('J'));
+    }
+
+    #[ord_eq_by_key_selector(ord = methods = [len]; eq = |(s)| s; unsafe_inconsistent)]
+    pub struct Word(&'static str);
+
+    impl Word {
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    fn test_ord_section() {
+        assert!(Word("ab").cmp(&Word("c")) == Ordering::Greater);
+        assert!(Word("ab") != Word("cd"));
+    }
+}
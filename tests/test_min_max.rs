@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::earliest_of;
+    use ord_by_key::latest_of;
+    use ord_by_key::max_of;
+    use ord_by_key::min_of;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|r|
+        ord_by_key::max_of(r.width, r.height),
+        ord_by_key::min_of(r.width, r.height))]
+    #[derive(Debug)]
+    pub struct Rectangle {
+        pub width: u32,
+        pub height: u32,
+    }
+
+    #[ord_eq_by_key_selector(|n| max_of(&n.primary, &n.secondary))]
+    pub struct Names {
+        pub primary: String,
+        pub secondary: String,
+    }
+
+    #[ord_eq_by_key_selector(|r| Reverse(max_of(r.width, r.height)), min_of(r.width, r.height))]
+    pub struct LargestFirst {
+        pub width: u32,
+        pub height: u32,
+    }
+
+    #[ord_eq_by_key_selector(|r| Reverse(latest_of([r.created, r.updated, r.accessed])), r.id)]
+    #[derive(Debug)]
+    pub struct Record {
+        pub created: u64,
+        pub updated: u64,
+        pub accessed: u64,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(|d| earliest_of([&d.draft, &d.review, &d.published]))]
+    pub struct Document {
+        pub draft: String,
+        pub review: String,
+        pub published: String,
+    }
+
+    fn rectangle(width: u32, height: u32) -> Rectangle {
+        Rectangle { width, height }
+    }
+
+    #[test]
+    fn test_bucketing() {
+        let mut rectangles = [
+            rectangle(1, 3),
+            rectangle(4, 2),
+            rectangle(3, 2),
+            rectangle(2, 4),
+            rectangle(1, 1),
+        ];
+        rectangles.sort();
+
+        let sides: Vec<_> = rectangles.iter().map(|r| (r.width, r.height)).collect();
+        assert_eq!(sides, [(1, 1), (1, 3), (3, 2), (4, 2), (2, 4)]);
+
+        assert!(rectangle(4, 2).cmp(&rectangle(2, 4)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let names = |primary: &str, secondary: &str| Names {
+            primary: primary.to_string(),
+            secondary: secondary.to_string(),
+        };
+
+        assert!(names("b", "a") == names("a", "b"));
+        assert!(names("b", "a").cmp(&names("c", "a")) == Ordering::Less);
+        assert!(max_of("a", "b") == "b");
+        assert!(min_of(&names("b", "a").primary, &names("a", "b").primary) == "a");
+    }
+
+    #[test]
+    fn test_reverse() {
+        let largest_first = |width, height| LargestFirst { width, height };
+
+        assert!(largest_first(5, 1).cmp(&largest_first(4, 4)) == Ordering::Less);
+        assert!(largest_first(5, 1).cmp(&largest_first(2, 5)) == Ordering::Less);
+        assert!(largest_first(5, 3).cmp(&largest_first(3, 5)) == Ordering::Equal);
+        assert!(Reverse(max_of(1, 2)) < Reverse(min_of(1, 2)));
+    }
+
+    #[test]
+    fn test_latest_of_timestamps() {
+        let record = |created, updated, accessed, id| Record {
+            created,
+            updated,
+            accessed,
+            id,
+        };
+
+        let mut records = [
+            record(1, 2, 3, 0),
+            record(9, 1, 1, 1),
+            record(1, 5, 2, 2),
+            record(3, 3, 3, 3),
+        ];
+        records.sort();
+
+        let ids: Vec<_> = records.iter().map(|r| r.id).collect();
+        assert_eq!(ids, [1, 2, 0, 3]);
+        assert!(record(1, 9, 2, 0) == record(9, 1, 1, 0));
+    }
+
+    #[test]
+    fn test_varying_number_of_values() {
+        assert_eq!(latest_of([7]), 7);
+        assert_eq!(earliest_of([7]), 7);
+        assert_eq!(latest_of([3, 8]), 8);
+        assert_eq!(earliest_of([3, 8]), 3);
+        assert_eq!(latest_of([4, 1, 9, 2, 9, 0]), 9);
+        assert_eq!(earliest_of([4, 1, 9, 2, 9, 0]), 0);
+        assert_eq!(latest_of([3, 8]), max_of(3, 8));
+        assert_eq!(earliest_of([3, 8]), min_of(3, 8));
+    }
+
+    #[test]
+    fn test_borrowed_values() {
+        let document = |draft: &str, review: &str, published: &str| Document {
+            draft: draft.to_string(),
+            review: review.to_string(),
+            published: published.to_string(),
+        };
+
+        assert!(document("b", "c", "d").cmp(&document("c", "a", "z")) == Ordering::Greater);
+        assert!(document("b", "a", "a") == document("a", "z", "z"));
+
+        let names = [String::from("bob"), String::from("alice")];
+        let first: &String = earliest_of([&names[0], &names[1]]);
+        assert!(std::ptr::eq(first, &names[1]));
+
+        // Equal values: `latest_of` returns the last one, `earliest_of` the first one
+        let equal = [Reverse(1), Reverse(1)];
+        assert!(std::ptr::eq(latest_of([&equal[0], &equal[1]]), &equal[1]));
+        assert!(std::ptr::eq(earliest_of([&equal[0], &equal[1]]), &equal[0]));
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[ord_eq_by_key_selector(|w| &w.0.1)]
+    pub struct Wrap((i32, String));
+
+    #[ord_eq_by_key_selector(|(inner)| &inner.1)]
+    pub struct WrapDestructured((i32, String));
+
+    pub struct Inner {
+        pub subfield: (u8, String),
+    }
+
+    #[ord_eq_by_key_selector(|o| &o.field.subfield.0, &o.field.subfield.1)]
+    pub struct Outer {
+        pub field: Inner,
+    }
+
+    #[ord_eq_by_key_selector(|(a, b)| &a.subfield.1, b.1.0)]
+    pub struct OuterDestructured(Inner, (u8, (i64, u8)));
+
+    #[ord_eq_by_key_selector(|s| &s.0 .1)]
+    pub struct Shared(Arc<(i32, String)>);
+
+    #[ord_eq_by_key_selector(|(inner)| &inner.1, inner.0)]
+    pub struct SharedDestructured(Arc<(i32, String)>);
+
+    #[ord_eq_by_key_selector(|(inner)| &inner.0, inner.1.len())]
+    pub struct SharedGeneric<T: Ord>(Arc<(T, String)>);
+
+    #[ord_eq_by_key_selector(|s| &s.items.1.0)]
+    pub struct DeepShared {
+        pub items: Rc<(u8, (String, u8))>,
+    }
+
+    fn inner(n: u8, s: &str) -> Inner {
+        Inner {
+            subfield: (n, s.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_tuple_in_tuple_struct() {
+        let wrap = |i, s: &str| Wrap((i, s.to_string()));
+        assert!(wrap(9, "a").cmp(&wrap(0, "b")) == Ordering::Less);
+        assert!(wrap(9, "a").cmp(&wrap(0, "a")) == Ordering::Equal);
+
+        let wrap = |i, s: &str| WrapDestructured((i, s.to_string()));
+        assert!(wrap(9, "a").cmp(&wrap(0, "b")) == Ordering::Less);
+        assert!(wrap(9, "a").cmp(&wrap(0, "a")) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_nested_fields() {
+        let outer = |n, s| Outer { field: inner(n, s) };
+        assert!(outer(0, "b").cmp(&outer(1, "a")) == Ordering::Less);
+        assert!(outer(1, "a").cmp(&outer(1, "b")) == Ordering::Less);
+        assert!(outer(1, "a").cmp(&outer(1, "a")) == Ordering::Equal);
+
+        let outer = |s, i| OuterDestructured(inner(0, s), (0, (i, 0)));
+        assert!(outer("a", 1).cmp(&outer("b", 0)) == Ordering::Less);
+        assert!(outer("a", 0).cmp(&outer("a", 1)) == Ordering::Less);
+        assert!(outer("a", 1).cmp(&outer("a", 1)) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_through_smart_pointers() {
+        let shared = |i, s: &str| Shared(Arc::new((i, s.to_string())));
+        assert!(shared(9, "a").cmp(&shared(0, "b")) == Ordering::Less);
+        assert!(shared(9, "a").cmp(&shared(0, "a")) == Ordering::Equal);
+
+        let shared = |i, s: &str| SharedDestructured(Arc::new((i, s.to_string())));
+        assert!(shared(9, "a").cmp(&shared(0, "b")) == Ordering::Less);
+        assert!(shared(9, "a").cmp(&shared(0, "a")) == Ordering::Greater);
+
+        let shared = |i, s: &str| SharedGeneric(Arc::new((i, s.to_string())));
+        assert!(shared("b", "aa").cmp(&shared("a", "a")) == Ordering::Greater);
+        assert!(shared("a", "aa").cmp(&shared("a", "b")) == Ordering::Greater);
+        assert!(shared("a", "a").cmp(&shared("a", "b")) == Ordering::Equal);
+
+        let deep = |s: &str| DeepShared {
+            items: Rc::new((0, (s.to_string(), 0))),
+        };
+        assert!(deep("a").cmp(&deep("b")) == Ordering::Less);
+        assert!(deep("b").cmp(&deep("b")) == Ordering::Equal);
+    }
+}
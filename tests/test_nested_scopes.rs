@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_by_key_advanced;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    /// Module-level type which nested types of the same name shadow
+    #[ord_eq_by_key_selector(|e| e.id)]
+    pub struct Entry {
+        pub id: u32,
+    }
+
+    pub struct Registry<T> {
+        pub items: Vec<(u32, T)>,
+    }
+
+    impl<T: Clone> Registry<T> {
+        /// Ids of items in descending order, with a local keyed type shadowing `Entry`
+        pub fn ids_desc(&self) -> Vec<u32> {
+            #[ord_eq_by_key_selector(|e| ::core::cmp::Reverse(e.id); gen_sorted, gen_extremes = max_of/min_of)]
+            struct Entry {
+                id: u32,
+            }
+
+            let entries = Entry::sorted(self.items.iter().map(|(id, _)| Entry { id: *id }));
+            assert!(Entry::max_of(Entry { id: 1 }, Entry { id: 2 }).id == 1);
+            entries.into_iter().map(|e| e.id).collect()
+        }
+
+        pub fn largest(&self) -> Option<u32> {
+            #[ord_eq_by_key_selector(|(id)| id; gen_max_queue = Queue, debug_keys)]
+            struct Id(u32);
+
+            let mut queue: Queue = self.items.iter().map(|(id, _)| Id(*id)).collect();
+            queue.pop().map(|id| id.0)
+        }
+    }
+
+    pub trait Named {
+        fn name(&self) -> &str;
+
+        /// Orders names by length then alphabetically, with local types in a default method
+        fn compare_names(&self, other: &Self) -> Ordering
+        where
+            Self: Sized,
+        {
+            #[ord_eq_by_key_selector(|n| n.name.len(), n.name; fifo_tiebreak, gen_cmp_mono)]
+            struct ByLen<'a> {
+                name: &'a str,
+            }
+
+            #[ord_by_key_advanced(ord = |n| n.0.len(); eq = |n| n.0.len(); hash = |n| n.0.len())]
+            struct Len<'a>(&'a str);
+
+            #[ord_eq_by_key_selector(enum_fields)]
+            enum Side<'a> {
+                Left(&'a str),
+                Right(&'a str),
+            }
+
+            assert!(Side::Left(self.name()) < Side::Right(other.name()));
+            assert!(
+                Len(self.name()).cmp(&Len(other.name()))
+                    == self.name().len().cmp(&other.name().len())
+            );
+            ByLen::new(self.name()).cmp(&ByLen::new(other.name()))
+        }
+    }
+
+    pub struct Tag(&'static str);
+
+    impl Named for Tag {
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    impl Named for Entry {
+        fn name(&self) -> &str {
+            #[ord_eq_by_key_selector(transparent)]
+            struct Inner(u32);
+
+            #[ord_eq_by_key_selector(|d| d.0; gen_btree_desc = Names, gen_has_key = u32)]
+            #[derive(Clone)]
+            struct Desc(u32);
+
+            let mut names = Names::new();
+            names.insert(Desc(self.id), "entry");
+            assert!(Inner(self.id) == Inner(self.id));
+            names.get(&Desc(self.id)).copied().unwrap_or("")
+        }
+    }
+
+    mod outer {
+        use ord_by_key::ord_eq_by_key_selector;
+
+        pub mod inner {
+            use ord_by_key::ord_eq_by_key_selector;
+
+            #[ord_eq_by_key_selector(|v| &v.label, super::super::Entry { id: v.id }; gen_option_ord = none_last)]
+            pub struct Versioned {
+                pub label: &'static str,
+                pub id: u32,
+            }
+
+            #[ord_eq_by_key_selector(|p| p.value; partial_only(nan = equal))]
+            pub struct Point {
+                pub value: f64,
+            }
+        }
+
+        /// Same name as in `inner`, in a parent module
+        #[ord_eq_by_key_selector(|v| ::core::cmp::Reverse(&v.0))]
+        pub struct Versioned(pub inner::Versioned);
+    }
+
+    #[test]
+    fn test_inherent_impl_method() {
+        let registry = Registry {
+            items: vec![(2, "b"), (3, "c"), (1, "a")],
+        };
+        assert!(registry.ids_desc() == [3, 2, 1]);
+        assert!(registry.largest() == Some(3));
+
+        // Module-level `Entry` is not affected by the local one
+        assert!(Entry { id: 1 } < Entry { id: 2 });
+    }
+
+    #[test]
+    fn test_trait_methods() {
+        assert!(Tag("bb").compare_names(&Tag("a")) == Ordering::Greater);
+        assert!(Tag("ab").compare_names(&Tag("ba")) == Ordering::Less);
+        assert!(Entry { id: 1 }.name() == "entry");
+    }
+
+    #[test]
+    fn test_nested_modules() {
+        use outer::inner;
+
+        let versioned = |label, id| inner::Versioned { label, id };
+        assert!(versioned("a", 2) < versioned("b", 1));
+        assert!(versioned("a", 1) < versioned("a", 2));
+        assert!(outer::Versioned(versioned("a", 1)) > outer::Versioned(versioned("a", 2)));
+        assert!(inner::Point { value: f64::NAN } == inner::Point { value: f64::NAN });
+    }
+}
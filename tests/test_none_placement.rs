@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::NoneFirst;
+    use ord_by_key::NoneLast;
+
+    /// Only `Scheduled` carries an orderable payload, the rest are a single bucket
+    #[ord_eq_by_key_selector(|e| NoneLast(match e {
+        Event::Scheduled { at } => Some(*at),
+        _ => None,
+    }))]
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    pub enum Event {
+        Unscheduled(&'static str),
+        Scheduled { at: u64 },
+        Cancelled,
+    }
+
+    /// `Unknown` goes before any level
+    #[ord_eq_by_key_selector(|e| NoneFirst(match e {
+        Priority::Level(level) => Some(*level),
+        Priority::Unknown => None,
+    }))]
+    #[derive(Debug)]
+    pub enum Priority {
+        Level(u8),
+        Unknown,
+    }
+
+    #[test]
+    fn test_none_last() {
+        assert!(Event::Scheduled { at: 5 }.cmp(&Event::Cancelled) == Ordering::Less);
+        assert!(Event::Unscheduled("x").cmp(&Event::Scheduled { at: 0 }) == Ordering::Greater);
+        assert!(Event::Scheduled { at: 1 }.cmp(&Event::Scheduled { at: 2 }) == Ordering::Less);
+        assert!(Event::Unscheduled("x") == Event::Cancelled);
+        assert!(Event::Unscheduled("x") == Event::Unscheduled("y"));
+
+        let mut events = [
+            Event::Cancelled,
+            Event::Scheduled { at: 3 },
+            Event::Unscheduled("a"),
+            Event::Scheduled { at: 1 },
+        ];
+        events.sort();
+
+        let keys: Vec<_> = events
+            .iter()
+            .map(|e| match e {
+                Event::Scheduled { at } => Some(*at),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(keys, [Some(1), Some(3), None, None]);
+    }
+
+    #[test]
+    fn test_none_first() {
+        assert!(Priority::Unknown.cmp(&Priority::Level(0)) == Ordering::Less);
+        assert!(Priority::Level(2).cmp(&Priority::Level(1)) == Ordering::Greater);
+        assert!(Priority::Unknown == Priority::Unknown);
+    }
+
+    #[test]
+    fn test_helpers() {
+        for a in [None, Some(1), Some(2)] {
+            for b in [None, Some(1), Some(2)] {
+                assert_eq!(NoneFirst(a).cmp(&NoneFirst(b)), a.cmp(&b));
+
+                let expected = match (a, b) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) => Ordering::Greater,
+                    (Some(_), None) => Ordering::Less,
+                    (Some(a), Some(b)) => a.cmp(&b),
+                };
+                assert_eq!(NoneLast(a).cmp(&NoneLast(b)), expected);
+                assert_eq!(NoneLast(a) == NoneLast(b), a == b);
+            }
+        }
+    }
+}
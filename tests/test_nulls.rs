@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    /// `ORDER BY rating NULLS LAST, name NULLS FIRST`
+    #[ord_eq_by_key_selector(|r| r.rating nulls = last, &r.name nulls = first)]
+    #[derive(Debug)]
+    pub struct Row {
+        pub rating: Option<u32>,
+        pub name: Option<&'static str>,
+    }
+
+    /// `ORDER BY rating DESC NULLS LAST, title`
+    #[ord_eq_by_key_selector(|b| b.rating.map(Reverse) nulls = last, &b.title)]
+    #[derive(Debug)]
+    pub struct Book {
+        pub rating: Option<u32>,
+        pub title: String,
+    }
+
+    /// Borrowed `Option` of a non-`Copy` key
+    #[ord_eq_by_key_selector(|(nickname)| nickname nulls = last)]
+    #[derive(Debug)]
+    pub struct User(Option<String>);
+
+    fn row(rating: Option<u32>, name: Option<&'static str>) -> Row {
+        Row { rating, name }
+    }
+
+    #[test]
+    fn test_sql_order() {
+        let mut rows = [
+            row(None, Some("a")),
+            row(Some(2), Some("b")),
+            row(None, None),
+            row(Some(1), Some("c")),
+            row(Some(2), None),
+            row(Some(1), Some("a")),
+        ];
+
+        rows.sort();
+
+        let sorted: Vec<_> = rows.iter().map(|r| (r.rating, r.name)).collect();
+        assert_eq!(
+            sorted,
+            [
+                (Some(1), Some("a")),
+                (Some(1), Some("c")),
+                (Some(2), None),
+                (Some(2), Some("b")),
+                (None, None),
+                (None, Some("a")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nulls_equal() {
+        assert!(row(None, None) == row(None, None));
+        assert!(row(None, None).cmp(&row(None, None)) == Ordering::Equal);
+        assert!(row(None, Some("a")) != row(None, None));
+        assert!(row(Some(0), None).cmp(&row(None, None)) == Ordering::Less);
+        assert!(row(Some(0), None).cmp(&row(Some(0), Some(""))) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_desc_nulls_last() {
+        let book = |rating, title: &str| Book {
+            rating,
+            title: title.to_string(),
+        };
+
+        let mut books = [
+            book(None, "a"),
+            book(Some(3), "b"),
+            book(Some(5), "c"),
+            book(Some(3), "a"),
+        ];
+
+        books.sort();
+
+        let sorted: Vec<_> = books.iter().map(|b| (b.rating, &b.title[..])).collect();
+        assert_eq!(
+            sorted,
+            [(Some(5), "c"), (Some(3), "a"), (Some(3), "b"), (None, "a")]
+        );
+    }
+
+    #[test]
+    fn test_borrowed_option() {
+        let none = User(None);
+        let alice = User(Some("alice".to_string()));
+        let bob = User(Some("bob".to_string()));
+
+        assert!(alice.cmp(&bob) == Ordering::Less);
+        assert!(bob.cmp(&none) == Ordering::Less);
+        assert!(none == User(None));
+        assert!(alice == User(Some("alice".to_string())));
+    }
+}
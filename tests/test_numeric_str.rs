@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::NumericStr;
+    use std::collections::BTreeSet;
+
+    #[ord_eq_by_key_selector(|i| NumericStr(i.id))]
+    #[derive(Debug)]
+    pub struct Item {
+        pub id: &'static str,
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        assert!(NumericStr("007").cmp(&NumericStr("7")) == Ordering::Equal);
+        assert!(NumericStr("0").cmp(&NumericStr("000")) == Ordering::Equal);
+        assert!(NumericStr("010").cmp(&NumericStr("9")) == Ordering::Greater);
+        assert!(NumericStr("0009").cmp(&NumericStr("10")) == Ordering::Less);
+        assert!(NumericStr("0").cmp(&NumericStr("1")) == Ordering::Less);
+
+        let set: BTreeSet<_> = ["7", "007", "07", "8"]
+            .iter()
+            .map(|&id| Item { id })
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_huge_numbers() {
+        let max = u128::MAX.to_string();
+        let above_max = "340282366920938463463374607431768211456";
+
+        assert!(NumericStr(&max).cmp(&NumericStr(above_max)) == Ordering::Less);
+        assert!(
+            NumericStr(above_max).cmp(&NumericStr(&format!("000{}", above_max))) == Ordering::Equal
+        );
+        assert!(
+            NumericStr("1000000000000000000000000000000000000000000")
+                .cmp(&NumericStr("999999999999999999999999999999999999999999"))
+                == Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_mixed() {
+        // Numbers go before everything else
+        assert!(NumericStr("99999").cmp(&NumericStr("a")) == Ordering::Less);
+        assert!(NumericStr("").cmp(&NumericStr("0")) == Ordering::Greater);
+
+        // Non-numeric strings are compared as strings, leading zeros are kept
+        assert!(NumericStr("-1").cmp(&NumericStr("-2")) == Ordering::Less);
+        assert!(NumericStr(" 7").cmp(&NumericStr("7")) == Ordering::Greater);
+        assert!(NumericStr("07a").cmp(&NumericStr("7a")) == Ordering::Less);
+        assert!(NumericStr("abc").cmp(&NumericStr("abc")) == Ordering::Equal);
+
+        let mut items: Vec<_> = ["b", "10", "", "02", "a", "9", "1"]
+            .iter()
+            .map(|&id| Item { id })
+            .collect();
+        items.sort();
+
+        let ids: Vec<_> = items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, ["1", "02", "9", "10", "", "a", "b"]);
+    }
+}
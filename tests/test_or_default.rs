@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::or_default;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[derive(Debug)]
+    pub struct Builder {
+        pub name: Option<&'static str>,
+        pub size: &'static str,
+    }
+
+    impl Builder {
+        pub fn name(&self) -> Option<String> {
+            self.name.map(String::from)
+        }
+
+        pub fn size(&self) -> Result<u32, core::num::ParseIntError> {
+            self.size.parse()
+        }
+    }
+
+    #[ord_eq_by_key_selector(|(b)| ord_by_key::or_default(b.size()), ord_by_key::or_default(b.name()))]
+    #[derive(Debug)]
+    pub struct ByHelper(Builder);
+
+    /// Same fallback spelled inline as a `match` block
+    #[ord_eq_by_key_selector(|(b)|
+        match b.size() {
+            Ok(size) if size > 0 => size,
+            _ => 0,
+        },
+        b.name().unwrap_or_default())]
+    #[derive(Debug)]
+    pub struct ByMatch(Builder);
+
+    fn builder(name: Option<&'static str>, size: &'static str) -> Builder {
+        Builder { name, size }
+    }
+
+    #[test]
+    fn test_helper() {
+        assert_eq!(or_default(Some(5u32)), 5);
+        assert_eq!(or_default(None::<u32>), 0);
+        assert_eq!(or_default("12".parse::<u32>()), 12);
+        assert_eq!(or_default("x".parse::<u32>()), 0);
+        assert_eq!(or_default(None::<String>), "");
+    }
+
+    #[test]
+    fn test_result_key_defaults() {
+        let b = |size| ByHelper(builder(Some("a"), size));
+
+        assert!(b("invalid").cmp(&b("0")) == Ordering::Equal);
+        assert!(b("invalid").cmp(&b("1")) == Ordering::Less);
+        assert!(b("-1").cmp(&b("invalid")) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_option_key_defaults() {
+        let b = |name| ByHelper(builder(name, "1"));
+
+        assert!(b(None).cmp(&b(Some(""))) == Ordering::Equal);
+        assert!(b(None).cmp(&b(Some("a"))) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_defaults_sort_consistently() {
+        let inputs = [
+            (Some("b"), "3"),
+            (None, "x"),
+            (Some("a"), "0"),
+            (Some("c"), "y"),
+            (None, "2"),
+            (Some("a"), "2"),
+        ];
+
+        let mut by_helper: Vec<_> = inputs
+            .iter()
+            .map(|&(n, s)| ByHelper(builder(n, s)))
+            .collect();
+        let mut by_match: Vec<_> = inputs
+            .iter()
+            .map(|&(n, s)| ByMatch(builder(n, s)))
+            .collect();
+        by_helper.sort();
+        by_match.sort();
+
+        let helper: Vec<_> = by_helper.iter().map(|b| (b.0.name, b.0.size)).collect();
+        let matched: Vec<_> = by_match.iter().map(|b| (b.0.name, b.0.size)).collect();
+
+        // Sort is stable, so values falling back to the same default keep their input order
+        assert_eq!(
+            helper,
+            [
+                (None, "x"),
+                (Some("a"), "0"),
+                (Some("c"), "y"),
+                (None, "2"),
+                (Some("a"), "2"),
+                (Some("b"), "3"),
+            ]
+        );
+        assert_eq!(helper, matched);
+    }
+}
@@ -0,0 +1,279 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::hash::BuildHasher;
+    use ::core::hash::Hash;
+    use ord_by_key::ord_by_key_advanced;
+    use std::collections::hash_map::RandomState;
+    use std::collections::BTreeSet;
+    use std::collections::HashSet;
+
+    fn hash<T: Hash>(state: &RandomState, value: &T) -> u64 {
+        state.hash_one(value)
+    }
+
+    #[ord_by_key_advanced(ord = |r| r.score)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct OrdOnly {
+        pub score: u32,
+        pub name: &'static str,
+    }
+
+    #[ord_by_key_advanced(eq = |u| u.id)]
+    #[derive(Debug)]
+    pub struct EqOnly {
+        pub id: u32,
+        pub name: &'static str,
+    }
+
+    #[ord_by_key_advanced(hash = |e| e.id)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct HashOnly {
+        pub id: u32,
+        pub payload: Vec<u8>,
+    }
+
+    #[ord_by_key_advanced(ord = |s| s.category, s.score; eq = |s| s.category)]
+    #[derive(Debug)]
+    pub struct OrdEq {
+        pub category: u32,
+        pub score: u32,
+    }
+
+    #[ord_by_key_advanced(ord = |v| v.version; hash = |v| v.version)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct OrdHash {
+        pub version: u32,
+        pub notes: &'static str,
+    }
+
+    #[ord_by_key_advanced(eq = |s| s.id, s.name; hash = |s| s.id)]
+    #[derive(Debug)]
+    pub struct EqHash {
+        pub id: u32,
+        pub name: &'static str,
+        pub visits: u32,
+    }
+
+    #[ord_by_key_advanced(
+        ord = |p| p.score, p.id;
+        eq = |p| p.id;
+        hash = |p| p.id;
+    )]
+    #[derive(Debug)]
+    pub struct All {
+        pub id: u32,
+        pub score: u32,
+    }
+
+    #[ord_by_key_advanced(ord = enum_fields; eq = |s| s.is_empty(); hash = |s| s.is_empty())]
+    #[derive(Debug)]
+    pub enum Shape {
+        Empty,
+        Circle(u32),
+        Rect { width: u32, height: u32 },
+    }
+
+    impl Shape {
+        fn is_empty(&self) -> bool {
+            match self {
+                Shape::Empty => true,
+                Shape::Circle(r) => *r == 0,
+                Shape::Rect { width, height } => *width == 0 || *height == 0,
+            }
+        }
+    }
+
+    #[ord_by_key_advanced(ord = fields; eq = by_index = [1]; hash = methods = [len])]
+    #[derive(Debug)]
+    pub struct Modes(pub u32, pub &'static str);
+
+    impl Modes {
+        fn len(&self) -> usize {
+            self.1.len()
+        }
+    }
+
+    #[ord_by_key_advanced(ord = |w| &w.0; eq = |w| &w.0; hash = |w| &w.0)]
+    #[derive(Debug)]
+    pub struct Wrapper<T: Ord + Hash>(pub T);
+
+    #[test]
+    fn test_ord_only() {
+        let a = OrdOnly {
+            score: 1,
+            name: "b",
+        };
+        let b = OrdOnly {
+            score: 2,
+            name: "a",
+        };
+        let c = OrdOnly {
+            score: 1,
+            name: "c",
+        };
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(a.cmp(&c) == Ordering::Equal);
+        // Derived equality compares all fields
+        assert!(a != c);
+    }
+
+    #[test]
+    fn test_eq_only() {
+        let a = EqOnly { id: 1, name: "a" };
+        let b = EqOnly { id: 1, name: "b" };
+
+        assert!(a == b);
+        assert!(a != EqOnly { id: 2, name: "a" });
+        assert!(a.name != b.name);
+    }
+
+    #[test]
+    fn test_hash_only() {
+        let state = RandomState::new();
+        let a = HashOnly {
+            id: 1,
+            payload: vec![1],
+        };
+        let b = HashOnly {
+            id: 1,
+            payload: vec![2],
+        };
+
+        assert!(hash(&state, &a) == hash(&state, &b));
+        assert!(hash(&state, &a) == hash(&state, &1u32));
+        assert!(a != b);
+
+        let set: HashSet<_> = vec![a, b].into_iter().collect();
+        assert!(set.len() == 2);
+    }
+
+    #[test]
+    fn test_ord_eq() {
+        let a = OrdEq {
+            category: 1,
+            score: 1,
+        };
+        let b = OrdEq {
+            category: 1,
+            score: 2,
+        };
+
+        assert!(a == b);
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(
+            OrdEq {
+                category: 0,
+                score: 9
+            } < a
+        );
+
+        // `BTreeSet` uses `Ord`, so values equal by `eq` keys are kept
+        let mut set = BTreeSet::new();
+        assert!(set.insert(a));
+        assert!(set.insert(b));
+        assert!(set.len() == 2);
+    }
+
+    #[test]
+    fn test_ord_hash() {
+        let state = RandomState::new();
+        let a = OrdHash {
+            version: 1,
+            notes: "a",
+        };
+        let b = OrdHash {
+            version: 1,
+            notes: "b",
+        };
+
+        assert!(a.cmp(&b) == Ordering::Equal);
+        assert!(hash(&state, &a) == hash(&state, &b));
+        assert!(a != b);
+        assert!(
+            a < OrdHash {
+                version: 2,
+                notes: "a"
+            }
+        );
+    }
+
+    #[test]
+    fn test_eq_hash() {
+        let state = RandomState::new();
+        let visit = |id, name, visits| EqHash { id, name, visits };
+
+        let mut set = HashSet::new();
+        assert!(set.insert(visit(1, "a", 1)));
+        assert!(!set.insert(visit(1, "a", 2)));
+        assert!(set.insert(visit(1, "b", 1)));
+        assert!(set.insert(visit(2, "a", 1)));
+        assert!(set.len() == 3);
+        assert!(set.iter().all(|v| v.visits == 1));
+
+        // Hash keys are a subset of eq keys, values with the same `id` collide
+        assert!(hash(&state, &visit(1, "a", 0)) == hash(&state, &visit(1, "b", 0)));
+    }
+
+    #[test]
+    fn test_all() {
+        let mut players = HashSet::new();
+        players.insert(All { id: 1, score: 10 });
+        players.insert(All { id: 2, score: 5 });
+        assert!(players.replace(All { id: 1, score: 20 }).is_some());
+
+        let mut ranking: Vec<_> = players.into_iter().collect();
+        ranking.sort();
+
+        let ranking: Vec<_> = ranking.iter().map(|p| (p.id, p.score)).collect();
+        assert!(ranking == [(2, 5), (1, 20)]);
+
+        assert!(All { id: 1, score: 1 } == All { id: 1, score: 2 });
+        assert!(All { id: 1, score: 1 }.cmp(&All { id: 1, score: 2 }) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_enum() {
+        let state = RandomState::new();
+
+        assert!(Shape::Empty < Shape::Circle(0));
+        assert!(
+            Shape::Circle(5)
+                < Shape::Rect {
+                    width: 1,
+                    height: 1
+                }
+        );
+        assert!(Shape::Empty == Shape::Circle(0));
+        assert!(
+            Shape::Circle(0)
+                == Shape::Rect {
+                    width: 0,
+                    height: 3
+                }
+        );
+        assert!(Shape::Circle(1) != Shape::Empty);
+        assert!(hash(&state, &Shape::Empty) == hash(&state, &Shape::Circle(0)));
+    }
+
+    #[test]
+    fn test_modes() {
+        let state = RandomState::new();
+
+        assert!(Modes(1, "b") < Modes(2, "a"));
+        assert!(Modes(1, "b").cmp(&Modes(1, "a")) == Ordering::Greater);
+        assert!(Modes(1, "a") == Modes(2, "a"));
+        assert!(Modes(1, "a") != Modes(1, "b"));
+        assert!(hash(&state, &Modes(1, "ab")) == hash(&state, &2usize));
+    }
+
+    #[test]
+    fn test_generics() {
+        let state = RandomState::new();
+
+        assert!(Wrapper("a") < Wrapper("b"));
+        assert!(Wrapper(1) == Wrapper(1));
+        assert!(hash(&state, &Wrapper("a")) == hash(&state, &"a"));
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_comparison)]
+
 #[cfg(test)]
 mod tests {
     use ::core::cmp::Ordering;
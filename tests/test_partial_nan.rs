@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::nan_equal_cmp;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    const NAN: f64 = f64::NAN;
+
+    #[ord_eq_by_key_selector(|m| m.value, m.id; partial_only(nan = none))]
+    #[derive(Debug)]
+    pub struct Strict {
+        pub value: f64,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(|m| m.value, m.id; partial_only(nan = equal))]
+    #[derive(Debug)]
+    pub struct Lenient {
+        pub value: f64,
+        pub id: u32,
+    }
+
+    #[ord_eq_by_key_selector(fields; partial_only(nan = equal))]
+    #[derive(Debug)]
+    pub struct Point(pub f64, pub f64);
+
+    #[ord_eq_by_key_selector(enum_fields; partial_only(nan = equal))]
+    #[derive(Debug)]
+    pub enum Reading {
+        Missing,
+        Value(f64),
+    }
+
+    #[ord_eq_by_key_selector(transparent; partial_only(nan = equal))]
+    #[derive(Debug)]
+    pub struct Celsius(pub f64);
+
+    #[ord_eq_by_key_selector(|s| &s.0; partial_only(nan = equal))]
+    #[derive(Debug)]
+    pub struct Subset(pub Set);
+
+    /// Set ordered by inclusion, incomparable sets are not NaN
+    #[derive(Debug, PartialEq)]
+    pub struct Set(u8);
+
+    impl PartialOrd for Set {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            let union = self.0 | other.0;
+            match (union == other.0, union == self.0) {
+                (true, true) => Some(Ordering::Equal),
+                (true, false) => Some(Ordering::Less),
+                (false, true) => Some(Ordering::Greater),
+                (false, false) => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_none() {
+        let strict = |value, id| Strict { value, id };
+
+        assert!(strict(NAN, 1).partial_cmp(&strict(1.0, 1)).is_none());
+        assert!(strict(1.0, 1).partial_cmp(&strict(NAN, 1)).is_none());
+        assert!(strict(NAN, 1).partial_cmp(&strict(NAN, 1)).is_none());
+        assert!(strict(NAN, 1) != strict(NAN, 1));
+        assert!(strict(1.0, 1) < strict(1.0, 2));
+    }
+
+    #[test]
+    fn test_nan_equal() {
+        let lenient = |value, id| Lenient { value, id };
+
+        assert!(lenient(NAN, 1).partial_cmp(&lenient(1.0, 1)) == Some(Ordering::Greater));
+        assert!(lenient(1.0, 1).partial_cmp(&lenient(NAN, 1)) == Some(Ordering::Less));
+        assert!(lenient(NAN, 1).partial_cmp(&lenient(f64::INFINITY, 1)) == Some(Ordering::Greater));
+        assert!(lenient(NAN, 1) == lenient(NAN, 1));
+        assert!(lenient(-NAN, 1) == lenient(NAN, 1));
+
+        // Equal NaN keys are followed by the next key
+        assert!(lenient(NAN, 1) < lenient(NAN, 2));
+        assert!(lenient(NAN, 1) != lenient(NAN, 2));
+    }
+
+    #[test]
+    fn test_nan_equal_sort() {
+        let mut values = vec![Lenient { value: NAN, id: 1 }, Lenient { value: 2.0, id: 2 }];
+        values.push(Lenient { value: -1.0, id: 3 });
+        values.push(Lenient { value: NAN, id: 0 });
+
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let ids: Vec<_> = values.iter().map(|v| v.id).collect();
+        assert!(ids == [3, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_nan_equal_fields() {
+        assert!(Point(NAN, 1.0) == Point(NAN, 1.0));
+        assert!(Point(NAN, 1.0) < Point(NAN, NAN));
+        assert!(Point(0.0, NAN).partial_cmp(&Point(0.0, 1.0)) == Some(Ordering::Greater));
+
+        assert!(Reading::Value(NAN) == Reading::Value(NAN));
+        assert!(Reading::Value(1.0) < Reading::Value(NAN));
+        assert!(Reading::Missing < Reading::Value(NAN));
+
+        assert!(Celsius(NAN) == Celsius(NAN));
+        assert!(Celsius(NAN) > Celsius(100.0));
+    }
+
+    #[test]
+    fn test_nan_equal_incomparable() {
+        // Values which are comparable to themselves are not NaN, so they stay incomparable
+        assert!(Subset(Set(0b01)).partial_cmp(&Subset(Set(0b10))).is_none());
+        assert!(Subset(Set(0b01)) != Subset(Set(0b10)));
+        assert!(Subset(Set(0b01)) < Subset(Set(0b11)));
+    }
+
+    #[test]
+    fn test_nan_equal_cmp() {
+        assert!(nan_equal_cmp(&NAN, &NAN) == Some(Ordering::Equal));
+        assert!(nan_equal_cmp(&NAN, &0.0) == Some(Ordering::Greater));
+        assert!(nan_equal_cmp(&f32::NEG_INFINITY, &f32::NAN) == Some(Ordering::Less));
+        assert!(nan_equal_cmp(&1.0, &1.0) == Some(Ordering::Equal));
+        assert!(nan_equal_cmp(&Set(1), &Set(2)).is_none());
+        assert!(nan_equal_cmp("a", "b") == Some(Ordering::Less));
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    /// Subset of `{0, 1, ..., 31}`, ordered by inclusion
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub struct Subset(u32);
+
+    impl PartialOrd for Subset {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            let union = self.0 | other.0;
+
+            match (union == other.0, union == self.0) {
+                (true, true) => Some(Ordering::Equal),
+                (true, false) => Some(Ordering::Less),
+                (false, true) => Some(Ordering::Greater),
+                (false, false) => None,
+            }
+        }
+    }
+
+    #[ord_eq_by_key_selector(|(s)| *s; partial_only)]
+    #[derive(Debug)]
+    pub struct Lattice(Subset);
+
+    #[ord_eq_by_key_selector(|t| t.subset, t.rank; partial_only)]
+    #[derive(Debug)]
+    pub struct Tagged {
+        pub subset: Subset,
+        pub rank: u32,
+    }
+
+    #[ord_eq_by_key_selector(|t| t.rank, t.subset; partial_only)]
+    #[derive(Debug)]
+    pub struct Ranked {
+        pub subset: Subset,
+        pub rank: u32,
+    }
+
+    /// Total `u32` key, then partial domain key, then partial `f64` key
+    #[ord_eq_by_key_selector(fields; partial_only)]
+    #[derive(Debug)]
+    pub struct Measurement {
+        pub priority: u32,
+        pub subset: Subset,
+        pub value: f64,
+    }
+
+    #[ord_eq_by_key_selector(enum_fields; partial_only)]
+    #[derive(Debug)]
+    pub enum Shape {
+        Point,
+        Region(Subset, u32),
+    }
+
+    #[test]
+    fn test_subset_lattice() {
+        let empty = Lattice(Subset(0b000));
+        let a = Lattice(Subset(0b001));
+        let b = Lattice(Subset(0b010));
+        let ab = Lattice(Subset(0b011));
+
+        assert_eq!(empty.partial_cmp(&a), Some(Ordering::Less));
+        assert_eq!(a.partial_cmp(&ab), Some(Ordering::Less));
+        assert_eq!(ab.partial_cmp(&b), Some(Ordering::Greater));
+        assert_eq!(
+            ab.partial_cmp(&Lattice(Subset(0b011))),
+            Some(Ordering::Equal)
+        );
+        assert!(ab == Lattice(Subset(0b011)));
+
+        // Incomparable but not equal
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(a != b);
+        assert!([a < b, a > b, a <= b, a >= b] == [false; 4]);
+    }
+
+    #[test]
+    fn test_incomparable_key_stops_comparison() {
+        let tagged = |subset, rank| Tagged {
+            subset: Subset(subset),
+            rank,
+        };
+
+        assert_eq!(
+            tagged(0b01, 9).partial_cmp(&tagged(0b11, 0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            tagged(0b01, 0).partial_cmp(&tagged(0b01, 9)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(tagged(0b01, 0).partial_cmp(&tagged(0b10, 9)), None);
+        assert_eq!(tagged(0b01, 0).partial_cmp(&tagged(0b10, 0)), None);
+        assert!(tagged(0b01, 0) != tagged(0b10, 0));
+        assert!(tagged(0b01, 0) == tagged(0b01, 0));
+    }
+
+    #[test]
+    fn test_incomparable_last_key() {
+        let ranked = |rank, subset| Ranked {
+            subset: Subset(subset),
+            rank,
+        };
+
+        assert_eq!(
+            ranked(0, 0b01).partial_cmp(&ranked(1, 0b10)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(ranked(1, 0b01).partial_cmp(&ranked(1, 0b10)), None);
+        assert_eq!(
+            ranked(1, 0b01).partial_cmp(&ranked(1, 0b11)),
+            Some(Ordering::Less)
+        );
+        assert!(ranked(1, 0b01) != ranked(1, 0b10));
+    }
+
+    #[test]
+    fn test_fields_mixed_keys() {
+        let m = |priority, subset, value| Measurement {
+            priority,
+            subset: Subset(subset),
+            value,
+        };
+
+        // Total key decides
+        assert_eq!(
+            m(1, 0b01, 0.0).partial_cmp(&m(2, 0b10, f64::NAN)),
+            Some(Ordering::Less)
+        );
+
+        // Partial key is incomparable
+        assert_eq!(m(1, 0b01, 0.0).partial_cmp(&m(1, 0b10, 0.0)), None);
+        assert_eq!(
+            m(1, 0b01, 5.0).partial_cmp(&m(1, 0b11, 0.0)),
+            Some(Ordering::Less)
+        );
+
+        // `f64` key is compared last, NaN is incomparable
+        assert_eq!(
+            m(1, 0b01, 2.0).partial_cmp(&m(1, 0b01, 1.0)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(m(1, 0b01, f64::NAN).partial_cmp(&m(1, 0b01, 1.0)), None);
+        assert!(m(1, 0b01, f64::NAN) != m(1, 0b01, f64::NAN));
+        assert!(m(1, 0b01, 1.0) == m(1, 0b01, 1.0));
+    }
+
+    #[test]
+    fn test_enum_fields() {
+        let region = |subset, n| Shape::Region(Subset(subset), n);
+
+        assert_eq!(
+            Shape::Point.partial_cmp(&region(0, 0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            region(0b01, 9).partial_cmp(&region(0b11, 0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(region(0b01, 0).partial_cmp(&region(0b10, 0)), None);
+        assert_eq!(
+            region(0b01, 1).partial_cmp(&region(0b01, 0)),
+            Some(Ordering::Greater)
+        );
+        assert!(region(0b01, 1) == region(0b01, 1));
+        assert!(Shape::Point == Shape::Point);
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::PathOrd;
+
+    #[ord_eq_by_key_selector(|e| ord_by_key::PathOrd(&e.path, '/'))]
+    pub struct Entry {
+        pub path: String,
+    }
+
+    fn entry(path: &str) -> Entry {
+        Entry {
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_directory_tree() {
+        let mut entries: Vec<_> = [
+            "b", "a/c/d", "a.txt", "a/b", "a", "a/c", "a-b", "a/b/z", "b/a",
+        ]
+        .iter()
+        .map(|p| entry(p))
+        .collect();
+
+        entries.sort();
+
+        let entries: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            entries,
+            ["a", "a/b", "a/b/z", "a/c", "a/c/d", "a-b", "a.txt", "b", "b/a"]
+        );
+
+        // Plain string order puts `a.txt` before children of `a`
+        assert!("a.txt" < "a/b");
+    }
+
+    #[test]
+    fn test_equal_prefixes() {
+        assert!(entry("a/b").cmp(&entry("a/b")) == Ordering::Equal);
+        assert!(entry("a/b").cmp(&entry("a/b/c")) == Ordering::Less);
+        assert!(entry("a/bc").cmp(&entry("a/b/c")) == Ordering::Greater);
+        assert!(entry("a/b/c").cmp(&entry("a/b")) == Ordering::Greater);
+        assert!(entry("ab").cmp(&entry("a/b")) == Ordering::Greater);
+        assert!(entry("a").cmp(&entry("a/")) == Ordering::Less);
+        assert!(entry("a//b").cmp(&entry("a/b")) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_separator() {
+        assert!(PathOrd("a::b", ':') < PathOrd("a_b", ':'));
+        assert!(PathOrd("a\\b", '\\') < PathOrd("a.b", '\\'));
+        assert!(PathOrd("a\\b", '\\') == PathOrd("a\\b", '\\'));
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use core::marker::PhantomData;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeSet;
+
+    /// Units which don't implement any traits
+    pub enum Meters {}
+    pub struct Feet;
+
+    #[ord_eq_by_key_selector(|l| ord_by_key::TotalF64(l.0))]
+    pub struct Length<Unit>(f64, PhantomData<Unit>);
+
+    fn length<Unit>(value: f64) -> Length<Unit> {
+        Length(value, PhantomData)
+    }
+
+    #[test]
+    fn test_unit_is_not_bound() {
+        fn assert_ord<T: Ord>() {}
+
+        assert_ord::<Length<Meters>>();
+        assert_ord::<Length<Feet>>();
+        assert_ord::<Length<*const u8>>();
+    }
+
+    #[test]
+    fn test_total_order() {
+        let a: Length<Meters> = length(1.5);
+        let b: Length<Meters> = length(2.0);
+
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(a == length(1.5));
+        assert!(length::<Meters>(-0.0).cmp(&length(0.0)) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_nan() {
+        let nan: Length<Meters> = length(f64::NAN);
+
+        assert!(nan.cmp(&length(f64::INFINITY)) == Ordering::Greater);
+        assert!(length::<Meters>(-f64::NAN).cmp(&length(f64::NEG_INFINITY)) == Ordering::Less);
+        assert!(nan == length(f64::NAN));
+        assert_eq!(nan.partial_cmp(&length(1.0)), Some(Ordering::Greater));
+
+        let set: BTreeSet<Length<Feet>> = [f64::NAN, 1.0, f64::NAN, 0.5]
+            .iter()
+            .map(|&v| length(v))
+            .collect();
+
+        let values: Vec<_> = set.iter().map(|l| l.0).collect();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[..2], [0.5, 1.0]);
+        assert!(values[2].is_nan());
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::{ord_eq_by_key_selector, rank_enum};
+
+    /// Declaration order differs from the ranking on purpose
+    #[derive(Clone, Copy, Debug)]
+    pub enum Tier {
+        Enterprise,
+        Free,
+        Pro,
+    }
+
+    #[ord_eq_by_key_selector(|u| rank_enum!(u.tier => Tier::Free, Tier::Pro, Tier::Enterprise), &u.name)]
+    #[derive(Debug)]
+    pub struct User {
+        pub tier: Tier,
+        pub name: String,
+    }
+
+    mod imported {
+        use super::Tier;
+        use super::Tier::*;
+        use ord_by_key::{ord_eq_by_key_selector, rank_enum};
+
+        /// Variants imported into the scope, ranked in reverse, with a trailing comma
+        #[ord_eq_by_key_selector(|(tier)| rank_enum!(tier => Enterprise, Pro, Free,))]
+        pub struct Account(pub Tier);
+    }
+
+    fn user(tier: Tier, name: &str) -> User {
+        User {
+            tier,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(
+            rank_enum!(Tier::Free => Tier::Free, Tier::Pro, Tier::Enterprise),
+            0
+        );
+        assert_eq!(
+            rank_enum!(Tier::Pro => Tier::Free, Tier::Pro, Tier::Enterprise),
+            1
+        );
+        assert_eq!(
+            rank_enum!(&Tier::Enterprise => Tier::Free, Tier::Pro, Tier::Enterprise),
+            2
+        );
+    }
+
+    #[test]
+    fn test_order_by_ranking() {
+        assert!(user(Tier::Free, "b").cmp(&user(Tier::Pro, "a")) == Ordering::Less);
+        assert!(user(Tier::Enterprise, "a").cmp(&user(Tier::Pro, "b")) == Ordering::Greater);
+        assert!(user(Tier::Pro, "a").cmp(&user(Tier::Pro, "b")) == Ordering::Less);
+        assert!(user(Tier::Pro, "a") == user(Tier::Pro, "a"));
+    }
+
+    #[test]
+    fn test_imported_variants() {
+        use imported::Account;
+
+        assert!(Account(Tier::Enterprise).cmp(&Account(Tier::Pro)) == Ordering::Less);
+        assert!(Account(Tier::Free).cmp(&Account(Tier::Pro)) == Ordering::Greater);
+        assert!(Account(Tier::Free) == Account(Tier::Free));
+    }
+}
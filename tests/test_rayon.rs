@@ -0,0 +1,118 @@
+#![cfg(feature = "rayon")]
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use rayon::slice::ParallelSliceMut;
+
+    #[ord_eq_by_key_selector(|e| e.bucket, ::core::cmp::Reverse(e.id); gen_par_sort)]
+    #[derive(Clone, Debug)]
+    pub struct Entry {
+        pub bucket: u32,
+        pub id: u64,
+    }
+
+    #[ord_eq_by_key_selector(|j| j.priority; fifo_tiebreak, debug_counters, gen_par_sort)]
+    pub struct Job {
+        pub priority: u32,
+        pub index: u32,
+    }
+
+    #[ord_eq_by_key_selector(|n| n.name.len(), &n.name; gen_par_sort, vis = pub(crate))]
+    pub struct Name {
+        pub name: String,
+    }
+
+    #[ord_eq_by_key_selector(|w| &w.0; gen_par_sort)]
+    pub struct Wrapper<T: Ord>(pub T);
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    /// Pseudo-random sequence, so the input has no order the sort could exploit
+    fn entries(count: u64) -> Vec<Entry> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+
+        (0..count)
+            .map(|id| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                Entry {
+                    bucket: (state % 1000) as u32,
+                    id,
+                }
+            })
+            .collect()
+    }
+
+    fn keys(entries: &[Entry]) -> Vec<(u32, u64)> {
+        entries.iter().map(|e| (e.bucket, e.id)).collect()
+    }
+
+    #[test]
+    fn test_send_sync() {
+        assert_send_sync::<Entry>();
+        assert_send_sync::<Job>();
+        assert_send_sync::<Name>();
+        assert_send_sync::<Wrapper<u32>>();
+    }
+
+    #[test]
+    fn test_par_sort() {
+        let mut parallel = entries(200_000);
+        let mut sequential = parallel.clone();
+
+        Entry::par_sort(&mut parallel);
+        sequential.sort();
+
+        assert!(keys(&parallel) == keys(&sequential));
+        assert!(parallel.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_par_sort_by() {
+        let mut parallel = entries(200_000);
+        let mut sequential = parallel.clone();
+
+        parallel.par_sort_by(Ord::cmp);
+        ord_by_key::par_sort(&mut sequential);
+
+        assert!(keys(&parallel) == keys(&sequential));
+    }
+
+    #[test]
+    fn test_par_sort_fifo() {
+        let mut jobs: Vec<_> = (0..100_000).map(|i| Job::new(i % 10, i)).collect();
+
+        Job::par_sort(&mut jobs);
+
+        // Sequence numbers make all values distinct, so even the unstable sort keeps jobs of the
+        // same priority in order of creation
+        assert!(jobs
+            .windows(2)
+            .all(|pair| pair[0].cmp(&pair[1]) == Ordering::Less));
+        assert!(jobs
+            .windows(2)
+            .all(|pair| (pair[0].priority, pair[0].index) < (pair[1].priority, pair[1].index)));
+    }
+
+    #[test]
+    fn test_par_sort_other() {
+        let mut names: Vec<_> = ["ccc", "a", "bb", "b"]
+            .iter()
+            .map(|name| Name {
+                name: name.to_string(),
+            })
+            .collect();
+        Name::par_sort(&mut names);
+
+        let names: Vec<_> = names.iter().map(|n| n.name.as_str()).collect();
+        assert!(names == ["a", "b", "bb", "ccc"]);
+
+        let mut wrappers = vec![Wrapper(3), Wrapper(1), Wrapper(2)];
+        Wrapper::par_sort(&mut wrappers);
+        assert!(wrappers.iter().map(|w| w.0).eq([1, 2, 3]));
+    }
+}
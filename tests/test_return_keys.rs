@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    // The first key returns early for missing priorities, the second key is still compared
+    #[ord_eq_by_key_selector(|t|
+        {
+            if t.priority == 0 {
+                return u32::MAX;
+            }
+
+            t.priority
+        },
+        t.name,
+        {
+            match t.id {
+                Some(id) => return id,
+                None => 0,
+            }
+        })]
+    #[derive(Debug)]
+    pub struct Task {
+        pub priority: u32,
+        pub name: &'static str,
+        pub id: Option<u64>,
+    }
+
+    // Unbraced `return` extends up to the comma, so these are two keys
+    #[ord_eq_by_key_selector(|p| return p.a, p.b)]
+    #[derive(Debug)]
+    pub struct Pair {
+        pub a: u32,
+        pub b: u32,
+    }
+
+    // `return` of a tuple in parentheses is a single key
+    #[ord_eq_by_key_selector(|p| return (p.b, p.a))]
+    #[derive(Debug)]
+    pub struct Swapped {
+        pub a: u32,
+        pub b: u32,
+    }
+
+    #[ord_eq_by_key_selector(|(v)| v.len(), { return v.first().copied().unwrap_or(0); })]
+    pub struct Lengths(Vec<u8>);
+
+    fn task(priority: u32, name: &'static str, id: Option<u64>) -> Task {
+        Task { priority, name, id }
+    }
+
+    #[test]
+    fn test_return_exits_only_its_key() {
+        // Early return of the first key makes missing priority the greatest
+        assert!(task(0, "a", None).cmp(&task(5, "b", None)) == Ordering::Greater);
+
+        // Equal early returns fall through to the next keys
+        assert!(task(0, "a", None).cmp(&task(0, "b", None)) == Ordering::Less);
+        assert!(task(0, "a", Some(2)).cmp(&task(0, "a", Some(1))) == Ordering::Greater);
+        assert!(task(0, "a", Some(0)) == task(0, "a", None));
+        assert!(task(3, "a", None).cmp(&task(3, "a", Some(1))) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_unbraced_return() {
+        assert!(Pair { a: 1, b: 9 }.cmp(&Pair { a: 2, b: 0 }) == Ordering::Less);
+        assert!(Pair { a: 1, b: 9 }.cmp(&Pair { a: 1, b: 0 }) == Ordering::Greater);
+        assert!(Pair { a: 1, b: 1 } == Pair { a: 1, b: 1 });
+
+        assert!(Swapped { a: 9, b: 1 }.cmp(&Swapped { a: 0, b: 2 }) == Ordering::Less);
+        assert!(Swapped { a: 1, b: 1 }.cmp(&Swapped { a: 0, b: 1 }) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_tuple_struct() {
+        assert!(Lengths(vec![9]) < Lengths(vec![1, 2]));
+        assert!(Lengths(vec![1, 2]) < Lengths(vec![2, 0]));
+        assert!(Lengths(vec![]) == Lengths(vec![]));
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::sat_add;
+    use ord_by_key::sat_sub;
+
+    #[ord_eq_by_key_selector(|p| Reverse(ord_by_key::sat_sub(p.base, p.penalty)))]
+    #[derive(Debug)]
+    pub struct Player {
+        pub base: u32,
+        pub penalty: u32,
+    }
+
+    #[test]
+    fn test_key_expression() {
+        let player = |base, penalty| Player { base, penalty };
+
+        assert!(player(10, 3).cmp(&player(5, 1)) == Ordering::Less);
+        assert!(player(0, u32::MAX).cmp(&player(3, 3)) == Ordering::Equal);
+        assert!(player(u32::MAX, 0).cmp(&player(0, 0)) == Ordering::Less);
+
+        let mut players = [player(1, 5), player(7, 2), player(4, 4), player(9, 1)];
+        players.sort();
+
+        let scores: Vec<_> = players.iter().map(|p| (p.base, p.penalty)).collect();
+        assert_eq!(scores, [(9, 1), (7, 2), (1, 5), (4, 4)]);
+    }
+
+    macro_rules! test_bounds {
+        ($($name:ident: $ty:ty),*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(sat_add(<$ty>::MAX, 1), <$ty>::MAX);
+                    assert_eq!(sat_add(<$ty>::MAX - 1, 1), <$ty>::MAX);
+                    assert_eq!(sat_add(<$ty>::MAX, <$ty>::MAX), <$ty>::MAX);
+                    assert_eq!(sat_sub(<$ty>::MIN, 1), <$ty>::MIN);
+                    assert_eq!(sat_sub(<$ty>::MIN + 1, 1), <$ty>::MIN);
+                    assert_eq!(sat_add(2 as $ty, 3), 5);
+                    assert_eq!(sat_sub(5 as $ty, 3), 2);
+                }
+            )*
+        };
+    }
+
+    test_bounds!(
+        test_u8: u8, test_u16: u16, test_u32: u32, test_u64: u64, test_u128: u128,
+        test_usize: usize, test_i8: i8, test_i16: i16, test_i32: i32, test_i64: i64,
+        test_i128: i128, test_isize: isize
+    );
+
+    #[test]
+    fn test_signed_bounds() {
+        assert_eq!(sat_add(i8::MIN, -1), i8::MIN);
+        assert_eq!(sat_sub(i8::MAX, -1), i8::MAX);
+        assert_eq!(sat_sub(0i8, i8::MIN), i8::MAX);
+        assert_eq!(sat_sub(-1i64, i64::MAX), i64::MIN);
+        assert_eq!(sat_sub(3u8, 5), 0);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::SegmentPattern;
+    use ord_by_key::Segmented;
+    use ord_by_key::UnknownSegmentType;
+
+    const INVOICE_NUMBER: SegmentPattern = SegmentPattern::new("skip-num-num");
+    const ORDER_NUMBER: SegmentPattern = SegmentPattern::new("lit/num-num-num.num");
+
+    #[ord_eq_by_key_selector(|i| Segmented(i.0, INVOICE_NUMBER))]
+    #[derive(Debug)]
+    pub struct Invoice(&'static str);
+
+    /// Order numbers like `EU/2024-03-15.7`: region, date, then number within the day
+    #[ord_eq_by_key_selector(|o| Segmented(o.0, ORDER_NUMBER))]
+    #[derive(Debug)]
+    pub struct Order(&'static str);
+
+    #[test]
+    fn test_invoice_numbers() {
+        assert!(Invoice("INV-2023-00950").cmp(&Invoice("INV-2024-00042")) == Ordering::Less);
+        assert!(Invoice("INV-2024-00042").cmp(&Invoice("INV-2024-00100")) == Ordering::Less);
+        assert!(Invoice("INV-2024-9").cmp(&Invoice("INV-2024-10")) == Ordering::Less);
+        assert!(Invoice("INV-2025-1").cmp(&Invoice("INV-2024-99999")) == Ordering::Greater);
+
+        // Prefix is skipped, leading zeros are ignored
+        assert!(Invoice("INV-2024-00042") == Invoice("CRN-2024-42"));
+    }
+
+    #[test]
+    fn test_order_numbers() {
+        let mut orders = [
+            Order("US/2024-03-15.2"),
+            Order("EU/2024-03-15.10"),
+            Order("EU/2024-03-15.9"),
+            Order("EU/2023-12-31.1"),
+            Order("EU/2024-3-9.1"),
+        ];
+        orders.sort();
+
+        let sorted: Vec<_> = orders.iter().map(|o| o.0).collect();
+        assert!(
+            sorted
+                == [
+                    "EU/2023-12-31.1",
+                    "EU/2024-3-9.1",
+                    "EU/2024-03-15.9",
+                    "EU/2024-03-15.10",
+                    "US/2024-03-15.2",
+                ]
+        );
+    }
+
+    #[test]
+    fn test_literal_segments() {
+        let key = |s| Segmented(s, SegmentPattern::new("lit-num"));
+
+        // Literal segments decide first and are compared as strings
+        assert!(key("A-10") < key("B-2"));
+        assert!(key("B-2") < key("B-10"));
+        assert!(key("b-1") > key("B-1"));
+    }
+
+    #[test]
+    fn test_malformed_values() {
+        let key = |s| Segmented(s, INVOICE_NUMBER);
+
+        // Missing segments are empty, and empty segments are not numbers
+        assert!(key("INV-2024") > key("INV-2024-5"));
+        assert!(key("INV") == key(""));
+
+        // Non-numbers go after numbers, last segment takes the rest
+        assert!(key("INV-2024-5") < key("INV-2024-x"));
+        assert!(key("INV-2024-5-1") > key("INV-2024-5"));
+        assert!(key("INV-2024-5-1") == key("INV-2024-5-1"));
+    }
+
+    #[test]
+    fn test_multibyte_separators() {
+        let key = |s| Segmented(s, SegmentPattern::new("num→num"));
+
+        assert!(key("1→2") < key("1→10"));
+        assert!(key("01→2") == key("1→2"));
+    }
+
+    #[test]
+    fn test_unknown_segment_type() {
+        assert!(SegmentPattern::parse("lit-nmu").unwrap_err() == UnknownSegmentType("nmu"));
+        assert!(SegmentPattern::parse("date").unwrap_err() == UnknownSegmentType("date"));
+        assert!(SegmentPattern::parse("num→date").unwrap_err() == UnknownSegmentType("date"));
+
+        // Two separators in a row leave an empty segment type between them
+        assert!(SegmentPattern::parse("num--num").unwrap_err() == UnknownSegmentType(""));
+
+        assert!(SegmentPattern::parse("skip-num→lit").is_ok());
+        assert!(SegmentPattern::parse("num-").is_ok());
+        assert!(SegmentPattern::parse("").is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown segment type in pattern")]
+    fn test_unknown_segment_type_panics_in_new() {
+        let _ = SegmentPattern::new("lit-nmu");
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::Seq;
+    use std::cell::Cell;
+
+    /// Path ordered by its non-empty segments, so `a//b/` equals `a/b`
+    #[ord_eq_by_key_selector(|p| Seq(p.segments()))]
+    #[derive(Debug)]
+    pub struct Route {
+        pub path: &'static str,
+    }
+
+    impl Route {
+        fn segments(&self) -> impl Iterator<Item = &str> + Clone {
+            self.path.split('/').filter(|s| !s.is_empty())
+        }
+    }
+
+    fn route(path: &'static str) -> Route {
+        Route { path }
+    }
+
+    #[test]
+    fn test_lexicographic() {
+        assert!(route("a/b").cmp(&route("a/c")) == Ordering::Less);
+        assert!(route("b").cmp(&route("a/z")) == Ordering::Greater);
+        assert!(route("a//b/") == route("/a/b"));
+        assert!(Seq(1..3).cmp(&Seq(1..3)) == Ordering::Equal);
+        assert!(Seq([3, 1].iter()).cmp(&Seq([2, 9].iter())) == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_different_lengths() {
+        assert!(route("").cmp(&route("a")) == Ordering::Less);
+        assert!(route("a").cmp(&route("a/b")) == Ordering::Less);
+        assert!(route("a/b/c").cmp(&route("a/b")) == Ordering::Greater);
+        assert!(route("a/b") != route("a/b/c"));
+        assert!(Seq(0..0) == Seq(5..5));
+        assert!(Seq(0..2).cmp(&Seq(0..5)) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_stops_at_first_difference() {
+        let visited = Cell::new(0);
+        let counted =
+            |range: core::ops::Range<u32>| range.inspect(|_| visited.set(visited.get() + 1));
+
+        assert!(Seq(counted(0..1000)).cmp(&Seq(counted(1..1000))) == Ordering::Less);
+        assert_eq!(visited.get(), 2);
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use core::cmp::Reverse;
+    use std::cell::Cell;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    pub struct Item {
+        pub name: &'static str,
+        pub priority: u64,
+        pub created: u64,
+    }
+
+    /// Priority decays by one per time unit
+    fn freshness(item: &Item, now: &u64) -> Reverse<i64> {
+        Reverse(item.priority as i64 - (*now - item.created) as i64)
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item {
+                name: "old",
+                priority: 10,
+                created: 0,
+            },
+            Item {
+                name: "new",
+                priority: 6,
+                created: 7,
+            },
+            Item {
+                name: "middle",
+                priority: 8,
+                created: 3,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_reference_is_fixed() {
+        // Clock which moves on every reading
+        let clock = Cell::new(10);
+        let now = || {
+            clock.set(clock.get() + 1);
+            clock.get()
+        };
+
+        let mut items = items();
+        items.sort_by(ord_by_key::at(now(), freshness));
+
+        // Clock is read once, keys at time 11 are -1, -2 and -4
+        assert_eq!(clock.get(), 11);
+        let names: Vec<_> = items.iter().map(|i| i.name).collect();
+        assert_eq!(names, ["new", "middle", "old"]);
+    }
+
+    #[test]
+    fn test_consistent() {
+        let compare = ord_by_key::at(20, freshness);
+        let items = items();
+
+        for a in &items {
+            for b in &items {
+                assert_eq!(compare(a, b), compare(b, a).reverse());
+                assert_eq!(compare(a, b), compare(a, b));
+            }
+            assert!(compare(a, a) == Ordering::Equal);
+        }
+
+        // Order depends on the reference: with slower decay old item stays first
+        let compare = ord_by_key::at(10, |item: &Item, now: &u64| {
+            Reverse(item.priority * 10 - (now - item.created))
+        });
+        assert!(compare(&items[0], &items[1]) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_instant() {
+        let start = Instant::now();
+        let item = |priority: u64, age: u64| (priority, start + Duration::from_secs(100 - age));
+
+        let mut items = [item(10, 8), item(5, 0), item(9, 1)];
+        items.sort_by(ord_by_key::at(
+            start + Duration::from_secs(100),
+            |(priority, created): &(u64, Instant), now: &Instant| {
+                Reverse(*priority as i64 - now.duration_since(*created).as_secs() as i64)
+            },
+        ));
+
+        let priorities: Vec<_> = items.iter().map(|i| i.0).collect();
+        assert_eq!(priorities, [9, 5, 10]);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::StableHash;
+
+    #[ord_eq_by_key_selector(|r| ord_by_key::StableHash(&r.key), &r.key)]
+    #[derive(Debug)]
+    pub struct Record {
+        pub key: String,
+    }
+
+    fn record(key: &str) -> Record {
+        Record {
+            key: key.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        // Changing any of these values breaks the stability guarantee
+        assert_eq!(StableHash("hello").value(), 0xa9bd73cca220c59c);
+        assert_eq!(StableHash("").value(), 0xaf64724c8602eb6e);
+        assert_eq!(StableHash(&42u32).value(), 0x8d9aadc8352fdf7f);
+        assert_eq!(StableHash(&-1i32).value(), 0x994f76653e2a3951);
+        assert_eq!(StableHash(&1u64).value(), 0x89cd31291d2aefa4);
+        assert_eq!(StableHash(&vec![1u8, 2, 3]).value(), 0x01ef76d429b11552);
+        assert_eq!(StableHash(&(1u8, "a")).value(), 0xd1130e1867854ff8);
+        assert_eq!(StableHash(&()).value(), 0xcbf29ce484222325);
+
+        // `usize` is hashed as 64-bit on every platform
+        assert_eq!(StableHash(&1usize).value(), StableHash(&1u64).value());
+    }
+
+    #[test]
+    fn test_order() {
+        assert!(StableHash("hello").cmp(&StableHash("")) == Ordering::Less);
+        assert!(StableHash("hello") == StableHash("hello"));
+        assert!(StableHash(&42u32).value() != StableHash(&42u64).value());
+
+        let mut records: Vec<_> = ["", "hello", "a", "b"].iter().map(|k| record(k)).collect();
+        records.sort();
+
+        let mut expected: Vec<_> = ["", "hello", "a", "b"]
+            .iter()
+            .map(|k| (StableHash(*k).value(), *k))
+            .collect();
+        expected.sort();
+
+        let keys: Vec<_> = records.iter().map(|r| r.key.as_str()).collect();
+        let expected: Vec<_> = expected.iter().map(|(_, k)| *k).collect();
+        assert_eq!(keys, expected);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::tagged;
+    use ord_by_key::AnyOrd;
+
+    #[ord_eq_by_key_selector(|v| match v {
+        Value::Numeric(n) => tagged(0, n),
+        Value::Text(s) => tagged(1, s),
+        Value::Pair(pair) => tagged(2, pair),
+        // Both flags share a rank and the payload type
+        Value::Flag(f) | Value::Toggle(f) => tagged(3, f),
+        Value::Missing => tagged(4, &()),
+    })]
+    #[derive(Debug)]
+    pub enum Value {
+        Missing,
+        Text(String),
+        Flag(bool),
+        Numeric(u64),
+        Pair((i32, i32)),
+        Toggle(bool),
+    }
+
+    #[ord_eq_by_key_selector(|c| c.column, match &c.value {
+        Cell::Int(i) => tagged(Reverse(0), i),
+        Cell::Name(n) => tagged(Reverse(1), n),
+    })]
+    pub struct Row {
+        pub column: u32,
+        pub value: Cell,
+    }
+
+    pub enum Cell {
+        Int(i64),
+        Name(&'static str),
+    }
+
+    fn text(s: &str) -> Value {
+        Value::Text(s.to_string())
+    }
+
+    #[test]
+    fn test_any_ord() {
+        assert!(AnyOrd::new(&1u64).cmp(&AnyOrd::new(&2u64)) == Ordering::Less);
+        assert!(AnyOrd::new(&"b").cmp(&AnyOrd::new(&"a")) == Ordering::Greater);
+        assert!(AnyOrd::new(&"a") == AnyOrd::new(&"a"));
+
+        // Different types are ordered consistently but never equal
+        let (a, b) = (AnyOrd::new(&1u64), AnyOrd::new(&1u32));
+        assert!(a != b);
+        assert!(a.cmp(&b) == b.cmp(&a).reverse());
+        assert!(format!("{:?}", a) == "AnyOrd { .. }");
+    }
+
+    #[test]
+    fn test_mixed_variants() {
+        let mut values = vec![
+            Value::Missing,
+            text("b"),
+            Value::Toggle(true),
+            Value::Numeric(10),
+            Value::Pair((1, 2)),
+            text("a"),
+            Value::Flag(false),
+            Value::Numeric(2),
+            Value::Pair((0, 5)),
+        ];
+        values.sort();
+
+        let expected = [
+            Value::Numeric(2),
+            Value::Numeric(10),
+            text("a"),
+            text("b"),
+            Value::Pair((0, 5)),
+            Value::Pair((1, 2)),
+            Value::Flag(false),
+            Value::Toggle(true),
+            Value::Missing,
+        ];
+
+        assert!(values.iter().zip(&expected).all(|(a, b)| a == b));
+        assert_eq!(values.len(), expected.len());
+    }
+
+    #[test]
+    fn test_shared_rank() {
+        assert!(Value::Flag(true) == Value::Toggle(true));
+        assert!(Value::Toggle(false) < Value::Flag(true));
+        assert!(Value::Numeric(u64::MAX) < text(""));
+        assert!(Value::Missing == Value::Missing);
+    }
+
+    #[test]
+    fn test_key_chain() {
+        let row = |column, value| Row { column, value };
+
+        // Column decides first, then names go before integers
+        assert!(row(0, Cell::Int(9)) < row(1, Cell::Name("a")));
+        assert!(row(0, Cell::Name("z")) < row(0, Cell::Int(-1)));
+        assert!(row(0, Cell::Int(-1)) < row(0, Cell::Int(1)));
+        assert!(row(0, Cell::Name("a")) == row(0, Cell::Name("a")));
+    }
+}
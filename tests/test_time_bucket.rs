@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::time::Duration;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::EpochNanos;
+    use ord_by_key::TimeBucket;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[ord_eq_by_key_selector(|e| e.source, TimeBucket(e.at, SECOND))]
+    #[derive(Debug)]
+    pub struct Event {
+        pub source: u32,
+        pub at: SystemTime,
+    }
+
+    fn millis(millis: u64) -> TimeBucket<Duration> {
+        TimeBucket(Duration::from_millis(millis), SECOND)
+    }
+
+    fn system(nanos: i64) -> SystemTime {
+        if nanos >= 0 {
+            UNIX_EPOCH + Duration::from_nanos(nanos as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_nanos(nanos.unsigned_abs())
+        }
+    }
+
+    #[test]
+    fn test_boundaries() {
+        assert!(millis(0) == millis(999));
+        assert!(millis(999).cmp(&millis(1_000)) == Ordering::Less);
+        assert!(millis(1_000) == millis(1_999));
+        assert!(millis(2_000).cmp(&millis(1_999)) == Ordering::Greater);
+
+        // Close timestamps in neighbouring buckets are not equal
+        assert!(millis(999) != millis(1_001));
+
+        let nanos = |n| TimeBucket(Duration::from_nanos(n), Duration::from_nanos(3));
+        assert!(nanos(2) == nanos(0));
+        assert!(nanos(3) != nanos(2));
+        assert!(nanos(5) == nanos(3));
+    }
+
+    #[test]
+    fn test_transitivity() {
+        // Within a tolerance of 600ms, 0 ~ 500 and 500 ~ 1000, but 0 !~ 1000. Buckets are
+        // transitive: every equal pair shares the bucket
+        let values: Vec<_> = (0..40).map(|i| millis(i * 150)).collect();
+
+        for a in &values {
+            for b in &values {
+                for c in &values {
+                    if a == b && b == c {
+                        assert!(a == c);
+                    }
+
+                    if a < b && b < c {
+                        assert!(a < c);
+                    }
+                }
+
+                assert!((a == b) == (a.cmp(b) == Ordering::Equal));
+            }
+        }
+    }
+
+    #[test]
+    fn test_before_epoch() {
+        let bucket = |nanos| TimeBucket(system(nanos), SECOND);
+
+        // Buckets are rounded down, so `-1ns` is in the bucket before the epoch
+        assert!(bucket(-1) != bucket(0));
+        assert!(bucket(-1) == bucket(-1_000_000_000));
+        assert!(bucket(-1_000_000_001) < bucket(-1_000_000_000));
+        assert!(system(-5).epoch_nanos() == -5);
+        assert!(system(5).epoch_nanos() == 5);
+    }
+
+    #[test]
+    fn test_zero_window() {
+        let zero = |n| TimeBucket(Duration::from_nanos(n), Duration::ZERO);
+
+        assert!(zero(1) != zero(2));
+        assert!(zero(1) == zero(1));
+    }
+
+    #[test]
+    fn test_dedup_within_window() {
+        let event = |source, millis| Event {
+            source,
+            at: UNIX_EPOCH + Duration::from_millis(millis),
+        };
+
+        let mut events = vec![
+            event(1, 10_500),
+            event(2, 10_100),
+            event(1, 10_900),
+            event(1, 11_000),
+            event(2, 10_200),
+        ];
+        events.sort();
+        events.dedup();
+
+        let events: Vec<_> = events
+            .iter()
+            .map(|e| {
+                (
+                    e.source,
+                    e.at.duration_since(UNIX_EPOCH).unwrap().as_millis(),
+                )
+            })
+            .collect();
+        assert!(events == [(1, 10_500), (1, 11_000), (2, 10_100)]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        use chrono::DateTime;
+
+        let at = |s: &str| TimeBucket(DateTime::parse_from_rfc3339(s).unwrap(), SECOND);
+
+        assert!(at("2024-01-01T10:00:00.2+02:00") == at("2024-01-01T08:00:00.9+00:00"));
+        assert!(at("2024-01-01T08:00:00.9Z") < at("2024-01-01T08:00:01Z"));
+        assert!(at("1969-12-31T23:59:59.5Z") != at("1970-01-01T00:00:00Z"));
+
+        let day = Duration::from_secs(24 * 60 * 60);
+        let date = |s: &str| TimeBucket(DateTime::parse_from_rfc3339(s).unwrap(), day);
+
+        // Days start at UTC midnight
+        assert!(date("2024-01-01T23:00:00-02:00") == date("2024-01-02T01:00:00Z"));
+    }
+}
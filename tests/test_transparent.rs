@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::BTreeSet;
+
+    #[ord_eq_by_key_selector(transparent)]
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Id(u64);
+
+    #[ord_eq_by_key_selector(transparent)]
+    pub struct Name {
+        pub value: String,
+    }
+
+    #[ord_eq_by_key_selector(transparent; gen_extremes = newest/oldest)]
+    #[derive(Debug)]
+    pub struct Version<T: Ord>(T);
+
+    #[test]
+    fn test_same_as_field() {
+        let values = [0, 1, 2, u64::MAX, 42];
+
+        for &a in &values {
+            for &b in &values {
+                assert_eq!(Id(a).cmp(&Id(b)), a.cmp(&b));
+                assert_eq!(Id(a).partial_cmp(&Id(b)), a.partial_cmp(&b));
+                assert_eq!(Id(a) == Id(b), a == b);
+            }
+        }
+
+        let set: BTreeSet<_> = [3, 1, 3, 2].iter().map(|&id| Id(id)).collect();
+        let ids: Vec<_> = set.iter().map(|id| id.0).collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_named_field() {
+        let name = |value: &str| Name {
+            value: value.to_string(),
+        };
+
+        assert!(name("a").cmp(&name("b")) == Ordering::Less);
+        assert!(name("b") == name("b"));
+    }
+
+    #[test]
+    fn test_generic() {
+        assert!(Version("1.2").cmp(&Version("1.10")) == Ordering::Greater);
+        assert_eq!(Version(3).newest(Version(5)).0, 5);
+        assert_eq!(Version(3).oldest(Version(5)).0, 3);
+    }
+}
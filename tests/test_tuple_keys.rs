@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::TotalF64;
+
+    /// Elements of tuple keys are checked separately, the tuple still compares lexicographically
+    #[ord_eq_by_key_selector(|p| (p.rank, &p.name), (Reverse(TotalF64(p.score)),))]
+    #[derive(Debug)]
+    pub struct Player {
+        pub rank: u32,
+        pub name: String,
+        pub score: f64,
+    }
+
+    #[ord_eq_by_key_selector(|p| (p.rank, ((&p.name[..], p.score.is_nan()), p.rank)))]
+    #[derive(Debug)]
+    pub struct Nested {
+        pub rank: u32,
+        pub name: String,
+        pub score: f64,
+    }
+
+    #[ord_eq_by_key_selector(|m| (m.rank, m.value); partial_only)]
+    #[derive(Debug)]
+    pub struct Measurement {
+        pub rank: u32,
+        pub value: f64,
+    }
+
+    #[ord_eq_by_key_selector(ord = |v| (v.major, v.minor); eq = |v| (v.major,); unsafe_inconsistent)]
+    #[derive(Debug)]
+    pub struct Version {
+        pub major: u32,
+        pub minor: u32,
+    }
+
+    fn player(rank: u32, name: &str, score: f64) -> Player {
+        Player {
+            rank,
+            name: name.to_string(),
+            score,
+        }
+    }
+
+    #[test]
+    fn test_borrowed_elements() {
+        assert!(player(1, "b", 0.0).cmp(&player(2, "a", 0.0)) == Ordering::Less);
+        assert!(player(1, "a", 0.0).cmp(&player(1, "b", 0.0)) == Ordering::Less);
+        assert!(player(1, "a", 2.0).cmp(&player(1, "a", 1.0)) == Ordering::Less);
+        assert!(player(1, "a", 1.0) == player(1, "a", 1.0));
+    }
+
+    #[test]
+    fn test_nested_tuples() {
+        let nested = |rank, name: &str, score| Nested {
+            rank,
+            name: name.to_string(),
+            score,
+        };
+
+        assert!(nested(1, "b", 0.0) < nested(2, "a", 0.0));
+        assert!(nested(1, "a", f64::NAN) > nested(1, "a", 0.0));
+        assert!(nested(1, "a", 0.0) == nested(1, "a", 5.0));
+    }
+
+    #[test]
+    fn test_partial_only_tuple() {
+        let m = |rank, value| Measurement { rank, value };
+
+        assert!(m(1, 5.0).partial_cmp(&m(2, 0.0)) == Some(Ordering::Less));
+        assert!(m(1, 0.0).partial_cmp(&m(1, 5.0)) == Some(Ordering::Less));
+        assert!(m(1, f64::NAN).partial_cmp(&m(1, 0.0)).is_none());
+        assert!(m(1, f64::NAN) != m(1, f64::NAN));
+    }
+
+    #[test]
+    fn test_eq_tuple() {
+        let v = |major, minor| Version { major, minor };
+
+        assert!(v(1, 0) < v(1, 2));
+        assert!(v(1, 0) == v(1, 2));
+        assert!(v(1, 0) != v(2, 0));
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::Utf16Order;
+
+    #[ord_eq_by_key_selector(|l| Utf16Order(&l.text))]
+    pub struct Label {
+        pub text: String,
+    }
+
+    fn label(text: &str) -> Label {
+        Label {
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_supplementary_plane() {
+        // U+1F600 is 0xD83D 0xDE00 in UTF-16, U+FF61 is 0xFF61
+        assert!("\u{1F600}".cmp("\u{FF61}") == Ordering::Greater);
+        assert!(label("\u{1F600}").cmp(&label("\u{FF61}")) == Ordering::Less);
+
+        // U+E000 is the first character after surrogates
+        assert!(label("a\u{1F389}").cmp(&label("a\u{E000}")) == Ordering::Less);
+        assert!(label("\u{1F389}").cmp(&label("\u{1F600}")) == Ordering::Less);
+        assert!(label("\u{1F600}").cmp(&label("\u{1F600}")) == Ordering::Equal);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut labels: Vec<_> = ["\u{FFFD}", "\u{1F600}", "z", "\u{E9}", "", "\u{10000}", "a"]
+            .iter()
+            .map(|t| label(t))
+            .collect();
+
+        labels.sort();
+
+        // Same order as `Array.prototype.sort` in JavaScript
+        let texts: Vec<_> = labels.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            ["", "a", "z", "\u{E9}", "\u{10000}", "\u{1F600}", "\u{FFFD}"]
+        );
+    }
+
+    #[test]
+    fn test_basic_plane_same_as_str() {
+        let texts = ["", "a", "ab", "b", "\u{E9}", "\u{4E2D}", "\u{FFFD}"];
+
+        for a in &texts {
+            for b in &texts {
+                assert_eq!(Utf16Order(a).cmp(&Utf16Order(b)), a.cmp(b));
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::VersionGroups;
+
+    #[ord_eq_by_key_selector(|b| VersionGroups(b.id))]
+    #[derive(Debug)]
+    pub struct Build {
+        pub id: &'static str,
+    }
+
+    fn cmp(a: &str, b: &str) -> Ordering {
+        VersionGroups(a).cmp(&VersionGroups(b))
+    }
+
+    #[test]
+    fn test_separators() {
+        assert!(cmp("1.2.3-4_5", "1-2-3.4.5") == Ordering::Equal);
+        assert!(cmp("1.2.3-4_5", "1 2 3+4 5") == Ordering::Equal);
+        assert!(cmp("1..2", "1/2") == Ordering::Equal);
+        assert!(cmp("1.2.3-4_5", "1.2.3-4_6") == Ordering::Less);
+    }
+
+    #[test]
+    fn test_group_counts() {
+        assert!(cmp("1.2", "1.2.0") == Ordering::Equal);
+        assert!(cmp("1.2", "1.2.0.0") == Ordering::Equal);
+        assert!(cmp("", "0") == Ordering::Equal);
+        assert!(cmp("1.2", "1.2.1") == Ordering::Less);
+        assert!(cmp("1.3", "1.2.9") == Ordering::Greater);
+    }
+
+    #[test]
+    fn test_numeric_groups() {
+        assert!(cmp("1.9", "1.10") == Ordering::Less);
+        assert!(cmp("1.010", "1.10") == Ordering::Equal);
+        assert!(cmp("1.0009", "1.10") == Ordering::Less);
+        assert!(cmp("1.0", "1.00") == Ordering::Equal);
+        assert!(
+            cmp(
+                "1.99999999999999999999999999",
+                "1.100000000000000000000000000"
+            ) == Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_mixed_segments() {
+        assert!(cmp("2.0a", "2.0b") == Ordering::Less);
+        assert!(cmp("2.0-alpha", "2.0-beta") == Ordering::Less);
+        assert!(cmp("2.0", "2.0-rc") == Ordering::Less);
+        assert!(cmp("2.0-rc.final", "2.0-rc") == Ordering::Greater);
+
+        // Numeric groups decide before letters
+        assert!(cmp("1.0-rc1", "1.0-beta2") == Ordering::Less);
+        assert!(cmp("release-1.0", "1.0") == Ordering::Greater);
+        assert!(cmp("release-1.0", "build-1.1") == Ordering::Less);
+    }
+
+    #[test]
+    fn test_key() {
+        let mut builds = [
+            Build { id: "1.10" },
+            Build { id: "1.2.3-4_5" },
+            Build { id: "1.9-rc" },
+            Build { id: "1.2.3.4.4" },
+            Build { id: "1.9" },
+        ];
+        builds.sort();
+
+        let ids: Vec<_> = builds.iter().map(|b| b.id).collect();
+        assert_eq!(ids, ["1.2.3.4.4", "1.2.3-4_5", "1.9", "1.9-rc", "1.10"]);
+
+        assert!(Build { id: "1.2" } == Build { id: "1.2.0" });
+    }
+}
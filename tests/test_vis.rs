@@ -0,0 +1,49 @@
+#![deny(missing_docs)]
+//! Generated helpers with restricted visibility
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+
+    mod inner {
+        use ord_by_key::ord_eq_by_key_selector;
+
+        /// Public type with helpers visible only in the crate
+        #[ord_eq_by_key_selector(|(n)| n; gen_extremes = larger/smaller, gen_option_ord = none_first, vis = pub(crate))]
+        pub struct Count(pub u32);
+
+        /// Public type with private helpers
+        #[ord_eq_by_key_selector(|(n)| n; gen_extremes = larger/smaller, vis = pub(self))]
+        pub struct Private(pub u32);
+
+        pub fn private_larger(a: Private, b: Private) -> Private {
+            a.larger(b)
+        }
+
+        /// Private type with helpers visible to the parent module
+        #[ord_eq_by_key_selector(|(n)| n; gen_extremes = larger/smaller, vis = pub(super))]
+        struct Hidden(u32);
+
+        pub(super) fn hidden_larger(a: u32, b: u32) -> u32 {
+            Hidden(a).larger(Hidden(b)).0
+        }
+    }
+
+    use inner::Count;
+    use inner::OptCount;
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(Count(1).larger(Count(2)).0, 2);
+        assert_eq!(Count(1).smaller(Count(2)).0, 1);
+        assert!(OptCount(None).cmp(&OptCount(Some(Count(0)))) == Ordering::Less);
+
+        assert_eq!(
+            inner::private_larger(inner::Private(3), inner::Private(1)).0,
+            3
+        );
+        assert!(inner::Private(3) > inner::Private(1));
+
+        assert_eq!(inner::hidden_larger(1, 5), 5);
+    }
+}
@@ -0,0 +1,57 @@
+#![deny(deprecated)]
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+
+    #[ord_eq_by_key_selector(|e| &e.name, e.id; warn_missing_fields(skip(note)))]
+    #[allow(dead_code)]
+    pub struct Entry {
+        pub name: String,
+        pub id: u32,
+        pub note: String,
+    }
+
+    #[ord_eq_by_key_selector(|p| p.0, p.1 .1; warn_missing_fields)]
+    pub struct Unnamed(u32, (u8, u8));
+
+    #[ord_eq_by_key_selector(|(a, b, c)| a, *b + 1; warn_missing_fields(skip(2)))]
+    pub struct Destructured(u32, u32, f64);
+
+    #[ord_eq_by_key_selector(fields(take = 1); warn_missing_fields)]
+    #[allow(dead_code)]
+    pub struct Fields(u32, f64);
+
+    #[ord_eq_by_key_selector(
+        ord = |s| s.category, s.score;
+        eq = |s| s.category;
+        unsafe_inconsistent, warn_missing_fields)]
+    pub struct Score {
+        pub category: u32,
+        pub score: u32,
+    }
+
+    #[test]
+    fn test_no_warnings() {
+        let entry = |name: &str, id| Entry {
+            name: name.to_string(),
+            id,
+            note: String::new(),
+        };
+
+        assert!(entry("a", 1).cmp(&entry("a", 2)) == Ordering::Less);
+        assert!(Unnamed(1, (0, 2)).cmp(&Unnamed(1, (9, 1))) == Ordering::Greater);
+        assert!(Destructured(1, 2, 0.0).cmp(&Destructured(1, 3, 9.0)) == Ordering::Less);
+        assert!(Fields(1, 0.0).cmp(&Fields(1, 9.0)) == Ordering::Equal);
+        assert!(
+            Score {
+                category: 1,
+                score: 2
+            } == Score {
+                category: 1,
+                score: 3
+            }
+        );
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ::core::cmp::Reverse;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::weighted;
+    use ord_by_key::StrictF64;
+
+    #[ord_eq_by_key_selector(|d|
+        Reverse(ord_by_key::weighted([(0.6, d.relevance), (0.4, d.recency)])),
+        d.id)]
+    #[derive(Debug)]
+    pub struct Document {
+        pub id: u32,
+        pub relevance: f64,
+        pub recency: f64,
+    }
+
+    fn document(id: u32, relevance: f64, recency: f64) -> Document {
+        Document {
+            id,
+            relevance,
+            recency,
+        }
+    }
+
+    #[test]
+    fn test_ranking() {
+        let mut documents = [
+            document(0, 0.5, 0.5), // 0.5
+            document(1, 1.0, 0.0), // 0.6
+            document(2, 0.0, 1.0), // 0.4
+            document(3, 0.9, 0.9), // 0.9
+            document(4, 0.2, 0.8), // 0.44
+        ];
+
+        documents.sort();
+
+        let ids: Vec<_> = documents.iter().map(|d| d.id).collect();
+        assert_eq!(ids, [3, 1, 0, 4, 2]);
+    }
+
+    #[test]
+    fn test_ties() {
+        // Both have score 0.5, ties are broken by id
+        let mut documents = [document(7, 0.5, 0.5), document(2, 0.5, 0.5)];
+        documents.sort();
+
+        let ids: Vec<_> = documents.iter().map(|d| d.id).collect();
+        assert_eq!(ids, [2, 7]);
+
+        assert!(document(1, 0.0, 0.0).cmp(&document(1, -0.0, -0.0)) == Ordering::Equal);
+        assert!(weighted([(1.0, -0.0)]) == weighted([(1.0, 0.0)]));
+        assert!(weighted([(-1.0, 0.0)]) == StrictF64(0.0));
+    }
+
+    #[test]
+    fn test_scores() {
+        assert!(weighted([(0.5, 2u32), (2.0, 3u32)]) == StrictF64(7.0));
+        assert!(weighted([(0.5, 1.0f32), (-1.0, 2.0f32)]) == StrictF64(-1.5));
+        assert!(weighted(Vec::<(f64, f64)>::new()) == StrictF64(0.0));
+
+        // NaN goes after all numbers regardless of how it was computed
+        let inf_minus_inf = weighted([(1.0, f64::INFINITY), (-1.0, f64::INFINITY)]);
+        assert!(inf_minus_inf == StrictF64(f64::NAN));
+        assert!(inf_minus_inf > StrictF64(f64::INFINITY));
+        assert!(weighted([(0.0, f64::INFINITY)]) == StrictF64(f64::NAN));
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+    use ord_by_key::ord_eq_by_key_selector;
+    use ord_by_key::WithAlphabet;
+
+    static RANKS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+
+    /// Reversed Latin alphabet
+    static REVERSED: [char; 5] = ['e', 'd', 'c', 'b', 'a'];
+
+    #[ord_eq_by_key_selector(|h| WithAlphabet(&h.cards, &RANKS))]
+    pub struct Hand {
+        pub cards: String,
+    }
+
+    fn hand(cards: &str) -> Hand {
+        Hand {
+            cards: cards.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_card_ranks() {
+        let mut hands: Vec<_> = ["A", "K", "T", "9", "2", "AK", "A2", "KA"]
+            .iter()
+            .map(|c| hand(c))
+            .collect();
+
+        hands.sort();
+
+        let hands: Vec<_> = hands.iter().map(|h| h.cards.as_str()).collect();
+        assert_eq!(hands, ["2", "9", "T", "K", "KA", "A", "A2", "AK"]);
+    }
+
+    #[test]
+    fn test_reshuffled() {
+        let key = |s| WithAlphabet(s, &REVERSED);
+
+        assert!(key("a").cmp(&key("b")) == Ordering::Greater);
+        assert!(key("e").cmp(&key("a")) == Ordering::Less);
+        assert!(key("ea").cmp(&key("ad")) == Ordering::Less);
+        assert!(key("ab").cmp(&key("ab")) == Ordering::Equal);
+        assert!(key("a").cmp(&key("ab")) == Ordering::Less);
+        assert!(key("").cmp(&key("e")) == Ordering::Less);
+    }
+
+    #[test]
+    fn test_out_of_alphabet() {
+        let key = |s| WithAlphabet(s, &REVERSED);
+
+        // Characters out of the alphabet go after all characters of the alphabet
+        assert!(key("z").cmp(&key("a")) == Ordering::Greater);
+        assert!(key("A").cmp(&key("a")) == Ordering::Greater);
+        assert!(key("az").cmp(&key("aa")) == Ordering::Greater);
+
+        // and are ordered by their values
+        assert!(key("f").cmp(&key("z")) == Ordering::Less);
+        assert!(key("Z").cmp(&key("f")) == Ordering::Less);
+        assert!(key("x").cmp(&key("x")) == Ordering::Equal);
+
+        // Empty alphabet is the same as plain string order
+        for (a, b) in [("a", "b"), ("b", "a"), ("ab", "a"), ("", "")] {
+            assert_eq!(WithAlphabet(a, &[]).cmp(&WithAlphabet(b, &[])), a.cmp(b));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    #![deny(warnings)]
+
+    use ::core::cmp::Ordering;
+    use ::core::hash::BuildHasher;
+    use ::core::marker::PhantomData;
+    use ord_by_key::ord_by_key_advanced;
+    use ord_by_key::ord_eq_by_key_selector;
+    use std::collections::hash_map::RandomState;
+    use std::collections::BTreeSet;
+
+    #[ord_eq_by_key_selector(|m|)]
+    #[derive(Debug)]
+    pub struct Marker();
+
+    #[ord_eq_by_key_selector(|()|)]
+    #[derive(Debug)]
+    pub struct Destructured();
+
+    #[ord_eq_by_key_selector(|u|)]
+    #[derive(Debug)]
+    pub struct Unit;
+
+    #[ord_eq_by_key_selector(fields)]
+    #[derive(Debug)]
+    pub struct Empty {}
+
+    #[ord_eq_by_key_selector(|s|)]
+    #[derive(Debug)]
+    pub enum Singleton {
+        Only,
+    }
+
+    #[ord_eq_by_key_selector(|n|)]
+    pub enum Never {}
+
+    #[ord_by_key_advanced(ord = |s|; eq = |s|; hash = |s|)]
+    #[derive(Debug)]
+    pub struct Advanced;
+
+    // Generic code which requires `Ord` of a phantom parameter
+    #[ord_eq_by_key_selector(|i| i.value)]
+    #[derive(Debug)]
+    pub struct Id<T: Ord> {
+        pub value: u32,
+        pub kind: PhantomData<T>,
+    }
+
+    fn all_equal<T: Ord>(a: &T, b: &T) -> bool {
+        a.cmp(b) == Ordering::Equal && a == b && a.partial_cmp(b) == Some(Ordering::Equal)
+    }
+
+    #[test]
+    fn test_all_equal() {
+        assert!(all_equal(&Marker(), &Marker()));
+        assert!(all_equal(&Destructured(), &Destructured()));
+        assert!(all_equal(&Unit, &Unit));
+        assert!(all_equal(&Empty {}, &Empty {}));
+        assert!(all_equal(&Singleton::Only, &Singleton::Only));
+        assert!(all_equal(&Advanced, &Advanced));
+
+        fn never_is_ord<T: Ord>() {}
+        never_is_ord::<Never>();
+    }
+
+    #[test]
+    fn test_collections() {
+        let set: BTreeSet<_> = vec![Marker(), Marker(), Marker()].into_iter().collect();
+        assert!(set.len() == 1);
+
+        let mut markers = vec![Unit, Unit];
+        markers.sort();
+        markers.dedup();
+        assert!(markers.len() == 1);
+    }
+
+    #[test]
+    fn test_hash_is_constant() {
+        let state = RandomState::new();
+        assert!(state.hash_one(Advanced) == state.hash_one(Advanced));
+        assert!(state.hash_one(Advanced) == state.hash_one(()));
+    }
+
+    #[test]
+    fn test_phantom_parameter() {
+        let a = Id::<Marker> {
+            value: 1,
+            kind: PhantomData,
+        };
+        let b = Id::<Marker> {
+            value: 2,
+            kind: PhantomData,
+        };
+
+        assert!(a < b);
+        assert!(
+            Id::<Singleton> {
+                value: 1,
+                kind: PhantomData
+            } == Id::<Singleton> {
+                value: 1,
+                kind: PhantomData
+            }
+        );
+    }
+}